@@ -0,0 +1,63 @@
+// Fleet wear report and repairs: cargo run --example fleet_maintenance [-- --repair]
+use spacetraders_cc::{SpaceTradersClient, admiral::load_agent_token, client::priority_client::PriorityApiClient};
+use spacetraders_cc::config::SpaceTradersConfig;
+use spacetraders_cc::operations::{MaintenanceManager, MaintenanceOutcome};
+use spacetraders_cc::storage::get_condition_history;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let repair = std::env::args().any(|arg| arg == "--repair");
+
+    let token = load_agent_token()?;
+    let client = SpaceTradersClient::new(token);
+    let priority_client = PriorityApiClient::new(client);
+    let config = SpaceTradersConfig::load_or_create("config.toml")?;
+
+    println!("🔧 FLEET MAINTENANCE REPORT (repair below {:.0}%)", config.fleet.repair_condition_threshold);
+    println!("==============================================");
+
+    let ships = priority_client.get_ships().await?;
+    let history = get_condition_history();
+    let mut maintenance = MaintenanceManager::new(config.fleet.repair_condition_threshold);
+
+    for ship in &ships {
+        history.record_ship(ship);
+        if !maintenance.knows_system(&ship.nav.system_symbol) {
+            let shipyards = priority_client.get_system_waypoints_with_traits(&ship.nav.system_symbol, "SHIPYARD").await?;
+            maintenance.record_shipyards(&ship.nav.system_symbol, &shipyards);
+        }
+
+        println!("\n🚢 {} at {}", ship.symbol, ship.nav.waypoint_symbol);
+        for forecast in maintenance.forecast(ship, history) {
+            let wear = forecast.wear_per_hour.map(|wear| format!("{:.2}%/h", wear)).unwrap_or_else(|| "unknown".to_string());
+            let due = match forecast.hours_to_threshold {
+                Some(hours) if hours <= 0.0 => "repair now".to_string(),
+                Some(hours) => format!("due in {:.1}h", hours),
+                None => "not wearing".to_string(),
+            };
+            println!("   {:?}: {:.0}% - wear {} - {}", forecast.component, forecast.condition, wear, due);
+        }
+
+        if !maintenance.repair_due(ship, history) {
+            continue;
+        }
+        let Some(visit) = maintenance.plan_visit(ship) else {
+            println!("   ⚠️ Repairs due but no shipyard in {}", ship.nav.system_symbol);
+            continue;
+        };
+        println!("   🏭 Repair at {} ({:.0} units off route)", visit.shipyard, visit.detour);
+
+        if repair {
+            match MaintenanceManager::repair_at(&priority_client, &ship.symbol, &visit.shipyard).await {
+                Ok(MaintenanceOutcome::AlreadySound) => println!("   ✅ Nothing to repair"),
+                Ok(MaintenanceOutcome::Repaired { cost }) => println!("   ✅ Repaired for {} credits", cost),
+                Ok(MaintenanceOutcome::ScrapRecommended(advice)) => {
+                    println!("   🗑️ Repair {} exceeds scrap value {} - consider scrapping", advice.repair_cost, advice.scrap_value)
+                }
+                Err(e) => println!("   ❌ {}", e),
+            }
+        }
+    }
+
+    Ok(())
+}
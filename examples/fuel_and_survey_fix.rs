@@ -70,7 +70,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 
                                 if !iron_surveys.is_empty() {
                                     println!("🎯 Ready for blitz mining campaign!");
-                                    println!("💡 Run: cargo run --example iron_ore_blitz_campaign");
+                                    println!("💡 Run: cargo run --example resource_campaign -- IRON_ORE 100");
                                 } else {
                                     println!("⚠️ No iron ore in this survey - try again");
                                 }
@@ -0,0 +1,72 @@
+// Compare every ship against its role's loadout: cargo run --example outfit_fleet [-- --apply SHIPYARD_WAYPOINT]
+use spacetraders_cc::{SpaceTradersClient, admiral::load_agent_token, client::priority_client::PriorityApiClient};
+use spacetraders_cc::operations::{OutfittingManager, ShipRoleManager};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let apply_at = args.iter().position(|arg| arg == "--apply").and_then(|index| args.get(index + 1)).cloned();
+
+    let token = load_agent_token()?;
+    let client = SpaceTradersClient::new(token);
+    let priority_client = PriorityApiClient::new(client);
+
+    println!("🛠️ FLEET OUTFITTING PLAN");
+    println!("========================");
+
+    let mut role_manager = ShipRoleManager::new();
+    role_manager.analyze_fleet(&priority_client).await?;
+    let ships = priority_client.get_ships().await?;
+
+    let mut outfitting = OutfittingManager::new();
+    outfitting.learn_from_ships(&ships);
+    if let Some(ship) = ships.first() {
+        let markets = outfitting.scan_markets(&priority_client, &ship.nav.system_symbol).await?;
+        println!("🏪 Scanned {} marketplaces for mounts and modules", markets);
+    }
+    if let Some(shipyard_waypoint) = &apply_at {
+        let system_symbol = shipyard_waypoint.rsplit_once('-').map(|(system, _)| system).unwrap_or(shipyard_waypoint);
+        let shipyard = priority_client.get_shipyard(system_symbol, shipyard_waypoint).await?;
+        outfitting.record_shipyard(&shipyard);
+    }
+
+    for ship in &ships {
+        let Some(role) = role_manager.get_ship_capability(&ship.symbol).map(|capability| capability.current_role.clone()) else {
+            continue;
+        };
+        let Some(plan) = outfitting.plan(ship, &role) else {
+            println!("\n🚢 {} ({:?}): no loadout for this role", ship.symbol, role);
+            continue;
+        };
+        println!("\n🚢 {} ({:?})", ship.symbol, role);
+        if plan.is_empty() {
+            println!("   ✅ Matches its loadout");
+            continue;
+        }
+        println!("   ➕ Mounts: {:?}", plan.install_mounts);
+        println!("   ➖ Mounts: {:?}", plan.remove_mounts);
+        println!("   ➕ Modules: {:?}", plan.install_modules);
+        for purchase in &plan.purchases {
+            println!("   🛒 {} at {} for {}", purchase.part,
+                     purchase.waypoint.as_deref().unwrap_or("(not sold nearby)"),
+                     purchase.price.map(|price| price.to_string()).unwrap_or_else(|| "?".to_string()));
+        }
+        for violation in &plan.violations {
+            println!("   ❌ {}", violation);
+        }
+        if !plan.unknown_requirements.is_empty() {
+            println!("   ⚠️ Requirements not yet seen for {:?}", plan.unknown_requirements);
+        }
+
+        if let Some(shipyard_waypoint) = &apply_at {
+            let estimate = outfitting.estimate_cost(&plan, shipyard_waypoint);
+            println!("   💰 Estimated cost: {} credits", estimate.total());
+            match outfitting.execute(&priority_client, &plan, shipyard_waypoint).await {
+                Ok(spent) => println!("   ✅ Outfitted for {} credits", spent),
+                Err(e) => println!("   ❌ {}", e),
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// Gather any trade good with the fleet: cargo run --example resource_campaign -- IRON_ORE 100 [WAYPOINT...]
+use spacetraders_cc::{SpaceTradersClient, admiral::load_agent_token, client::priority_client::PriorityApiClient};
+use spacetraders_cc::operations::{CampaignEvent, ResourceCampaign};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let good = args.first().cloned().unwrap_or_else(|| "IRON_ORE".to_string());
+    let target: i32 = args.get(1).and_then(|target| target.parse().ok()).unwrap_or(100);
+
+    let token = load_agent_token()?;
+    let client = SpaceTradersClient::new(token);
+    let priority_client = PriorityApiClient::new(client);
+
+    let mut campaign = ResourceCampaign::new(&good, target);
+    campaign.candidate_waypoints = args.iter().skip(2).cloned().collect();
+    let mut events = campaign.subscribe();
+
+    println!("🎯 {} CAMPAIGN - target {} units", good, target);
+    let (report, _) = tokio::join!(campaign.run(&priority_client), async {
+        while let Some(event) = events.recv().await {
+            match event {
+                CampaignEvent::Started { ships, waypoints, .. } => {
+                    println!("🚀 {} ship(s), sites: {}", ships.len(), waypoints.join(", "));
+                }
+                CampaignEvent::Extracted { ship, units, total, .. } => {
+                    println!("💎 {} +{} ({}/{})", ship, units, total, target);
+                }
+                CampaignEvent::ShipStopped { ship, reason } => println!("🛑 {}: {}", ship, reason),
+                _ => {}
+            }
+        }
+    });
+
+    let report = report?;
+    println!("📊 Gathered {}/{} {} in {} extractions - {}",
+             report.collected, report.target_quantity, report.good, report.extractions,
+             if report.success() { "SUCCESS" } else { "target not reached" });
+    Ok(())
+}
@@ -1,5 +1,5 @@
 // Test automatic docking and contract negotiation
-use spacetraders_cc::{SpaceTradersClient, admiral::load_agent_token, operations::contracts::ContractOperations};
+use spacetraders_cc::{SpaceTradersClient, ConfigManager, admiral::load_agent_token, operations::contracts::ContractOperations};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -35,7 +35,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("🎯 All contracts completed - testing automatic contract negotiation with docking");
     
     // Create contract operations instance
-    let contract_ops = ContractOperations::new(&client);
+    let config_manager = ConfigManager::new("config.toml")?;
+    let contract_ops = ContractOperations::new(&client, config_manager.config());
     
     // Test the negotiation process
     match contract_ops.negotiate_new_contract().await {
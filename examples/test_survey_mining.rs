@@ -1,5 +1,5 @@
 // Test the survey-based iron ore mining system
-use spacetraders_cc::{SpaceTradersClient, admiral::load_agent_token, client::priority_client::PriorityApiClient, operations::IronOreMiner};
+use spacetraders_cc::{SpaceTradersClient, admiral::load_agent_token, client::priority_client::PriorityApiClient, operations::ResourceCampaign};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -10,14 +10,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("🎯 TESTING SURVEY-BASED IRON ORE MINING");
     println!("=====================================");
     
-    // Step 1: Create iron ore miner targeting 100 units
-    let mut iron_miner = IronOreMiner::new(100);
+    // Step 1: Create an iron ore campaign targeting 100 units
+    let campaign = ResourceCampaign::new("IRON_ORE", 100);
     
     // Step 2: Execute mining campaign
     println!("🚀 Starting iron ore mining campaign...");
-    match iron_miner.execute_mining_campaign(&priority_client).await {
-        Ok(success) => {
-            if success {
+    match campaign.run(&priority_client).await {
+        Ok(report) => {
+            if report.success() {
                 println!("🎉 MINING CAMPAIGN SUCCESSFUL!");
                 println!("✅ Target iron ore amount achieved");
             } else {
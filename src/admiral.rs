@@ -1,6 +1,10 @@
 // Admiral module - High-level autonomous game loop orchestration
 use crate::client::SpaceTradersClient;
 use crate::config::ConfigManager;
+use crate::control::get_control;
+use crate::goals::{GoalManager, GoalInterpreter, GoalDecomposer, ContextEngine, ClauseLink};
+use crate::goals::goal_manager::GOAL_STORE_PATH;
+use crate::client::PriorityApiClient;
 use std::fs;
 
 // Use global verbosity macros and output broker
@@ -25,6 +29,10 @@ impl Admiral {
         })
     }
     
+    pub fn config(&self) -> &crate::config::SpaceTradersConfig {
+        self.config_manager.config()
+    }
+
     pub fn set_debug_mode(&mut self, debug: bool) {
         self.debug_mode = debug;
         self.client.set_debug_mode(debug);
@@ -84,12 +92,13 @@ impl Admiral {
         o_debug!( "🚢 Fleet Analysis:");
         o_debug!( "  Total ships: {}", analysis.total_ships);
         o_debug!( "  Mining ships: {}", analysis.mining_ships);
+        o_debug!( "  Siphon ships: {}", analysis.siphon_ships);
         o_debug!( "  Hauler ships: {}", analysis.hauler_ships);
         o_debug!( "  Cargo: {}/{} units", analysis.total_cargo_used, analysis.total_cargo_capacity);
         
         // Step 2: Contract analysis and selection
         o_debug!( "\n═══ STEP 2: Contract Management ═══");
-        let contract_ops = ContractOperations::new(&self.client);
+        let contract_ops = ContractOperations::new(&self.client, self.config_manager.config());
         let active_contract = match contract_ops.analyze_and_accept_best_contract().await? {
             Some(contract) => {
                 // Level 0: Always show active contract
@@ -175,6 +184,8 @@ impl Admiral {
                                 known_waypoints: std::collections::HashMap::new(),
                                 known_markets: std::collections::HashMap::new(),
                                 available_credits: 0,
+                                credit_reserve: self.config_manager.config().credits.min_reserve_credits,
+                                fuel_safety_margin: self.config_manager.config().fuel.fuel_safety_margin,
                                 fleet_status: crate::goals::FleetStatus {
                                     available_ships: vec![],
                                     busy_ships: std::collections::HashMap::new(),
@@ -182,6 +193,7 @@ impl Admiral {
                                     hauler_ships: vec![],
                                     probe_ships: vec![],
                                 },
+                                progress: Default::default(),
                             };
                             
                             // Validate and execute the goal
@@ -226,7 +238,7 @@ impl Admiral {
         
         // Get contract materials early to determine strategy
         let needed_materials = {
-            let contract_ops = ContractOperations::new(&self.client);
+            let contract_ops = ContractOperations::new(&self.client, self.config_manager.config());
             contract_ops.get_required_materials(&active_contract)
         };
         
@@ -242,7 +254,7 @@ impl Admiral {
             o_debug!( "🏭 Contract requires MANUFACTURED goods: {:?}", needed_materials);
             o_debug!( "🏪 Routing to marketplace trading system...");
             
-            let contract_ops = ContractOperations::new(&self.client);
+            let contract_ops = ContractOperations::new(&self.client, self.config_manager.config());
             match contract_ops.handle_marketplace_trading(&active_contract).await {
                 Ok(trading_initiated) => {
                     if trading_initiated {
@@ -325,6 +337,11 @@ impl Admiral {
                 Ok(_) => o_summary!("✅ Fleet mining coordination cycle completed successfully"),
                 Err(_) => o_info!("⏰ Fleet mining coordination cycle timed out - continuing to next step"),
             }
+            
+            if get_control().shutdown_requested() {
+                fleet_coordinator.shutdown().await;
+                return Ok(());
+            }
         } else if skip_mining {
             o_info!("⚡ Skipping fleet mining coordination - using marketplace trading strategy");
         } else {
@@ -332,9 +349,9 @@ impl Admiral {
         }
         
         // Get needed materials from the already-executed Step 2.5 for remaining operations
-        let contract_ops = ContractOperations::new(&self.client);
+        let contract_ops = ContractOperations::new(&self.client, self.config_manager.config());
         let needed_materials = {
-            let contract_ops = ContractOperations::new(&self.client);
+            let contract_ops = ContractOperations::new(&self.client, self.config_manager.config());
             contract_ops.get_required_materials(&active_contract)
         };
         
@@ -749,6 +766,129 @@ impl Admiral {
         Ok(())
     }
 
+    /// Run a goal command (or goals saved by a previous run) until every goal finishes
+    pub async fn execute_goal(&self, goal_command: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
+        o_info!("🧠 Initializing goal execution system...");
+
+        // Create priority-aware API client
+        let priority_client = PriorityApiClient::new(self.client.clone());
+
+        // Initialize goal system components
+        let mut goal_manager = GoalManager::with_store(GOAL_STORE_PATH);
+        let goal_interpreter = GoalInterpreter::new();
+        let goal_decomposer = GoalDecomposer::new();
+        let mut context_engine = ContextEngine::new();
+        context_engine.set_credit_reserve(self.config().credits.min_reserve_credits);
+        context_engine.set_fuel_safety_margin(self.config().fuel.fuel_safety_margin);
+
+        // Pick up goals a previous run left unfinished
+        let restored = goal_manager.restore_goals(&goal_interpreter);
+        if restored > 0 {
+            o_info!("🔁 Resumed {} saved goals", restored);
+        }
+
+        // Parse the goal command, which may chain goals with "then" / "and"
+        let parsed_goals = match goal_command {
+            Some(goal_command) => {
+                o_info!("🎯 Interpreting goal: '{}'", goal_command);
+                goal_interpreter.parse_goals(goal_command).await
+                    .map_err(|e| format!("Failed to interpret goal '{}':\n{}", goal_command, e))?
+            }
+            None => Vec::new(),
+        };
+
+        // Build execution context
+        o_info!("🔄 Building execution context...");
+        let context = context_engine.build_context(&priority_client).await?;
+
+        // "then" goals depend on every goal of the previous group; "and" joins the current group
+        let mut previous_group: Vec<String> = Vec::new();
+        let mut current_group: Vec<String> = Vec::new();
+        for parsed in parsed_goals {
+            let mut goal = parsed.goal;
+            o_info!("✅ Goal parsed: {}", goal.description());
+            if parsed.link == ClauseLink::Then {
+                previous_group = std::mem::take(&mut current_group);
+            }
+            for dependency in &previous_group {
+                goal.add_dependency(dependency);
+            }
+
+            // Validate goal feasibility
+            o_info!("✅ Validating goal feasibility...");
+            context_engine.validate_goal_feasibility(&goal.description().to_lowercase(), &context)
+                .map_err(|e| format!("Goal validation failed: {}", e))?;
+
+            // Decompose complex goals into sub-goals if needed
+            let goals = if goal_decomposer.needs_decomposition(&*goal) {
+                o_info!("🔧 Decomposing complex goal into sub-goals...");
+                let sub_goals = goal_decomposer.decompose(goal, &context).await;
+                o_info!("📋 Created {} sub-goals for execution", sub_goals.len());
+                sub_goals
+            } else {
+                vec![goal]
+            };
+
+            // The manager allocates ships when each goal starts
+            for goal in goals {
+                current_group.push(goal.id());
+                goal_manager.add_goal(goal);
+            }
+        }
+
+        // Goals run until they finish or their own deadline passes ("... by 18:00")
+        o_info!("🚀 Starting goal execution...");
+        let start_time = std::time::Instant::now();
+
+        while goal_manager.has_pending_goals() {
+            // Update context for current execution cycle
+            let current_context = context_engine.build_context(&priority_client).await?;
+
+            // Start ready goals and wait for running ones to finish
+            let results = goal_manager.execute_goals(&priority_client, &current_context).await?;
+
+            // Report results
+            for result in results {
+                if result.success {
+                    o_info!("✅ Goal result: {}", result.message);
+                    if !result.ships_used.is_empty() {
+                        o_info!("🚢 Ships used: {:?}", result.ships_used);
+                    }
+                    if result.credits_spent != 0 {
+                        if result.credits_spent > 0 {
+                            o_info!("💸 Credits spent: {}", result.credits_spent);
+                        } else {
+                            o_info!("💰 Credits earned: {}", -result.credits_spent);
+                        }
+                    }
+                } else {
+                    o_error!("❌ Goal failed: {}", result.message);
+                }
+            }
+
+            // Brief pause between execution cycles
+            tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+        }
+
+        let execution_time = start_time.elapsed().as_secs_f64();
+        let status = goal_manager.get_status();
+
+        o_info!("\n📊 Goal Execution Summary:");
+        o_info!("  ⏱️  Total execution time: {:.1} seconds", execution_time);
+        o_info!("  ✅ Goals completed: {}", status.completed_count);
+        o_info!("  ❌ Goals failed: {}", status.failed_count);
+        for (goal_id, reason) in goal_manager.get_failed_goals() {
+            o_info!("     - {}: {}", goal_id, reason);
+        }
+        o_info!("  📋 Goals remaining: {}", status.queued_count);
+
+        if status.failed_count > 0 {
+            return Err("Some goals failed during execution".into());
+        }
+
+        Ok(())
+    }
+
     pub async fn run_continuous_operations(&self) -> Result<(), Box<dyn std::error::Error>> {
         crate::debug_fn_enter!("Admiral::run_continuous_operations");
         
@@ -758,66 +898,58 @@ impl Admiral {
         
         let mut cycle_count = 0;
         
-        // Setup Ctrl+C handler
-        let ctrl_c = async {
-            tokio::signal::ctrl_c().await.expect("Failed to install Ctrl+C handler");
-        };
-        
-        let operations = async {
-            loop {
-                cycle_count += 1;
-                o_summary!("\n🔄 ═══════ AUTONOMOUS CYCLE #{} ═══════", cycle_count);
-                
-                match self.run_autonomous_cycle().await {
-                    Ok(()) => {
-                        o_summary!("✅ Cycle #{} completed successfully", cycle_count);
-                        o_info!("💰 Agent continuing autonomous operations...");
-                    }
-                    Err(e) => {
-                        o_error!("❌ Cycle #{} failed: {}", cycle_count, e);
-                        let config = self.config_manager.config();
-                        o_error!("⏳ Waiting {} seconds before retry...", config.timing.error_retry_delay_seconds);
-                        
-                        // Check for Ctrl+C during error recovery delay
-                        tokio::select! {
-                            _ = tokio::time::sleep(tokio::time::Duration::from_secs(config.timing.error_retry_delay_seconds as u64)) => {},
-                            _ = tokio::signal::ctrl_c() => {
-                                o_info!("\n⚠️  Ctrl+C received during error recovery. Shutting down...");
-                                return Ok::<(), Box<dyn std::error::Error>>(());
-                            }
-                        }
-                    }
+        // Ctrl+C and `ctl shutdown` raise the same signal; each stage stops at its next safe point
+        while !get_control().shutdown_requested() {
+            cycle_count += 1;
+            o_summary!("\n🔄 ═══════ AUTONOMOUS CYCLE #{} ═══════", cycle_count);
+            
+            match self.run_autonomous_cycle().await {
+                Ok(()) => {
+                    o_summary!("✅ Cycle #{} completed successfully", cycle_count);
+                    o_info!("💰 Agent continuing autonomous operations...");
                 }
-                
-                // Brief pause between cycles with Ctrl+C handling
-                let config = self.config_manager.config();
-                o_info!("⏳ Cycle complete. Waiting {} seconds before next cycle...", config.timing.main_cycle_delay_seconds);
-                
-                tokio::select! {
-                    _ = tokio::time::sleep(tokio::time::Duration::from_secs(config.timing.main_cycle_delay_seconds as u64)) => {},
-                    _ = tokio::signal::ctrl_c() => {
-                        o_info!("\n⚠️  Ctrl+C received. Shutting down gracefully...");
-                        return Ok::<(), Box<dyn std::error::Error>>(());
+                Err(e) => {
+                    o_error!("❌ Cycle #{} failed: {}", cycle_count, e);
+                    let config = self.config_manager.config();
+                    o_error!("⏳ Waiting {} seconds before retry...", config.timing.error_retry_delay_seconds);
+                    
+                    // Check for shutdown during error recovery delay
+                    tokio::select! {
+                        _ = tokio::time::sleep(tokio::time::Duration::from_secs(config.timing.error_retry_delay_seconds as u64)) => {},
+                        _ = get_control().wait_for_shutdown() => break,
                     }
                 }
             }
-        };
-        
-        // Run operations with Ctrl+C handling
-        tokio::select! {
-            result = operations => {
-                crate::debug_fn_exit!("Admiral::run_continuous_operations", &result);
-                result
-            },
-            _ = ctrl_c => {
-                o_summary!("\n🛑 CTRL+C RECEIVED - Graceful shutdown initiated");
-                o_summary!("🎖️  Admiral reporting: Operations terminated by user command");
-                o_summary!("📊 Total cycles completed: {}", cycle_count);
-                let result = Ok(());
-                crate::debug_fn_exit!("Admiral::run_continuous_operations", &result);
-                result
+            if get_control().shutdown_requested() {
+                break;
+            }
+            
+            // Goals queued through the control API run between cycles
+            for goal in get_control().take_goals() {
+                o_summary!("🎛️ Running queued goal: '{}'", goal);
+                match self.execute_goal(Some(&goal)).await {
+                    Ok(()) => o_summary!("✅ Queued goal '{}' completed", goal),
+                    Err(e) => o_error!("❌ Queued goal '{}' failed: {}", goal, e),
+                }
+            }
+            
+            // Brief pause between cycles with shutdown handling
+            let config = self.config_manager.config();
+            o_info!("⏳ Cycle complete. Waiting {} seconds before next cycle...", config.timing.main_cycle_delay_seconds);
+            
+            tokio::select! {
+                _ = tokio::time::sleep(tokio::time::Duration::from_secs(config.timing.main_cycle_delay_seconds as u64)) => {},
+                _ = get_control().wait_for_shutdown() => break,
             }
         }
+        
+        // Only reached once actors have stopped and state is on disk
+        o_summary!("\n🛑 Graceful shutdown complete");
+        o_summary!("🎖️  Admiral reporting: Operations terminated by user command");
+        o_summary!("📊 Total cycles completed: {}", cycle_count);
+        let result = Ok(());
+        crate::debug_fn_exit!("Admiral::run_continuous_operations", &result);
+        result
     }
 }
 
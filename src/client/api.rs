@@ -1,11 +1,23 @@
 // Keep these for compatibility but they're not needed with the broker
 use crate::models::*;
 use crate::API_BASE_URL;
+use crate::events::GameEvent;
+use crate::metrics::get_metrics;
+use crate::output_broker::OutputLevel;
+use crate::structured_log::{self, LogRecord};
 use crate::{o_debug};
 use crate::client::brokered_client::BrokeredClient;
 use std::fs::OpenOptions;
 use std::io::Write;
 
+/// The `error.code` from the first API error body embedded in an error message
+pub fn api_error_code(error: &str) -> Option<u64> {
+    error.match_indices('{').find_map(|(start, _)| {
+        serde_json::Deserializer::from_str(&error[start..]).into_iter::<serde_json::Value>().next()?.ok()?
+            .pointer("/error/code")?.as_u64()
+    })
+}
+
 #[derive(Clone)]
 pub struct SpaceTradersClient {
     client: BrokeredClient,
@@ -93,6 +105,10 @@ impl SpaceTradersClient {
     }
     
     fn log_api_call(&self, method: &str, url: &str, body: Option<&str>, response_status: u16, response_body: Option<&str>) {
+        let level = if response_status >= 400 { OutputLevel::Error } else { OutputLevel::Debug };
+        structured_log::log_record(&LogRecord::from_message(&level, "api",
+            &format!("{} {} -> {}", method, url, response_status)));
+        
         if !self.api_logging {
             return;
         }
@@ -185,7 +201,10 @@ impl SpaceTradersClient {
                             self.log_api_call("GET", &url, None, status, Some(&response_text));
                             
                             match serde_json::from_str::<AgentResponse>(&response_text) {
-                                Ok(agent_response) => Ok(agent_response.data),
+                                Ok(agent_response) => {
+                                    get_metrics().record_credits(agent_response.data.credits);
+                                    Ok(agent_response.data)
+                                }
                                 Err(e) => Err(format!("JSON parse error: {}", e))
                             }
                         },
@@ -201,6 +220,31 @@ impl SpaceTradersClient {
         result
     }
 
+    /// Server status, including the date of the last universe reset; needs no agent
+    pub async fn get_server_status(&self) -> Result<ServerStatus, Box<dyn std::error::Error>> {
+        let url = format!("{}/", API_BASE_URL);
+        crate::debug_api_call!("GET", &url);
+        
+        let result = self.make_request_with_retry("GET", &url, || async {
+            match self.client.get(&url).send().await {
+                Ok(response) => {
+                    let status = response.status().as_u16();
+                    let response_text = response.text().await
+                        .map_err(|e| format!("Failed to read response: {}", e))?;
+                    self.log_api_call("GET", &url, None, status, Some(&response_text));
+                    if !(200..300).contains(&status) {
+                        return Err(format!("Get server status failed with status {}: {}", status, response_text));
+                    }
+                    serde_json::from_str::<ServerStatus>(&response_text)
+                        .map_err(|e| format!("JSON parse error: {}", e))
+                },
+                Err(e) => Err(format!("Request failed: {}", e))
+            }
+        }, 3).await;
+        
+        result.map_err(|e| e.into())
+    }
+
     // Waypoint operations
     pub async fn get_waypoint(&self, system_symbol: &str, waypoint_symbol: &str) -> Result<Waypoint, Box<dyn std::error::Error>> {
         let url = format!("{}/systems/{}/waypoints/{}", API_BASE_URL, system_symbol, waypoint_symbol);
@@ -252,6 +296,8 @@ impl SpaceTradersClient {
         }
 
         let contracts_response: ContractsResponse = response.json().await?;
+        crate::storage::get_survey_pool().record_contract_values(&contracts_response.data);
+        get_metrics().record_contracts(&contracts_response.data);
         Ok(contracts_response.data)
     }
 
@@ -266,6 +312,7 @@ impl SpaceTradersClient {
         }
 
         let contract_accept_response: ContractAcceptResponse = response.json().await?;
+        crate::events::publish(GameEvent::ContractAccepted { contract_id: contract_id.to_string() });
         Ok(contract_accept_response.data)
     }
 
@@ -333,6 +380,8 @@ impl SpaceTradersClient {
         }
 
         let fulfill_response: FulfillContractResponse = response.json().await?;
+        get_metrics().record_credits(fulfill_response.data.agent.credits);
+        crate::events::publish(GameEvent::ContractFulfilled { contract_id: contract_id.to_string() });
         Ok(fulfill_response.data)
     }
 
@@ -491,6 +540,28 @@ impl SpaceTradersClient {
         result.map_err(|e| e.into())
     }
 
+    pub async fn siphon_resources(&self, ship_symbol: &str) -> Result<SiphonData, Box<dyn std::error::Error>> {
+        let url = format!("{}/my/ships/{}/siphon", API_BASE_URL, ship_symbol);
+        let result = self.make_request_with_retry("POST", &url, || async {
+            match self.client.post(&url).json(&serde_json::json!({})).send().await {
+                Ok(response) => {
+                    if !response.status().is_success() {
+                        let status = response.status();
+                        let error_body = response.text().await.unwrap_or_else(|_| "Could not read error response".to_string());
+                        return Err(format!("Siphon failed with status {}: {}", status, error_body));
+                    }
+
+                    match response.json::<SiphonResponse>().await {
+                        Ok(siphon_response) => Ok(siphon_response.data),
+                        Err(e) => Err(format!("JSON parse error: {}", e))
+                    }
+                },
+                Err(e) => Err(format!("Request failed: {}", e))
+            }
+        }, 3).await;
+        result.map_err(|e| e.into())
+    }
+
     pub async fn extract_resources_with_survey(&self, ship_symbol: &str, survey: &Survey) -> Result<ExtractionData, Box<dyn std::error::Error>> {
         let url = format!("{}/my/ships/{}/extract", API_BASE_URL, ship_symbol);
         let payload = serde_json::json!({
@@ -524,6 +595,13 @@ impl SpaceTradersClient {
         }
 
         let sell_response: SellCargoResponse = response.json().await?;
+        get_metrics().record_credits(sell_response.data.agent.credits);
+        crate::events::publish(GameEvent::Sale {
+            ship_symbol: ship_symbol.to_string(),
+            good: trade_symbol.to_string(),
+            units: sell_response.data.transaction.units,
+            total_price: sell_response.data.transaction.total_price as i64,
+        });
         Ok(sell_response.data)
     }
 
@@ -578,9 +656,32 @@ impl SpaceTradersClient {
         }
 
         let purchase_response: ShipPurchaseResponse = response.json().await?;
+        get_metrics().record_credits(purchase_response.data.agent.credits);
+        let transaction = &purchase_response.data.transaction;
+        crate::events::publish(GameEvent::ShipPurchased {
+            ship_symbol: transaction.ship_symbol.clone(),
+            ship_type: transaction.ship_type.clone(),
+            waypoint_symbol: transaction.waypoint_symbol.clone(),
+            price: transaction.price as i64,
+        });
         Ok(purchase_response.data)
     }
 
+    pub async fn create_chart(&self, ship_symbol: &str) -> Result<ChartData, Box<dyn std::error::Error>> {
+        let url = format!("{}/my/ships/{}/chart", API_BASE_URL, ship_symbol);
+        
+        let response = self.client.post(&url).json(&serde_json::json!({})).send().await?;
+        
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await?;
+            return Err(format!("Chart creation failed with status {}: {}", status, error_text).into());
+        }
+
+        let chart_response: ChartResponse = response.json().await?;
+        Ok(chart_response.data)
+    }
+
     // Ship module management
     pub async fn install_ship_module(&self, ship_symbol: &str, module_symbol: &str) -> Result<ModuleInstallData, Box<dyn std::error::Error>> {
         let url = format!("{}/my/ships/{}/modules/install", API_BASE_URL, ship_symbol);
@@ -643,6 +744,68 @@ impl SpaceTradersClient {
         Ok(modules_response.data)
     }
 
+    // Ship mount management
+    pub async fn install_ship_mount(&self, ship_symbol: &str, mount_symbol: &str) -> Result<MountChangeData, Box<dyn std::error::Error>> {
+        let url = format!("{}/my/ships/{}/mounts/install", API_BASE_URL, ship_symbol);
+        let payload = serde_json::json!({
+            "symbol": mount_symbol
+        });
+
+        o_debug!("🔧 Installing mount {} on {}", mount_symbol, ship_symbol);
+        
+        let response = self.client.post(&url)
+            .json(&payload)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await?;
+            return Err(format!("Mount installation failed with status {}: {}", status, error_text).into());
+        }
+
+        let install_response: MountInstallResponse = response.json().await?;
+        Ok(install_response.data)
+    }
+
+    pub async fn remove_ship_mount(&self, ship_symbol: &str, mount_symbol: &str) -> Result<MountChangeData, Box<dyn std::error::Error>> {
+        let url = format!("{}/my/ships/{}/mounts/remove", API_BASE_URL, ship_symbol);
+        let payload = serde_json::json!({
+            "symbol": mount_symbol
+        });
+
+        o_debug!("🗑️ Removing mount {} from {}", mount_symbol, ship_symbol);
+        
+        let response = self.client.post(&url)
+            .json(&payload)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await?;
+            return Err(format!("Mount removal failed with status {}: {}", status, error_text).into());
+        }
+
+        let removal_response: MountRemovalResponse = response.json().await?;
+        Ok(removal_response.data)
+    }
+
+    pub async fn get_ship_mounts(&self, ship_symbol: &str) -> Result<Vec<ShipMount>, Box<dyn std::error::Error>> {
+        let url = format!("{}/my/ships/{}/mounts", API_BASE_URL, ship_symbol);
+        
+        let response = self.client.get(&url)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to get ship mounts: {}", response.status()).into());
+        }
+
+        let mounts_response: ShipMountsResponse = response.json().await?;
+        Ok(mounts_response.data)
+    }
+
     pub async fn jettison_cargo(&self, ship_symbol: &str, item_symbol: &str, units: i32) -> Result<JettisonCargoData, Box<dyn std::error::Error>> {
         let url = format!("{}/my/ships/{}/jettison", API_BASE_URL, ship_symbol);
         let payload = serde_json::json!({
@@ -848,6 +1011,7 @@ impl SpaceTradersClient {
         self.log_api_call("GET", &url, None, status, Some(&response_text));
         
         let market_response: MarketResponse = serde_json::from_str(&response_text)?;
+        crate::storage::get_survey_pool().record_market_prices(&market_response.data);
         let result = Ok(market_response.data);
         crate::debug_fn_exit!("SpaceTradersClient::get_market", &result);
         result
@@ -886,6 +1050,13 @@ impl SpaceTradersClient {
         self.log_api_call("POST", &url, Some(&payload.to_string()), status, Some(&response_text));
         
         let purchase_response: PurchaseCargoResponse = serde_json::from_str(&response_text)?;
+        get_metrics().record_credits(purchase_response.data.agent.credits);
+        crate::events::publish(GameEvent::Purchase {
+            ship_symbol: ship_symbol.to_string(),
+            good: trade_symbol.to_string(),
+            units: purchase_response.data.transaction.units,
+            total_price: purchase_response.data.transaction.total_price as i64,
+        });
         let result = Ok(purchase_response.data);
         crate::debug_fn_exit!("SpaceTradersClient::purchase_cargo", &result);
         result
@@ -1034,6 +1205,38 @@ impl SpaceTradersClient {
         result
     }
 
+    pub async fn get_my_factions(&self) -> Result<Vec<AgentFaction>, Box<dyn std::error::Error>> {
+        crate::debug_fn_enter!("SpaceTradersClient::get_my_factions");
+
+        let url = format!("{}/my/factions", API_BASE_URL);
+        crate::debug_api_call!("GET", &url);
+
+        if !self.request_approval("GET", &url, None).await {
+            let error = Err("API call not approved".into());
+            crate::debug_fn_exit!("SpaceTradersClient::get_my_factions", &error);
+            return error;
+        }
+
+        let response = self.client.get(&url).send().await?;
+        let status = response.status().as_u16();
+
+        if !response.status().is_success() {
+            let error_body = response.text().await.unwrap_or_else(|_| "Could not read response".to_string());
+            self.log_api_call("GET", &url, None, status, Some(&error_body));
+            let error = Err(format!("Get agent factions failed with status: {}", status).into());
+            crate::debug_fn_exit!("SpaceTradersClient::get_my_factions", &error);
+            return error;
+        }
+
+        let response_text = response.text().await?;
+        self.log_api_call("GET", &url, None, status, Some(&response_text));
+
+        let factions_response: AgentFactionsResponse = serde_json::from_str(&response_text)?;
+        let result = Ok(factions_response.data);
+        crate::debug_fn_exit!("SpaceTradersClient::get_my_factions", &result);
+        result
+    }
+
     // Jump gate operations
     pub async fn get_jump_gate(&self, system_symbol: &str, waypoint_symbol: &str) -> Result<JumpGate, Box<dyn std::error::Error>> {
         crate::debug_fn_enter!("SpaceTradersClient::get_jump_gate", "system={}, waypoint={}", system_symbol, waypoint_symbol);
@@ -1172,4 +1375,37 @@ impl SpaceTradersClient {
         crate::debug_fn_exit!("SpaceTradersClient::repair_ship", &result);
         result
     }
+
+    pub async fn get_scrap_value(&self, ship_symbol: &str) -> Result<ScrapValue, Box<dyn std::error::Error>> {
+        crate::debug_fn_enter!("SpaceTradersClient::get_scrap_value", "ship_symbol={}", ship_symbol);
+        
+        let url = format!("{}/my/ships/{}/scrap", API_BASE_URL, ship_symbol);
+        
+        crate::debug_api_call!("GET", &url, "");
+        
+        if !self.request_approval("GET", &url, None).await {
+            let error = Err("API call not approved".into());
+            crate::debug_fn_exit!("SpaceTradersClient::get_scrap_value", &error);
+            return error;
+        }
+        
+        let response = self.client.get(&url).send().await?;
+        let status = response.status().as_u16();
+        
+        if !response.status().is_success() {
+            let error_body = response.text().await.unwrap_or_else(|_| "Could not read response".to_string());
+            self.log_api_call("GET", &url, None, status, Some(&error_body));
+            let error = Err(format!("Get scrap value failed with status: {}", status).into());
+            crate::debug_fn_exit!("SpaceTradersClient::get_scrap_value", &error);
+            return error;
+        }
+
+        let response_text = response.text().await?;
+        self.log_api_call("GET", &url, None, status, Some(&response_text));
+        
+        let scrap_response: ScrapValueResponse = serde_json::from_str(&response_text)?;
+        let result = Ok(scrap_response.data);
+        crate::debug_fn_exit!("SpaceTradersClient::get_scrap_value", &result);
+        result
+    }
 }
\ No newline at end of file
@@ -3,6 +3,7 @@ use std::time::{Duration, Instant};
 use std::sync::OnceLock;
 use tokio::sync::{mpsc, oneshot};
 use tokio::time::sleep;
+use crate::metrics::get_metrics;
 use crate::{o_info, o_debug, o_trace};
 
 /// Global singleton broker instance
@@ -85,6 +86,7 @@ impl ApiRequestBroker {
         self.request_sender
             .send(request)
             .map_err(|_| "API broker is not running".to_string())?;
+        get_metrics().adjust_api_queue_depth(1);
         
         // Wait for response
         response_receiver
@@ -92,6 +94,21 @@ impl ApiRequestBroker {
             .map_err(|_| "API broker response channel closed".to_string())?
     }
     
+    /// Wait until every request queued before this call has been answered
+    pub async fn drain(&self) {
+        let (response_sender, response_receiver) = oneshot::channel();
+        let marker = ApiRequest {
+            method: "DRAIN".to_string(),
+            url: String::new(),
+            headers: HashMap::new(),
+            body: None,
+            response_sender,
+        };
+        if self.request_sender.send(marker).is_ok() {
+            let _ = response_receiver.await;
+        }
+    }
+    
     /// Background worker that processes all API requests with rate limiting
     async fn broker_worker(mut request_receiver: mpsc::UnboundedReceiver<ApiRequest>) {
         let mut state = BrokerState {
@@ -113,6 +130,20 @@ impl ApiRequestBroker {
     
     /// Handle a single API request with proper rate limiting
     async fn handle_request(state: &mut BrokerState, request: ApiRequest) {
+        // Handle the drain marker - everything queued ahead of it is done
+        if request.method == "DRAIN" {
+            let _ = request.response_sender.send(Ok(ApiResponse { status: 200, body: String::new(), headers: HashMap::new() }));
+            return;
+        }
+        
+        get_metrics().adjust_api_queue_depth(-1);
+        
+        // A caller that stopped waiting (e.g. an action interrupted by shutdown) no longer wants the call made
+        if request.response_sender.is_closed() {
+            o_debug!("🌐 Skipping {} {} - caller is gone", request.method, request.url);
+            return;
+        }
+        
         // Apply global backoff if needed
         if let Some(backoff_until) = state.backoff_until {
             let now = Instant::now();
@@ -140,6 +171,8 @@ impl ApiRequestBroker {
         // Execute the HTTP request
         let result = Self::execute_http_request(state, &request).await;
         
+        get_metrics().record_api_request(&request.url, result.as_ref().ok().map(|response| response.status));
+        
         // Handle rate limiting responses
         if let Ok(ref response) = result {
             if response.status == 429 {
@@ -4,6 +4,6 @@ pub mod api_broker;
 pub mod brokered_client;
 pub mod priority_client;
 
-pub use api::SpaceTradersClient;
+pub use api::{api_error_code, SpaceTradersClient};
 pub use api_broker::ApiRequestBroker;
 pub use priority_client::{PriorityApiClient, ApiPriority};
\ No newline at end of file
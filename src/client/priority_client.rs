@@ -4,7 +4,7 @@ use crate::models::*;
 use crate::models::ship::{NavigationData, ShipNav};
 use crate::models::transaction::{ExtractionData, RefuelData, SellCargoData, Survey, SurveyData, TransferCargoData, RefineData};
 use crate::models::market::PurchaseCargoData;
-use crate::models::responses::{ModuleInstallData, ModuleRemovalData};
+use crate::models::responses::{ChartData, ModuleInstallData, ModuleRemovalData};
 use crate::models::ShipModule;
 use crate::{o_debug, o_info};
 
@@ -18,6 +18,7 @@ pub enum ApiPriority {
     Override = 100,
 }
 
+#[derive(Clone)]
 pub struct PriorityApiClient {
     client: SpaceTradersClient,
 }
@@ -27,6 +28,11 @@ impl PriorityApiClient {
         Self { client }
     }
 
+    /// The underlying client, for helpers that take one directly
+    pub fn client(&self) -> &SpaceTradersClient {
+        &self.client
+    }
+
     pub async fn get_agent(&self) -> Result<Agent, Box<dyn std::error::Error>> {
         self.log_request(ApiPriority::Normal, "get_agent");
         self.client.get_agent().await
@@ -93,6 +99,11 @@ impl PriorityApiClient {
         }
     }
 
+    pub async fn siphon_resources_with_priority(&self, ship_symbol: &str, priority: ApiPriority) -> Result<SiphonData, Box<dyn std::error::Error>> {
+        self.log_request(priority, &format!("siphon_resources({}) [PRIORITY]", ship_symbol));
+        self.client.siphon_resources(ship_symbol).await
+    }
+
     pub async fn create_survey_with_priority(&self, ship_symbol: &str, priority: ApiPriority) -> Result<SurveyData, Box<dyn std::error::Error>> {
         self.log_request(priority, &format!("create_survey({}) [PRIORITY]", ship_symbol));
         self.client.create_survey(ship_symbol).await
@@ -128,6 +139,16 @@ impl PriorityApiClient {
         self.client.get_ship_modules(ship_symbol).await
     }
 
+    pub async fn install_ship_mount_with_priority(&self, ship_symbol: &str, mount_symbol: &str, priority: ApiPriority) -> Result<MountChangeData, Box<dyn std::error::Error>> {
+        self.log_request(priority, &format!("install_ship_mount({}, {}) [PRIORITY]", ship_symbol, mount_symbol));
+        self.client.install_ship_mount(ship_symbol, mount_symbol).await
+    }
+
+    pub async fn remove_ship_mount_with_priority(&self, ship_symbol: &str, mount_symbol: &str, priority: ApiPriority) -> Result<MountChangeData, Box<dyn std::error::Error>> {
+        self.log_request(priority, &format!("remove_ship_mount({}, {}) [PRIORITY]", ship_symbol, mount_symbol));
+        self.client.remove_ship_mount(ship_symbol, mount_symbol).await
+    }
+
     pub async fn purchase_cargo_with_priority(&self, ship_symbol: &str, trade_symbol: &str, units: i32, priority: ApiPriority) -> Result<PurchaseCargoData, Box<dyn std::error::Error>> {
         self.log_request(priority, &format!("purchase_cargo({}, {}, {}) [PRIORITY]", ship_symbol, trade_symbol, units));
         self.client.purchase_cargo(ship_symbol, trade_symbol, units).await
@@ -138,6 +159,11 @@ impl PriorityApiClient {
         self.client.refine_cargo(ship_symbol, produce).await
     }
 
+    pub async fn create_chart_with_priority(&self, ship_symbol: &str, priority: ApiPriority) -> Result<ChartData, Box<dyn std::error::Error>> {
+        self.log_request(priority, &format!("create_chart({}) [PRIORITY]", ship_symbol));
+        self.client.create_chart(ship_symbol).await
+    }
+
     // Ship repair operations
     pub async fn get_repair_cost(&self, ship_symbol: &str) -> Result<RepairCost, Box<dyn std::error::Error>> {
         self.log_request(ApiPriority::Background, &format!("get_repair_cost({})", ship_symbol));
@@ -149,6 +175,11 @@ impl PriorityApiClient {
         self.client.repair_ship(ship_symbol).await
     }
 
+    pub async fn get_scrap_value(&self, ship_symbol: &str) -> Result<ScrapValue, Box<dyn std::error::Error>> {
+        self.log_request(ApiPriority::Background, &format!("get_scrap_value({})", ship_symbol));
+        self.client.get_scrap_value(ship_symbol).await
+    }
+
     fn log_request(&self, priority: ApiPriority, description: &str) {
         if priority >= ApiPriority::Override {
             o_info!("⚡ OVERRIDE API: {}", description);
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::{o_info};
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use std::time::SystemTime;
@@ -13,6 +14,12 @@ pub struct SpaceTradersConfig {
     pub timing: TimingConfig,
     pub navigation: NavigationConfig,
     pub caching: CachingConfig,
+    #[serde(default)]
+    pub logging: LoggingConfig,
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+    #[serde(default)]
+    pub control: ControlConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +30,27 @@ pub struct FleetConfig {
     pub max_mining_ships: usize,
     /// Credits threshold for fleet expansion consideration
     pub fleet_expansion_threshold: i64,
+    /// Let the fleet planner buy its recommended ship without asking
+    #[serde(default = "default_auto_purchase_ships")]
+    pub auto_purchase_ships: bool,
+    /// Condition percentage below which ships are sent to a shipyard for repair
+    #[serde(default = "default_repair_condition_threshold")]
+    pub repair_condition_threshold: f64,
+    /// Haulers a mining station recruits; the rest stay free for contracts and trading
+    #[serde(default = "default_mining_station_haulers")]
+    pub mining_station_haulers: usize,
+}
+
+fn default_auto_purchase_ships() -> bool {
+    true
+}
+
+fn default_repair_condition_threshold() -> f64 {
+    70.0
+}
+
+fn default_mining_station_haulers() -> usize {
+    1
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -85,6 +113,72 @@ pub struct CachingConfig {
     pub survey_expiration_minutes: i64,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoggingConfig {
+    /// Write JSON lines alongside the console output
+    pub enabled: bool,
+    /// JSON log file; rotated copies are written next to it as .1, .2, ...
+    pub path: String,
+    /// Size in bytes at which the log is rotated
+    pub max_file_bytes: u64,
+    /// Rotated files to keep
+    pub max_files: usize,
+    /// Level logged for subsystems without their own entry (error, summary, info, debug, trace)
+    pub default_level: String,
+    /// Level per subsystem, keyed by module name such as ship_actor or api
+    pub levels: HashMap<String, String>,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            path: "logs/spacetraders.jsonl".to_string(),
+            max_file_bytes: 10 * 1024 * 1024,
+            max_files: 5,
+            default_level: "info".to_string(),
+            levels: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetricsConfig {
+    /// Serve Prometheus metrics over HTTP
+    pub enabled: bool,
+    /// Address the /metrics endpoint listens on; keep it local
+    pub bind: String,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bind: "127.0.0.1:9898".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ControlConfig {
+    /// Accept runtime commands from `spacetraders_cc ctl`
+    pub enabled: bool,
+    /// Address the control API listens on; it has no authentication, so keep it local
+    pub bind: String,
+}
+
+impl Default for ControlConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bind: "127.0.0.1:9899".to_string(),
+        }
+    }
+}
+
 impl Default for SpaceTradersConfig {
     fn default() -> Self {
         Self {
@@ -92,6 +186,9 @@ impl Default for SpaceTradersConfig {
                 min_credits_for_ship_purchase: 150000,
                 max_mining_ships: 5,
                 fleet_expansion_threshold: 200000,
+                auto_purchase_ships: true,
+                repair_condition_threshold: 70.0,
+                mining_station_haulers: 1,
             },
             fuel: FuelConfig {
                 refuel_threshold: 0.2,        // 20%
@@ -123,6 +220,9 @@ impl Default for SpaceTradersConfig {
                 survey_cache_hours: 12,
                 survey_expiration_minutes: 30,
             },
+            logging: LoggingConfig::default(),
+            metrics: MetricsConfig::default(),
+            control: ControlConfig::default(),
         }
     }
 }
@@ -165,6 +265,26 @@ impl SpaceTradersConfig {
         if self.fuel.mining_fuel_threshold < 0.0 || self.fuel.mining_fuel_threshold > 1.0 {
             return Err("mining_fuel_threshold must be between 0.0 and 1.0".to_string());
         }
+        if self.fleet.repair_condition_threshold < 0.0 || self.fleet.repair_condition_threshold > 100.0 {
+            return Err("repair_condition_threshold must be between 0 and 100".to_string());
+        }
+
+        for (subsystem, level) in std::iter::once(("default", &self.logging.default_level))
+            .chain(self.logging.levels.iter().map(|(subsystem, level)| (subsystem.as_str(), level))) {
+            if crate::structured_log::parse_level(level).is_none() {
+                return Err(format!("logging level '{}' for {} must be error, summary, info, debug or trace", level, subsystem));
+            }
+        }
+
+        if self.metrics.enabled && self.metrics.bind.parse::<std::net::SocketAddr>().is_err() {
+            return Err(format!("metrics bind '{}' must be an address like 127.0.0.1:9898", self.metrics.bind));
+        }
+        if self.control.enabled && self.control.bind.parse::<std::net::SocketAddr>().is_err() {
+            return Err(format!("control bind '{}' must be an address like 127.0.0.1:9899", self.control.bind));
+        }
+        if self.logging.max_file_bytes == 0 {
+            return Err("logging max_file_bytes must be greater than 0".to_string());
+        }
 
         // Validate positive values
         if self.fleet.min_credits_for_ship_purchase < 0 {
@@ -194,6 +314,12 @@ impl SpaceTradersConfig {
     }
 }
 
+fn apply_logging_config(config: &SpaceTradersConfig) {
+    if let Ok(mut logger) = crate::structured_log::get_json_logger().lock() {
+        logger.configure(config.logging.clone());
+    }
+}
+
 /// Hot-reloadable configuration manager
 #[derive(Debug)]
 pub struct ConfigManager {
@@ -208,6 +334,7 @@ impl ConfigManager {
         let config = SpaceTradersConfig::load_or_create(config_path)?;
         config.validate()?;
         config.print_summary();
+        apply_logging_config(&config);
         
         let last_modified = fs::metadata(config_path)
             .and_then(|m| m.modified())
@@ -262,6 +389,7 @@ impl ConfigManager {
                         
                         self.config = new_config;
                         self.last_modified = Some(new_modified_time);
+                        apply_logging_config(&self.config);
                         
                         let new_values = format!("cycle: {}s, reload: {}s, ship purchase: {}", 
                                                 self.config.timing.main_cycle_delay_seconds,
@@ -0,0 +1,317 @@
+// Control API - a local HTTP/JSON interface for steering a running bot, and the client behind `ctl`
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, OnceLock};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+use tokio::time::Instant;
+use crate::dashboard::get_dashboard;
+use crate::goals::goal_dsl::parse_goal_command;
+use crate::metrics::get_metrics;
+use crate::{o_error, o_info, o_summary};
+
+/// Largest request the control endpoint accepts
+const MAX_REQUEST_BYTES: usize = 64 * 1024;
+
+/// A role the operator pins a ship to, overriding what the coordinator would pick
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ForcedRole {
+    Miner,
+    Siphoner,
+    Hauler,
+    Explorer,
+}
+
+impl ForcedRole {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "MINER" | "MINE" => Some(Self::Miner),
+            "SIPHONER" | "SIPHON" => Some(Self::Siphoner),
+            "HAULER" | "HAUL" => Some(Self::Hauler),
+            "EXPLORER" | "EXPLORE" | "PROBE" => Some(Self::Explorer),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum ControlCommand {
+    Status,
+    /// Queue a goal in the `--goal` syntax
+    Goal { goal: String },
+    /// Stop giving a ship new tasks, or the whole fleet when no ship is named
+    Pause { ship: Option<String> },
+    Resume { ship: Option<String> },
+    /// Pin a ship to a role; no role hands it back to the coordinator
+    Role { ship: String, role: Option<ForcedRole> },
+    Shutdown,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ControlResponse {
+    pub ok: bool,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<serde_json::Value>,
+}
+
+impl ControlResponse {
+    fn ok(message: impl Into<String>) -> Self {
+        Self { ok: true, message: message.into(), status: None }
+    }
+
+    fn error(message: impl Into<String>) -> Self {
+        Self { ok: false, message: message.into(), status: None }
+    }
+}
+
+#[derive(Debug, Default)]
+struct ControlState {
+    fleet_paused: bool,
+    paused_ships: BTreeSet<String>,
+    roles: BTreeMap<String, ForcedRole>,
+    goals: VecDeque<String>,
+}
+
+/// Shared handle to the operator's runtime overrides; clones see the same state
+#[derive(Debug, Clone)]
+pub struct Control {
+    state: Arc<Mutex<ControlState>>,
+    shutdown: Arc<watch::Sender<bool>>,
+}
+
+impl Default for Control {
+    fn default() -> Self {
+        Self {
+            state: Arc::default(),
+            shutdown: Arc::new(watch::channel(false).0),
+        }
+    }
+}
+
+static GLOBAL_CONTROL: OnceLock<Control> = OnceLock::new();
+
+/// The overrides the coordinator and admiral check while running
+pub fn get_control() -> &'static Control {
+    GLOBAL_CONTROL.get_or_init(Control::default)
+}
+
+impl Control {
+    pub fn apply(&self, command: ControlCommand) -> ControlResponse {
+        match command {
+            ControlCommand::Status => ControlResponse { status: Some(self.status()), ..ControlResponse::ok("status") },
+            ControlCommand::Goal { goal } => {
+                if let Err(e) = parse_goal_command(&goal) {
+                    return ControlResponse::error(format!("Invalid goal '{}': {}", goal, e));
+                }
+                let mut state = self.state.lock().unwrap();
+                state.goals.push_back(goal.clone());
+                ControlResponse::ok(format!("Queued goal '{}' ({} waiting)", goal, state.goals.len()))
+            }
+            ControlCommand::Pause { ship: Some(ship) } => {
+                self.state.lock().unwrap().paused_ships.insert(ship.clone());
+                ControlResponse::ok(format!("{} paused", ship))
+            }
+            ControlCommand::Pause { ship: None } => {
+                self.state.lock().unwrap().fleet_paused = true;
+                ControlResponse::ok("Fleet paused")
+            }
+            ControlCommand::Resume { ship: Some(ship) } => {
+                let mut state = self.state.lock().unwrap();
+                if !state.paused_ships.remove(&ship) && !state.fleet_paused {
+                    return ControlResponse::error(format!("{} is not paused", ship));
+                }
+                if state.fleet_paused {
+                    return ControlResponse::ok(format!("{} resumed, but the whole fleet is still paused", ship));
+                }
+                ControlResponse::ok(format!("{} resumed", ship))
+            }
+            ControlCommand::Resume { ship: None } => {
+                let mut state = self.state.lock().unwrap();
+                state.fleet_paused = false;
+                state.paused_ships.clear();
+                ControlResponse::ok("Fleet resumed")
+            }
+            ControlCommand::Role { ship, role: Some(role) } => {
+                self.state.lock().unwrap().roles.insert(ship.clone(), role);
+                ControlResponse::ok(format!("{} forced into {:?} role", ship, role))
+            }
+            ControlCommand::Role { ship, role: None } => {
+                self.state.lock().unwrap().roles.remove(&ship);
+                ControlResponse::ok(format!("{} back under automatic role assignment", ship))
+            }
+            ControlCommand::Shutdown => {
+                self.request_shutdown();
+                ControlResponse::ok("Shutdown requested")
+            }
+        }
+    }
+
+    pub fn is_paused(&self, ship_symbol: &str) -> bool {
+        let state = self.state.lock().unwrap();
+        state.fleet_paused || state.paused_ships.contains(ship_symbol)
+    }
+
+    pub fn forced_role(&self, ship_symbol: &str) -> Option<ForcedRole> {
+        self.state.lock().unwrap().roles.get(ship_symbol).copied()
+    }
+
+    /// Goals queued since the last call, oldest first
+    pub fn take_goals(&self) -> Vec<String> {
+        self.state.lock().unwrap().goals.drain(..).collect()
+    }
+
+    pub fn request_shutdown(&self) {
+        self.shutdown.send_replace(true);
+    }
+
+    pub fn shutdown_requested(&self) -> bool {
+        *self.shutdown.borrow()
+    }
+
+    /// Resolves once a shutdown has been requested, including before the call
+    pub async fn wait_for_shutdown(&self) {
+        let mut receiver = self.shutdown.subscribe();
+        let _ = receiver.wait_for(|requested| *requested).await;
+    }
+
+    /// Fleet and contract state as last reported by the coordinator, plus the active overrides
+    pub fn status(&self) -> serde_json::Value {
+        let now = Instant::now();
+        let ships: Vec<serde_json::Value> = get_dashboard().ships().iter()
+            .map(|row| serde_json::json!({
+                "symbol": row.symbol,
+                "role": row.role,
+                "forced_role": self.forced_role(&row.symbol),
+                "paused": self.is_paused(&row.symbol),
+                "location": row.location,
+                "status": row.status,
+                "fuel": [row.fuel.0, row.fuel.1],
+                "cargo": [row.cargo.0, row.cargo.1],
+                "cooldown_seconds": row.cooldown_until.filter(|until| *until > now).map(|until| (until - now).as_secs()),
+                "action": row.action,
+            }))
+            .collect();
+        let metrics = get_metrics().snapshot();
+        let contracts: Vec<serde_json::Value> = metrics.contract_progress.iter()
+            .map(|(contract, good, done, required)| serde_json::json!({
+                "contract": contract, "good": good, "fulfilled": done, "required": required,
+            }))
+            .collect();
+        let state = self.state.lock().unwrap();
+        serde_json::json!({
+            "credits": metrics.credits,
+            "fleet_paused": state.fleet_paused,
+            "queued_goals": state.goals,
+            "shutdown_requested": self.shutdown_requested(),
+            "ships": ships,
+            "contracts": contracts,
+        })
+    }
+
+    /// Answer control requests on a local address until the process exits; returns the bound address
+    pub async fn serve(&self, addr: SocketAddr) -> std::io::Result<SocketAddr> {
+        let listener = TcpListener::bind(addr).await?;
+        let addr = listener.local_addr()?;
+        o_info!("🎛️ Control API listening on http://{}", addr);
+        let control = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = match listener.accept().await {
+                    Ok(connection) => connection,
+                    Err(e) => {
+                        o_error!("⚠️ Control API accept failed: {}", e);
+                        continue;
+                    }
+                };
+                let control = control.clone();
+                tokio::spawn(async move {
+                    let Some((head, body)) = read_http_message(&mut stream).await else {
+                        return;
+                    };
+                    let (code, response) = if head.starts_with("GET /status ") {
+                        ("200 OK", control.apply(ControlCommand::Status))
+                    } else if head.starts_with("POST /command ") {
+                        match serde_json::from_str::<ControlCommand>(&body) {
+                            Ok(command) => {
+                                o_info!("🎛️ Control command: {}", body.trim());
+                                let response = control.apply(command);
+                                (if response.ok { "200 OK" } else { "400 Bad Request" }, response)
+                            }
+                            Err(e) => ("400 Bad Request", ControlResponse::error(format!("Invalid command: {}", e))),
+                        }
+                    } else {
+                        ("404 Not Found", ControlResponse::error("Use GET /status or POST /command"))
+                    };
+                    let body = serde_json::to_string(&response).unwrap_or_default();
+                    let response = format!("HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                                           code, body.len(), body);
+                    let _ = stream.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+        Ok(addr)
+    }
+}
+
+/// Turn Ctrl+C into a shutdown request; a second Ctrl+C quits without waiting
+pub fn shutdown_on_ctrl_c() {
+    tokio::spawn(async {
+        if tokio::signal::ctrl_c().await.is_err() {
+            return;
+        }
+        o_summary!("\n🛑 CTRL+C RECEIVED - Graceful shutdown initiated (press Ctrl+C again to quit immediately)");
+        get_control().request_shutdown();
+        if tokio::signal::ctrl_c().await.is_ok() {
+            // The output broker may not get another turn, so write directly
+            eprintln!("⚠️ Second Ctrl+C - quitting without waiting for ships");
+            std::process::exit(130);
+        }
+    });
+}
+
+/// Read one HTTP message, returning its head and body once `Content-Length` bytes have arrived
+async fn read_http_message(stream: &mut TcpStream) -> Option<(String, String)> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    loop {
+        if let Some(end) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+            let head = String::from_utf8_lossy(&buffer[..end]).to_string();
+            let length = head.lines()
+                .filter_map(|line| line.split_once(':'))
+                .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+                .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+            if buffer.len() >= end + 4 + length {
+                let body = String::from_utf8_lossy(&buffer[end + 4..end + 4 + length]).to_string();
+                return Some((head, body));
+            }
+        }
+        if buffer.len() > MAX_REQUEST_BYTES {
+            return None;
+        }
+        let read = stream.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            // The peer closed; a response without Content-Length runs to the end of the stream
+            let end = buffer.windows(4).position(|window| window == b"\r\n\r\n")?;
+            return Some((String::from_utf8_lossy(&buffer[..end]).to_string(), String::from_utf8_lossy(&buffer[end + 4..]).to_string()));
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+}
+
+/// Send a command to a running bot and return its answer
+pub async fn send_command(addr: &str, command: &ControlCommand) -> Result<ControlResponse, Box<dyn std::error::Error>> {
+    let mut stream = TcpStream::connect(addr).await
+        .map_err(|e| format!("Could not reach the bot's control API at {} (is [control] enabled?): {}", addr, e))?;
+    let body = serde_json::to_string(command)?;
+    let request = format!("POST /command HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                          addr, body.len(), body);
+    stream.write_all(request.as_bytes()).await?;
+    let (_, body) = read_http_message(&mut stream).await.ok_or("Control API closed the connection without answering")?;
+    Ok(serde_json::from_str(&body)?)
+}
@@ -0,0 +1,229 @@
+// Terminal dashboard - a live fleet view redrawn in place, fed by ship status updates and the event bus
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, Instant};
+use crate::events::{EventSubscriber, GameEvent, PublishedEvent};
+use crate::metrics::{Metrics, MetricsSnapshot};
+use crate::operations::{ShipAction, ShipActorStatus, ShipState};
+
+/// Lines kept in the scrolling event log
+const EVENT_LOG_LINES: usize = 12;
+/// Credit readings kept for the graph
+const CREDIT_HISTORY: usize = 60;
+const BAR_WIDTH: usize = 20;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShipRow {
+    pub symbol: String,
+    pub role: String,
+    pub location: String,
+    pub status: String,
+    pub fuel: (i32, i32),
+    pub cargo: (i32, i32),
+    pub cooldown_until: Option<Instant>,
+    pub action: Option<String>,
+}
+
+impl ShipRow {
+    pub fn from_state(state: &ShipState) -> Self {
+        let ship = &state.ship;
+        let status = match &state.status {
+            ShipActorStatus::Error(_) => "error".to_string(),
+            status => status.label().replace('_', " "),
+        };
+        Self {
+            symbol: ship.symbol.clone(),
+            role: ship.registration.role.clone(),
+            location: ship.nav.waypoint_symbol.clone(),
+            status,
+            fuel: (ship.fuel.current, ship.fuel.capacity),
+            cargo: (ship.cargo.units, ship.cargo.capacity),
+            cooldown_until: state.cooldown_until,
+            action: state.current_action.as_ref().map(action_summary),
+        }
+    }
+}
+
+/// A few words on what an action is doing, for the fleet table
+pub fn action_summary(action: &ShipAction) -> String {
+    match action {
+        ShipAction::Mine { target, .. } => format!("mine @ {}", target),
+        ShipAction::Siphon { target, .. } => format!("siphon @ {}", target),
+        ShipAction::Navigate { destination } => format!("fly to {}", destination),
+        ShipAction::Explore { systems } => format!("explore {} systems", systems.len()),
+        ShipAction::Trade { orders, marketplace } => format!("trade {} orders @ {}", orders.len(), marketplace),
+        ShipAction::Refuel { station } => format!("refuel @ {}", station),
+        ShipAction::SellCargo { marketplace } => format!("sell @ {}", marketplace),
+        ShipAction::Dock => "dock".to_string(),
+        ShipAction::Orbit => "orbit".to_string(),
+        ShipAction::Survey { target } => format!("survey @ {}", target),
+        ShipAction::DeliverCargo { trade_symbol, units, destination, .. } => format!("deliver {} {} @ {}", units, trade_symbol, destination),
+        ShipAction::SmartSellOrJettison { marketplace, .. } => format!("clear hold @ {}", marketplace),
+        ShipAction::JettisonCargo { .. } => "jettison".to_string(),
+        ShipAction::TransferCargo { to_ship } => format!("transfer to {}", to_ship),
+        ShipAction::Repair { shipyard } => format!("repair @ {}", shipyard),
+    }
+}
+
+/// One line for the event log
+pub fn describe_event(event: &GameEvent) -> String {
+    match event {
+        GameEvent::Extraction { ship_symbol, waypoint_symbol, good, units } =>
+            format!("⛏️ {} extracted {} {} at {}", ship_symbol, units, good, waypoint_symbol),
+        GameEvent::Sale { ship_symbol, good, units, total_price } =>
+            format!("💰 {} sold {} {} for {}", ship_symbol, units, good, total_price),
+        GameEvent::Purchase { ship_symbol, good, units, total_price } =>
+            format!("🛒 {} bought {} {} for {}", ship_symbol, units, good, total_price),
+        GameEvent::ContractAccepted { contract_id } => format!("📝 Contract {} accepted", contract_id),
+        GameEvent::ContractFulfilled { contract_id } => format!("🎉 Contract {} fulfilled", contract_id),
+        GameEvent::ShipArrived { ship_symbol, waypoint_symbol } => format!("🧭 {} arrived at {}", ship_symbol, waypoint_symbol),
+        GameEvent::ShipPurchased { ship_symbol, ship_type, price, .. } =>
+            format!("🚢 Bought {} ({}) for {}", ship_symbol, ship_type, price),
+        GameEvent::CooldownEnded { ship_symbol } => format!("✅ {} cooldown over", ship_symbol),
+        GameEvent::Error { message } => format!("❌ {}", message),
+    }
+}
+
+pub fn progress_bar(percentage: f64, width: usize) -> String {
+    let filled = (((percentage / 100.0) * width as f64) as usize).min(width);
+    format!("[{}{}]", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+/// The last `width` values scaled between their minimum and maximum
+pub fn sparkline(values: &[i64], width: usize) -> String {
+    const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let recent = &values[values.len().saturating_sub(width)..];
+    let (Some(min), Some(max)) = (recent.iter().min(), recent.iter().max()) else {
+        return String::new();
+    };
+    let span = (max - min).max(1) as f64;
+    recent.iter()
+        .map(|value| LEVELS[(((value - min) as f64 / span) * (LEVELS.len() - 1) as f64).round() as usize])
+        .collect()
+}
+
+#[derive(Debug, Default)]
+struct DashboardState {
+    ships: BTreeMap<String, ShipRow>,
+    credits: VecDeque<i64>,
+    events: VecDeque<String>,
+}
+
+/// Shared handle to the dashboard; clones see the same state
+#[derive(Debug, Clone, Default)]
+pub struct Dashboard {
+    state: Arc<Mutex<DashboardState>>,
+}
+
+static GLOBAL_DASHBOARD: OnceLock<Dashboard> = OnceLock::new();
+
+/// The dashboard the fleet coordinator reports ship status to
+pub fn get_dashboard() -> &'static Dashboard {
+    GLOBAL_DASHBOARD.get_or_init(Dashboard::default)
+}
+
+impl Dashboard {
+    pub fn update_ship(&self, state: &ShipState) {
+        let row = ShipRow::from_state(state);
+        self.state.lock().unwrap().ships.insert(row.symbol.clone(), row);
+    }
+
+    /// Latest row for every ship, by symbol
+    pub fn ships(&self) -> Vec<ShipRow> {
+        self.state.lock().unwrap().ships.values().cloned().collect()
+    }
+
+    pub fn push_event(&self, line: String) {
+        let mut state = self.state.lock().unwrap();
+        state.events.push_back(line);
+        while state.events.len() > EVENT_LOG_LINES {
+            state.events.pop_front();
+        }
+    }
+
+    /// Add a credit reading to the graph when it has changed
+    pub fn sample_credits(&self, credits: i64) {
+        let mut state = self.state.lock().unwrap();
+        if state.credits.back() != Some(&credits) {
+            state.credits.push_back(credits);
+            while state.credits.len() > CREDIT_HISTORY {
+                state.credits.pop_front();
+            }
+        }
+    }
+
+    /// The whole dashboard as text, one frame
+    pub fn render(&self, metrics: &MetricsSnapshot) -> String {
+        let state = self.state.lock().unwrap();
+        let mut out = String::new();
+        let now = Instant::now();
+
+        let _ = writeln!(out, "🚀 SpaceTraders Fleet Dashboard    {}", chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC"));
+        let credits: Vec<i64> = state.credits.iter().copied().collect();
+        let _ = writeln!(out, "💰 Credits: {}  {}", metrics.credits.map(|c| c.to_string()).unwrap_or_else(|| "?".to_string()),
+                         sparkline(&credits, CREDIT_HISTORY));
+        let rate_status = if metrics.rate_limited > 0 { "⚠️" } else { "✅" };
+        let _ = writeln!(out, "🌐 API: queue {} | requests {} | rate limited {} {}", metrics.api_queue_depth,
+                         metrics.api_requests, metrics.rate_limited, rate_status);
+
+        let _ = writeln!(out, "\n📋 Contracts");
+        if metrics.contract_progress.is_empty() {
+            let _ = writeln!(out, "  none active");
+        }
+        for (contract, good, done, required) in &metrics.contract_progress {
+            let percentage = if *required > 0 { *done as f64 / *required as f64 * 100.0 } else { 100.0 };
+            let _ = writeln!(out, "  {:<26} {:<20} {} {}/{}", contract, good, progress_bar(percentage, BAR_WIDTH), done, required);
+        }
+
+        let _ = writeln!(out, "\n🚢 Fleet");
+        let _ = writeln!(out, "  {:<14} {:<12} {:<14} {:<12} {:>9} {:>9} {:>8}  ACTION", "SHIP", "ROLE", "LOCATION", "STATUS", "FUEL", "CARGO", "COOLDOWN");
+        for row in state.ships.values() {
+            let cooldown = row.cooldown_until
+                .filter(|until| *until > now)
+                .map(|until| format!("{}s", (until - now).as_secs()))
+                .unwrap_or_else(|| "-".to_string());
+            let _ = writeln!(out, "  {:<14} {:<12} {:<14} {:<12} {:>9} {:>9} {:>8}  {}", row.symbol, row.role, row.location, row.status,
+                             format!("{}/{}", row.fuel.0, row.fuel.1), format!("{}/{}", row.cargo.0, row.cargo.1), cooldown,
+                             row.action.as_deref().unwrap_or("-"));
+        }
+
+        let _ = writeln!(out, "\n📜 Events");
+        for line in &state.events {
+            let _ = writeln!(out, "  {}", line);
+        }
+        out
+    }
+
+    /// Redraw the dashboard in place every `interval` until the process exits
+    pub fn spawn(&self, metrics: &'static Metrics, interval: Duration) -> JoinHandle<()> {
+        let dashboard = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let snapshot = metrics.snapshot();
+                if let Some(credits) = snapshot.credits {
+                    dashboard.sample_credits(credits);
+                }
+                let frame = dashboard.render(&snapshot);
+                let mut stdout = std::io::stdout();
+                // Clear the screen and move home before each frame
+                let _ = write!(stdout, "\x1b[2J\x1b[H{}", frame);
+                let _ = stdout.flush();
+                tokio::time::sleep(interval).await;
+            }
+        })
+    }
+}
+
+/// Events go straight into the scrolling log
+impl EventSubscriber for Dashboard {
+    fn name(&self) -> &str {
+        "dashboard"
+    }
+
+    fn handle(&mut self, event: &PublishedEvent) {
+        self.push_event(format!("{} {}", event.timestamp.format("%H:%M:%S"), describe_event(&event.event)));
+    }
+}
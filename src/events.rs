@@ -0,0 +1,124 @@
+// Event bus - typed game events broadcast to any number of subscribers
+use std::sync::OnceLock;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use crate::models::ExtractionYield;
+
+/// Events beyond this many unread are dropped for slow subscribers
+const EVENT_BUS_CAPACITY: usize = 1024;
+
+/// Something significant that happened in the game
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum GameEvent {
+    Extraction { ship_symbol: String, waypoint_symbol: String, good: String, units: i32 },
+    Sale { ship_symbol: String, good: String, units: i32, total_price: i64 },
+    Purchase { ship_symbol: String, good: String, units: i32, total_price: i64 },
+    ContractAccepted { contract_id: String },
+    ContractFulfilled { contract_id: String },
+    ShipArrived { ship_symbol: String, waypoint_symbol: String },
+    ShipPurchased { ship_symbol: String, ship_type: String, waypoint_symbol: String, price: i64 },
+    CooldownEnded { ship_symbol: String },
+    Error { message: String },
+}
+
+impl GameEvent {
+    pub fn extraction(ship_symbol: &str, waypoint_symbol: &str, extraction_yield: &ExtractionYield) -> Self {
+        GameEvent::Extraction {
+            ship_symbol: ship_symbol.to_string(),
+            waypoint_symbol: waypoint_symbol.to_string(),
+            good: extraction_yield.symbol.clone(),
+            units: extraction_yield.units,
+        }
+    }
+
+    /// The ship the event is about, if it is about one
+    pub fn ship_symbol(&self) -> Option<&str> {
+        match self {
+            GameEvent::Extraction { ship_symbol, .. }
+            | GameEvent::Sale { ship_symbol, .. }
+            | GameEvent::Purchase { ship_symbol, .. }
+            | GameEvent::ShipArrived { ship_symbol, .. }
+            | GameEvent::ShipPurchased { ship_symbol, .. }
+            | GameEvent::CooldownEnded { ship_symbol } => Some(ship_symbol),
+            _ => None,
+        }
+    }
+}
+
+/// An event with the time it was published
+#[derive(Debug, Clone, Serialize)]
+pub struct PublishedEvent {
+    pub timestamp: DateTime<Utc>,
+    #[serde(flatten)]
+    pub event: GameEvent,
+}
+
+/// Receives every published event on its own task
+pub trait EventSubscriber: Send + 'static {
+    fn name(&self) -> &str;
+    fn handle(&mut self, event: &PublishedEvent);
+}
+
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<PublishedEvent>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new(EVENT_BUS_CAPACITY)
+    }
+}
+
+impl EventBus {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    /// Publish to everyone subscribed right now; returns how many subscribers will see it
+    pub fn publish(&self, event: GameEvent) -> usize {
+        self.sender.send(PublishedEvent { timestamp: Utc::now(), event }).unwrap_or(0)
+    }
+
+    /// A raw receiver for callers that want to drive their own loop
+    pub fn subscribe(&self) -> broadcast::Receiver<PublishedEvent> {
+        self.sender.subscribe()
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+
+    /// Run a subscriber on its own task until the bus is dropped. A subscriber that falls too
+    /// far behind skips the events it missed rather than holding up the publishers.
+    pub fn register(&self, mut subscriber: impl EventSubscriber) -> JoinHandle<()> {
+        let mut receiver = self.subscribe();
+        tokio::spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => subscriber.handle(&event),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        crate::o_debug!("📭 {} fell behind and missed {} events", subscriber.name(), skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+}
+
+static GLOBAL_EVENT_BUS: OnceLock<EventBus> = OnceLock::new();
+
+/// The event bus shared by the client and operations layers
+pub fn get_event_bus() -> &'static EventBus {
+    GLOBAL_EVENT_BUS.get_or_init(EventBus::default)
+}
+
+/// Publish on the global bus
+pub fn publish(event: GameEvent) {
+    get_event_bus().publish(event);
+}
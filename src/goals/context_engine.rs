@@ -12,6 +12,8 @@ pub struct ContextEngine {
     resource_locations: HashMap<String, Vec<String>>, // resource_type -> waypoint_symbols
     last_update: std::time::Instant,
     cache_ttl: std::time::Duration,
+    credit_reserve: i64,
+    fuel_safety_margin: i32,
 }
 
 impl ContextEngine {
@@ -23,9 +25,21 @@ impl ContextEngine {
             resource_locations: HashMap::new(),
             last_update: std::time::Instant::now(),
             cache_ttl: std::time::Duration::from_secs(300), // 5 minute cache
+            credit_reserve: crate::config::SpaceTradersConfig::default().credits.min_reserve_credits,
+            fuel_safety_margin: crate::config::SpaceTradersConfig::default().fuel.fuel_safety_margin,
         }
     }
 
+    /// Credits goals must keep in reserve (from `credits.min_reserve_credits`)
+    pub fn set_credit_reserve(&mut self, credit_reserve: i64) {
+        self.credit_reserve = credit_reserve;
+    }
+
+    /// Spare fuel goals keep when planning a trip (from `fuel.fuel_safety_margin`)
+    pub fn set_fuel_safety_margin(&mut self, fuel_safety_margin: i32) {
+        self.fuel_safety_margin = fuel_safety_margin;
+    }
+
     /// Build comprehensive context for goal planning and execution
     pub async fn build_context(&mut self, client: &PriorityApiClient) -> Result<GoalContext, Box<dyn std::error::Error>> {
         o_debug!("🔄 Building comprehensive goal context...");
@@ -55,7 +69,10 @@ impl ContextEngine {
             known_waypoints,
             known_markets,
             available_credits: credits,
+            credit_reserve: self.credit_reserve,
+            fuel_safety_margin: self.fuel_safety_margin,
             fleet_status,
+            progress: Default::default(),
         })
     }
 
@@ -335,7 +352,9 @@ impl ContextEngine {
         false
     }
 
-    fn analyze_fleet_status(&self, ships: &[Ship]) -> FleetStatus {
+    /// Classify ships by capability and availability. Probes have no fuel tank or hold, so
+    /// those checks only apply to ships that have one.
+    pub fn analyze_fleet_status(&self, ships: &[Ship]) -> FleetStatus {
         let mut mining_ships = Vec::new();
         let mut hauler_ships = Vec::new();
         let mut probe_ships = Vec::new();
@@ -364,9 +383,9 @@ impl ContextEngine {
             // Check availability status
             if ship.nav.status == "IN_TRANSIT" {
                 busy_ships.insert(ship_symbol, "in_transit".to_string());
-            } else if ship.fuel.current < 5 {
+            } else if ship.fuel.capacity > 0 && ship.fuel.current < 5 {
                 busy_ships.insert(ship_symbol, "low_fuel".to_string());
-            } else if ship.cargo.units >= ship.cargo.capacity {
+            } else if ship.cargo.capacity > 0 && ship.cargo.units >= ship.cargo.capacity {
                 busy_ships.insert(ship_symbol, "cargo_full".to_string());
             } else {
                 available_ships.push(ship_symbol);
@@ -1,6 +1,8 @@
 // Goal Decomposer - Breaks complex goals into sub-goals
-use crate::goals::{Goal, GoalPriority};
+use crate::goals::{Goal, GoalContext, GoalPriority, GoalAction, GoalParameters, GoalLocation, ShipSelector};
+use std::collections::BTreeMap;
 use crate::goals::goal_types::*;
+use crate::operations::recipe_for;
 use crate::{o_debug, o_info};
 
 pub struct GoalDecomposer;
@@ -11,18 +13,18 @@ impl GoalDecomposer {
     }
 
     /// Decompose a complex goal into executable sub-goals
-    pub async fn decompose(&self, goal: Box<dyn Goal>) -> Vec<Box<dyn Goal>> {
+    pub async fn decompose(&self, goal: Box<dyn Goal>, context: &GoalContext) -> Vec<Box<dyn Goal>> {
         o_debug!("🔧 Decomposing goal: {}", goal.description());
         
-        match goal.id().split('_').next() {
-            Some("refine") => self.decompose_refining_goal(goal).await,
-            Some("buy") => self.decompose_purchase_goal(goal).await,
-            Some("explore") => self.decompose_exploration_goal(goal).await,
+        match goal.action() {
+            Some(GoalAction::Refine) => self.decompose_refining_goal(goal).await,
+            Some(GoalAction::Buy) => self.decompose_purchase_goal(goal, context).await,
+            Some(GoalAction::Explore) => self.decompose_exploration_goal(goal, context).await,
             _ => vec![goal], // No decomposition needed
         }
     }
 
-    async fn decompose_refining_goal(&self, goal: Box<dyn Goal>) -> Vec<Box<dyn Goal>> {
+    async fn decompose_refining_goal(&self, mut goal: Box<dyn Goal>) -> Vec<Box<dyn Goal>> {
         o_info!("🏭 Decomposing refining goal into sub-tasks");
         
         // For "refine iron" goal, we need:
@@ -31,27 +33,37 @@ impl GoalDecomposer {
         // 3. Transport ore to refinery
         // 4. Execute refining process
         
-        let goal_id = goal.id();
-        let parts: Vec<&str> = goal_id.split('_').collect();
-        
-        if parts.len() >= 3 {
-            let resource = parts[1]; // e.g., "iron" from "refine_iron_50"
-            let quantity = parts[2].parse::<i32>().unwrap_or(50);
+        let params = goal.parameters();
+        if let Some(resource) = params.resource.clone() {
+            let quantity = params.quantity.unwrap_or(50);
+            // Mine enough raw input for every refine the target needs
+            let (ore, ore_quantity) = match recipe_for(&resource) {
+                Some(recipe) => (recipe.input.clone(), recipe.input_for_output(quantity)),
+                None => (format!("{}_ORE", resource), quantity),
+            };
             
+            let mining_goal_id = format!("mine_{}_for_refining_{}", ore.to_lowercase(), quantity);
             let mut sub_goals: Vec<Box<dyn Goal>> = Vec::new();
             
-            // Sub-goal 1: Mine the ore
+            // Sub-goal 1: Mine the ore with the same ships and deadline. A waypoint on
+            // the refine goal is where to refine, so only a system carries over.
             sub_goals.push(Box::new(MiningGoal {
-                id: format!("mine_{}_ore_for_refining_{}", resource, quantity),
-                resource_type: format!("{}_ORE", resource.to_uppercase()),
-                target_quantity: quantity,
+                id: mining_goal_id.clone(),
+                resource_type: ore.clone(),
+                target_quantity: ore_quantity,
                 priority: GoalPriority::Override,
                 status: crate::goals::GoalStatus::Pending,
-                survey_cache: crate::goals::SurveyCache::new(),
+                params: GoalParameters {
+                    resource: Some(ore),
+                    quantity: Some(ore_quantity),
+                    location: params.location.clone().filter(|location| matches!(location, GoalLocation::System(_))),
+                    ..params.clone()
+                },
                 use_surveys: true, // Enable surveys for decomposed mining goals
             }));
             
-            // Sub-goal 2: Transport and refine (the original goal, modified)
+            // Sub-goal 2: Transport and refine (the original goal, modified) once the ore is mined
+            goal.add_dependency(&mining_goal_id);
             sub_goals.push(goal);
             
             o_debug!("🔧 Created {} sub-goals for refining", sub_goals.len());
@@ -61,43 +73,158 @@ impl GoalDecomposer {
         vec![goal] // Fallback: return original goal
     }
 
-    async fn decompose_purchase_goal(&self, goal: Box<dyn Goal>) -> Vec<Box<dyn Goal>> {
+    async fn decompose_purchase_goal(&self, mut goal: Box<dyn Goal>, context: &GoalContext) -> Vec<Box<dyn Goal>> {
         o_debug!("💳 Decomposing purchase goal");
+        let mut sub_goals: Vec<Box<dyn Goal>> = Vec::new();
         
-        // For ship purchases, we might need:
-        // 1. Find shipyard
-        // 2. Ensure sufficient funds
-        // 3. Navigate to shipyard
-        // 4. Purchase ship
+        // Prices are only visible where we have a ship, so scout first when probes are free
+        let scout_target = if goal.parameters().ship_type.is_some() { "SHIPYARDS" } else { "MARKETS" };
+        if let Some(scout) = self.scouting_sub_goal(&goal.id(), scout_target, &goal.parameters(), context) {
+            goal.add_dependency(&scout.id());
+            sub_goals.push(scout);
+        }
+        let params = goal.parameters();
         
-        // For resource purchases:
-        // 1. Find marketplace with resource
-        // 2. Ensure sufficient funds and cargo space
-        // 3. Navigate to marketplace
-        // 4. Buy resources
+        match (params.ship_type.is_some(), params.resource.clone()) {
+            // Resource purchases: split the quantity across cargo ships by free space
+            (false, Some(resource)) => {
+                let quantity = params.quantity.unwrap_or(50);
+                let buyers = select_cargo_ships(context, &params.ships);
+                if buyers.len() > 1 {
+                    let mut remaining = quantity;
+                    let mut part_ids = Vec::new();
+                    for (i, buyer) in buyers.iter().enumerate() {
+                        if remaining <= 0 {
+                            break;
+                        }
+                        let share = remaining.min(buyer.cargo.capacity - buyer.cargo.units);
+                        remaining -= share;
+                        part_ids.push(format!("{}_part{}", goal.id(), i + 1));
+                        sub_goals.push(Box::new(ResourcePurchaseGoal {
+                            id: part_ids[i].clone(),
+                            resource_type: resource.clone(),
+                            target_quantity: share,
+                            priority: goal.priority(),
+                            status: crate::goals::GoalStatus::Pending,
+                            params: GoalParameters {
+                                quantity: Some(share),
+                                ships: ShipSelector::Symbols(vec![buyer.symbol.clone()]),
+                                ..params.clone()
+                            },
+                        }));
+                    }
+                    // Whatever the holds can't take now is bought once the parts are done,
+                    // by any ship that has freed space by then, or fails with the shortfall
+                    if remaining > 0 {
+                        let mut depends_on = params.depends_on.clone();
+                        depends_on.extend(part_ids);
+                        o_info!("📦 {} {} don't fit in {} ships; queueing the remainder", remaining, resource, buyers.len());
+                        sub_goals.push(Box::new(ResourcePurchaseGoal {
+                            id: format!("{}_remainder", goal.id()),
+                            resource_type: resource.clone(),
+                            target_quantity: remaining,
+                            priority: goal.priority(),
+                            status: crate::goals::GoalStatus::Pending,
+                            params: GoalParameters {
+                                quantity: Some(remaining),
+                                depends_on,
+                                ..params.clone()
+                            },
+                        }));
+                    }
+                    o_debug!("🔧 Split purchase of {} {} across {} ships", quantity, resource, buyers.len());
+                    return sub_goals;
+                }
+                sub_goals.push(goal);
+            }
+            _ => sub_goals.push(goal),
+        }
         
-        // For now, return as-is (would implement complex logic here)
-        vec![goal]
+        o_debug!("🔧 Created {} sub-goals for purchase", sub_goals.len());
+        sub_goals
     }
 
-    async fn decompose_exploration_goal(&self, goal: Box<dyn Goal>) -> Vec<Box<dyn Goal>> {
+    async fn decompose_exploration_goal(&self, goal: Box<dyn Goal>, context: &GoalContext) -> Vec<Box<dyn Goal>> {
         o_debug!("🔍 Decomposing exploration goal");
+        let params = goal.parameters();
+        let target = params.target.clone().unwrap_or_else(|| "NEARBY".to_string());
         
-        // Exploration might involve:
-        // 1. Assign probe ships
-        // 2. Plan exploration route
-        // 3. Execute waypoint scanning
-        // 4. Report findings
+        // A specific system is explored by whichever probes are already there
+        if params.location.is_some() {
+            return vec![goal];
+        }
         
-        vec![goal] // Simplified for now
+        // Otherwise explore each system our probes are in, with that system's probes
+        let probes_by_system = self.available_probes_by_system(&params, context);
+        if probes_by_system.len() <= 1 {
+            return vec![goal];
+        }
+        
+        let sub_goals: Vec<Box<dyn Goal>> = probes_by_system.into_iter()
+            .map(|(system, probes)| Box::new(ExplorationGoal {
+                id: format!("{}_{}", goal.id(), system.to_lowercase()),
+                target_type: target.clone(),
+                priority: goal.priority(),
+                status: crate::goals::GoalStatus::Pending,
+                params: GoalParameters {
+                    location: Some(GoalLocation::System(system)),
+                    ships: ShipSelector::Symbols(probes),
+                    ..params.clone()
+                },
+            }) as Box<dyn Goal>)
+            .collect();
+        
+        o_debug!("🔧 Split exploration across {} systems", sub_goals.len());
+        sub_goals
+    }
+
+    /// An exploration sub-goal that visits shipyards/markets before a purchase
+    fn scouting_sub_goal(&self, parent_id: &str, target: &str, params: &GoalParameters, context: &GoalContext) -> Option<Box<dyn Goal>> {
+        let probes_by_system = self.available_probes_by_system(&GoalParameters::default(), context);
+        let system = match &params.location {
+            Some(location) => location.system_symbol(),
+            None => context.ships.iter()
+                .find(|ship| !context.fleet_status.probe_ships.contains(&ship.symbol))
+                .map(|ship| ship.nav.system_symbol.clone())?,
+        };
+        let probes = probes_by_system.get(&system)?.clone();
+        
+        Some(Box::new(ExplorationGoal {
+            id: format!("{}_scout_{}", parent_id, target.to_lowercase()),
+            target_type: target.to_string(),
+            priority: GoalPriority::Override,
+            status: crate::goals::GoalStatus::Pending,
+            params: GoalParameters {
+                target: Some(target.to_string()),
+                location: Some(GoalLocation::System(system)),
+                ships: ShipSelector::Symbols(probes),
+                ..Default::default()
+            },
+        }))
+    }
+
+    fn available_probes_by_system(&self, params: &GoalParameters, context: &GoalContext) -> BTreeMap<String, Vec<String>> {
+        let mut probes_by_system: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for ship in &context.ships {
+            let selected = match &params.ships {
+                ShipSelector::Symbols(symbols) => symbols.contains(&ship.symbol),
+                _ => true,
+            };
+            if selected
+                && context.fleet_status.probe_ships.contains(&ship.symbol)
+                && !context.fleet_status.busy_ships.contains_key(&ship.symbol) {
+                probes_by_system.entry(ship.nav.system_symbol.clone()).or_default().push(ship.symbol.clone());
+            }
+        }
+        probes_by_system
     }
 
     /// Check if a goal needs decomposition based on complexity
     pub fn needs_decomposition(&self, goal: &dyn Goal) -> bool {
-        match goal.id().split('_').next() {
-            Some("refine") => true,  // Refining usually needs ore mining first
-            Some("buy") if goal.id().contains("ship") => true, // Ship buying has multiple steps
-            Some("explore") => false, // Exploration is usually atomic
+        match goal.action() {
+            Some(GoalAction::Refine) => true,  // Refining usually needs ore mining first
+            Some(GoalAction::Buy) => true, // Buying needs scouting for prices, and resources may span ships
+            Some(GoalAction::Explore) => goal.parameters().location.is_none(), // Split across systems with probes
             _ => false,
         }
     }
@@ -124,20 +251,17 @@ impl GoalDecomposer {
 
     fn goals_have_dependency(&self, goal1: &dyn Goal, goal2: &dyn Goal) -> bool {
         // Check if goal1's outputs are needed by goal2's inputs
-        let goal1_id = goal1.id();
-        let goal2_id = goal2.id();
+        let (Some(resource1), Some(resource2)) = (goal1.parameters().resource, goal2.parameters().resource) else {
+            return false;
+        };
         
-        // Example: mining goal output feeds into refining goal input
-        if goal1_id.starts_with("mine_") && goal2_id.starts_with("refine_") {
-            // Extract resource names and check compatibility
-            if let (Some(mine_resource), Some(refine_resource)) = (
-                goal1_id.split('_').nth(1),
-                goal2_id.split('_').nth(1)
-            ) {
-                return mine_resource == refine_resource;
-            }
+        match (goal1.action(), goal2.action()) {
+            // Example: mining goal output feeds into refining goal input
+            (Some(GoalAction::Mine), Some(GoalAction::Refine)) => recipe_for(&resource2)
+                .map_or(resource1 == format!("{}_ORE", resource2), |recipe| recipe.input == resource1),
+            // Anything produced can then be sold or moved
+            (Some(GoalAction::Mine | GoalAction::Refine | GoalAction::Buy), Some(GoalAction::Sell | GoalAction::Transfer)) => resource1 == resource2,
+            _ => false,
         }
-        
-        false
     }
 }
\ No newline at end of file
@@ -0,0 +1,718 @@
+// Goal DSL - Grammar for `--goal` commands
+//
+// command  := clause (("then" | "and" | ",") clause)*
+// clause   := action [quantity] [object] modifier*
+// modifier := "at" (WAYPOINT | "best" ["market"])
+//           | "from" WAYPOINT | "to" (SHIP | WAYPOINT)
+//           | "in" (SYSTEM | DURATION) | "within" DURATION
+//           | ("with" | "using") (N "ships" | SHIP ["," SHIP]*)
+//           | "by" (HH:MM | DATE | DATETIME) | "as" ROLE
+//
+// Example: "mine 200 iron ore at X1-AB12-C3 with 3 ships by 18:00 then sell at best market"
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GoalAction {
+    Mine,
+    Refine,
+    Sell,
+    Buy,
+    Explore,
+    Debug,
+    Transfer,
+    Designate,
+}
+
+impl GoalAction {
+    fn from_word(word: &str) -> Option<Self> {
+        match word {
+            "mine" | "extract" | "dig" | "harvest" => Some(Self::Mine),
+            "refine" | "process" | "smelt" | "manufacture" => Some(Self::Refine),
+            "sell" | "trade" => Some(Self::Sell),
+            "buy" | "purchase" | "acquire" => Some(Self::Buy),
+            "explore" | "scout" | "discover" | "chart" => Some(Self::Explore),
+            "debug" | "analyze" | "inspect" | "examine" => Some(Self::Debug),
+            "transfer" | "move" | "consolidate" | "transport" => Some(Self::Transfer),
+            "designate" | "assign" | "convert" => Some(Self::Designate),
+            _ => None,
+        }
+    }
+}
+
+/// Where a goal should take place
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GoalLocation {
+    Waypoint(String),
+    System(String),
+}
+
+impl GoalLocation {
+    pub fn system_symbol(&self) -> String {
+        match self {
+            GoalLocation::Waypoint(waypoint) => waypoint.split('-').take(2).collect::<Vec<_>>().join("-"),
+            GoalLocation::System(system) => system.clone(),
+        }
+    }
+}
+
+/// Which market to trade at
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MarketTarget {
+    Best,
+    Waypoint(String),
+}
+
+/// Which ships a goal may use
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShipSelector {
+    #[default]
+    Any,
+    Count(usize),
+    Symbols(Vec<String>),
+}
+
+/// Structured parameters for a goal, produced by the DSL parser
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GoalParameters {
+    pub quantity: Option<i32>,
+    pub resource: Option<String>,
+    pub location: Option<GoalLocation>,
+    pub market: Option<MarketTarget>,
+    pub ships: ShipSelector,
+    pub target_ship: Option<String>,
+    pub ship_type: Option<String>,
+    pub role: Option<String>,
+    pub target: Option<String>,
+    pub deadline: Option<DateTime<Utc>>,
+    /// Goal IDs that must complete first
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+}
+
+impl GoalParameters {
+    pub fn waypoint(&self) -> Option<&str> {
+        match &self.location {
+            Some(GoalLocation::Waypoint(waypoint)) => Some(waypoint),
+            _ => None,
+        }
+    }
+
+    pub fn is_past_deadline(&self) -> bool {
+        self.deadline.map(|deadline| Utc::now() >= deadline).unwrap_or(false)
+    }
+}
+
+/// How a clause relates to the one before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClauseLink {
+    /// First clause of a command
+    Start,
+    /// Runs after the previous clause completes
+    Then,
+    /// Runs alongside the previous clause
+    And,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoalClause {
+    pub action: GoalAction,
+    pub link: ClauseLink,
+    pub params: GoalParameters,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoalCommand {
+    pub clauses: Vec<GoalClause>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoalParseError {
+    pub message: String,
+    pub input: String,
+    pub token: Option<String>,
+    pub column: usize,
+}
+
+impl fmt::Display for GoalParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.token {
+            Some(token) => writeln!(f, "{} (at '{}', column {})", self.message, token, self.column + 1)?,
+            None => writeln!(f, "{} (at end of command)", self.message)?,
+        }
+        writeln!(f, "  {}", self.input)?;
+        let width = self.token.as_ref().map(|t| t.chars().count()).unwrap_or(1).max(1);
+        write!(f, "  {}{}", " ".repeat(self.column), "^".repeat(width))
+    }
+}
+
+impl std::error::Error for GoalParseError {}
+
+#[derive(Debug, Clone)]
+struct Token {
+    text: String,
+    lower: String,
+    column: usize,
+}
+
+const ORE_BASES: &[&str] = &["iron", "copper", "aluminum", "silver", "gold", "platinum", "uranite", "meritium"];
+const SHIP_ROLES: &[&str] = &["refiner", "refinery", "hauler", "cargo", "miner", "mining", "scout", "probe"];
+const CLAUSE_SEPARATORS: &[&str] = &["then", "and", ","];
+const MODIFIER_WORDS: &[&str] = &["at", "from", "to", "in", "within", "with", "using", "by", "as"];
+const FILLER_WORDS: &[&str] = &["units", "unit", "of", "the", "some", "x"];
+
+pub struct GoalParser<'a> {
+    input: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl<'a> GoalParser<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            tokens: tokenize(input),
+            pos: 0,
+        }
+    }
+
+    /// Parse a full command, which may chain several clauses
+    pub fn parse(mut self) -> Result<GoalCommand, GoalParseError> {
+        if self.tokens.is_empty() {
+            return Err(self.error_at_end("Empty goal command"));
+        }
+
+        let mut clauses: Vec<GoalClause> = Vec::new();
+        let mut link = ClauseLink::Start;
+
+        loop {
+            let start = self.pos;
+            let mut clause = self.parse_clause(link)?;
+            clause.text = self.tokens[start..self.pos].iter().map(|t| t.text.as_str()).collect::<Vec<_>>().join(" ");
+
+            // "then sell at best market" sells whatever the previous clause produced
+            if let Some(previous) = clauses.last()
+                && clause.params.resource.is_none()
+                && matches!(clause.action, GoalAction::Sell | GoalAction::Transfer | GoalAction::Refine) {
+                clause.params.resource = previous.params.resource.clone();
+                if clause.params.quantity.is_none() {
+                    clause.params.quantity = previous.params.quantity;
+                }
+            }
+            clauses.push(clause);
+
+            match self.peek() {
+                None => break,
+                Some(token) if token.lower == "then" => link = ClauseLink::Then,
+                Some(token) if token.lower == "and" || token.lower == "," => link = ClauseLink::And,
+                Some(token) => {
+                    let token = token.clone();
+                    return Err(self.error_at(&token, "Expected 'then' or 'and' between goals"));
+                }
+            }
+            self.pos += 1;
+            if self.peek().is_none() {
+                return Err(self.error_at_end("Expected another goal after connector"));
+            }
+        }
+
+        Ok(GoalCommand { clauses })
+    }
+
+    fn parse_clause(&mut self, link: ClauseLink) -> Result<GoalClause, GoalParseError> {
+        let action = self.parse_action()?;
+        let mut params = GoalParameters::default();
+
+        // Optional leading quantity ("mine 200 ...", "sell all ...")
+        if let Some(token) = self.peek().cloned() {
+            if let Ok(quantity) = token.lower.parse::<i32>() {
+                if quantity <= 0 {
+                    return Err(self.error_at(&token, "Quantity must be positive"));
+                }
+                params.quantity = Some(quantity);
+                self.pos += 1;
+            } else if token.lower == "all" {
+                self.pos += 1;
+            }
+        }
+
+        match action {
+            GoalAction::Buy => self.parse_purchase_object(&mut params)?,
+            GoalAction::Explore => self.parse_exploration_target(&mut params)?,
+            GoalAction::Debug => self.parse_debug_target(&mut params)?,
+            GoalAction::Designate => {
+                if let Some(token) = self.peek().cloned()
+                    && is_ship_symbol(&token.lower) {
+                    params.target_ship = Some(token.text.to_uppercase());
+                    self.pos += 1;
+                }
+            }
+            _ => params.resource = self.parse_resource(action)?,
+        }
+
+        while let Some(token) = self.peek().cloned() {
+            if CLAUSE_SEPARATORS.contains(&token.lower.as_str()) {
+                break;
+            }
+            self.pos += 1;
+            match token.lower.as_str() {
+                "at" => self.parse_at(&mut params)?,
+                "from" => {
+                    let waypoint = self.expect_symbol("Expected a waypoint after 'from'", is_waypoint_symbol)?;
+                    params.location = Some(GoalLocation::Waypoint(waypoint));
+                }
+                "to" => {
+                    let next = self.expect_token("Expected a ship or waypoint after 'to'")?;
+                    if is_waypoint_symbol(&next.lower) {
+                        params.market = Some(MarketTarget::Waypoint(next.text.to_uppercase()));
+                    } else if is_ship_symbol(&next.lower) {
+                        params.target_ship = Some(next.text.to_uppercase());
+                    } else {
+                        return Err(self.error_at(&next, "Expected a ship symbol (e.g. AGENT-1) or waypoint (e.g. X1-AB12-C3) after 'to'"));
+                    }
+                }
+                "in" => {
+                    let next = self.expect_token("Expected a system or duration after 'in'")?;
+                    if let Some(duration) = parse_duration(&next.lower) {
+                        params.deadline = Some(Utc::now() + duration);
+                    } else if is_system_symbol(&next.lower) {
+                        params.location = Some(GoalLocation::System(next.text.to_uppercase()));
+                    } else {
+                        return Err(self.error_at(&next, "Expected a system (e.g. X1-AB12) or duration (e.g. 2h) after 'in'"));
+                    }
+                }
+                "within" => {
+                    let next = self.expect_token("Expected a duration after 'within'")?;
+                    let duration = parse_duration(&next.lower)
+                        .ok_or_else(|| self.error_at(&next, "Expected a duration like 30m, 2h or 1d"))?;
+                    params.deadline = Some(Utc::now() + duration);
+                }
+                "with" | "using" => self.parse_ship_selector(&mut params)?,
+                "by" => {
+                    let next = self.expect_token("Expected a deadline after 'by'")?;
+                    let deadline = parse_deadline(&next.text)
+                        .ok_or_else(|| self.error_at(&next, "Expected a deadline like 18:00, 2026-10-18 or 2026-10-18T18:00:00Z"))?;
+                    params.deadline = Some(deadline);
+                }
+                "as" => {
+                    let next = self.expect_token("Expected a role after 'as'")?;
+                    if !SHIP_ROLES.contains(&next.lower.as_str()) {
+                        return Err(self.error_at(&next, &format!("Unknown role, expected one of: {}", SHIP_ROLES.join(", "))));
+                    }
+                    params.role = Some(normalize_role(&next.lower));
+                }
+                _ => {
+                    return Err(self.error_at(&token, &format!("Unexpected word, expected one of: {}", MODIFIER_WORDS.join(", "))));
+                }
+            }
+        }
+
+        if action == GoalAction::Designate && params.role.is_none() {
+            return Err(self.error_at_end("No role specified in designation command (use 'as refiner', 'as hauler', ...)"));
+        }
+        if action == GoalAction::Transfer && params.target_ship.is_none() {
+            return Err(self.error_at_end("No target ship specified in transfer command (use 'to SHIP-1')"));
+        }
+
+        Ok(GoalClause { action, link, params, text: String::new() })
+    }
+
+    fn parse_action(&mut self) -> Result<GoalAction, GoalParseError> {
+        let token = self.expect_token("Expected an action")?;
+        if let Some(action) = GoalAction::from_word(&token.lower) {
+            return Ok(action);
+        }
+
+        // A bare resource ("iron ore") means mining it
+        if ORE_BASES.contains(&token.lower.as_str()) {
+            self.pos -= 1;
+            return Ok(GoalAction::Mine);
+        }
+
+        Err(self.error_at(&token, "Unknown action, expected one of: mine, refine, sell, buy, explore, debug, transfer, designate"))
+    }
+
+    fn parse_resource(&mut self, action: GoalAction) -> Result<Option<String>, GoalParseError> {
+        let mut words = Vec::new();
+        let mut first: Option<Token> = None;
+
+        while let Some(token) = self.peek().cloned() {
+            if MODIFIER_WORDS.contains(&token.lower.as_str())
+                || CLAUSE_SEPARATORS.contains(&token.lower.as_str())
+                || token.lower.contains('-')
+            {
+                break;
+            }
+            if token.lower.parse::<i32>().is_ok() {
+                return Err(self.error_at(&token, "Quantity must come directly after the action"));
+            }
+            self.pos += 1;
+            if FILLER_WORDS.contains(&token.lower.as_str()) {
+                continue;
+            }
+            if first.is_none() {
+                first = Some(token.clone());
+            }
+            words.push(token.lower.clone());
+        }
+
+        if words.is_empty() {
+            return match action {
+                GoalAction::Mine => Err(self.error_here("Expected a resource to mine (e.g. 'iron ore')")),
+                GoalAction::Refine => Err(self.error_here("Expected a resource to refine (e.g. 'iron')")),
+                GoalAction::Transfer => Err(self.error_here("Expected a resource to transfer (e.g. 'iron ore')")),
+                _ => Ok(None),
+            };
+        }
+
+        let mut resource = words.join("_").to_uppercase();
+        let base = words[0].as_str();
+
+        match action {
+            // Mining always yields ore; refining is named by its output metal
+            GoalAction::Mine if ORE_BASES.contains(&base) && words.len() == 1 => resource.push_str("_ORE"),
+            GoalAction::Refine => resource = resource.trim_end_matches("_ORE").to_string(),
+            _ => {}
+        }
+
+        if !resource.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            let token = first.expect("resource has at least one word");
+            return Err(self.error_at(&token, "Resource names may only contain letters, digits and underscores"));
+        }
+
+        Ok(Some(resource))
+    }
+
+    fn parse_purchase_object(&mut self, params: &mut GoalParameters) -> Result<(), GoalParseError> {
+        let mut words = Vec::new();
+        let mut is_ship = false;
+
+        while let Some(token) = self.peek().cloned() {
+            if MODIFIER_WORDS.contains(&token.lower.as_str()) || CLAUSE_SEPARATORS.contains(&token.lower.as_str()) {
+                break;
+            }
+            self.pos += 1;
+            if token.lower == "ship" || token.lower == "ships" || token.lower.starts_with("ship_") {
+                is_ship = true;
+                if token.lower.starts_with("ship_") {
+                    words.push(token.lower.clone());
+                }
+                continue;
+            }
+            if FILLER_WORDS.contains(&token.lower.as_str()) || token.lower == "a" || token.lower == "an" {
+                continue;
+            }
+            words.push(token.lower.clone());
+        }
+
+        if words.is_empty() {
+            return Err(self.error_at_end("Expected something to buy (e.g. 'mining ship' or 'fuel')"));
+        }
+
+        let object = words.join("_").to_uppercase();
+        if is_ship || ["MINING", "MINER", "EXCAVATOR", "HAULER", "FREIGHTER", "PROBE", "SATELLITE", "REFINERY", "SIPHON"].contains(&object.as_str()) {
+            params.ship_type = Some(normalize_ship_type(&object));
+        } else {
+            params.resource = Some(object);
+        }
+        Ok(())
+    }
+
+    fn parse_exploration_target(&mut self, params: &mut GoalParameters) -> Result<(), GoalParseError> {
+        let Some(token) = self.peek().cloned() else {
+            params.target = Some("NEARBY".to_string());
+            return Ok(());
+        };
+
+        if is_system_symbol(&token.lower) {
+            params.location = Some(GoalLocation::System(token.text.to_uppercase()));
+            params.target = Some(token.text.to_uppercase());
+            self.pos += 1;
+        } else if token.lower.starts_with("shipyard") {
+            params.target = Some("SHIPYARDS".to_string());
+            self.pos += 1;
+        } else if token.lower.starts_with("market") {
+            params.target = Some("MARKETS".to_string());
+            self.pos += 1;
+        } else if token.lower == "system" || token.lower == "nearby" {
+            params.target = Some("NEARBY".to_string());
+            self.pos += 1;
+        } else {
+            params.target = Some("NEARBY".to_string());
+        }
+        Ok(())
+    }
+
+    fn parse_debug_target(&mut self, params: &mut GoalParameters) -> Result<(), GoalParseError> {
+        let Some(token) = self.peek().cloned() else {
+            params.target = Some("system".to_string());
+            return Ok(());
+        };
+
+        let target = if is_ship_symbol(&token.lower) || is_waypoint_symbol(&token.lower) {
+            format!("ship:{}", token.text.to_uppercase())
+        } else if token.lower.starts_with("contract") {
+            "contracts".to_string()
+        } else if token.lower == "fleet" || token.lower == "agent" {
+            token.lower.clone()
+        } else if token.lower.starts_with("waypoint") {
+            "waypoints".to_string()
+        } else if token.lower == "system" {
+            "system".to_string()
+        } else {
+            return Err(self.error_at(&token, "Unknown debug target, expected a ship symbol, fleet, agent, contracts, waypoints or system"));
+        };
+
+        params.target = Some(target);
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn parse_at(&mut self, params: &mut GoalParameters) -> Result<(), GoalParseError> {
+        let next = self.expect_token("Expected a waypoint or 'best market' after 'at'")?;
+        if next.lower == "best" {
+            if let Some(following) = self.peek()
+                && (following.lower == "market" || following.lower == "price") {
+                self.pos += 1;
+            }
+            params.market = Some(MarketTarget::Best);
+        } else if next.lower == "market" {
+            let waypoint = self.expect_symbol("Expected a waypoint after 'market'", is_waypoint_symbol)?;
+            params.market = Some(MarketTarget::Waypoint(waypoint));
+        } else if is_waypoint_symbol(&next.lower) {
+            params.location = Some(GoalLocation::Waypoint(next.text.to_uppercase()));
+        } else {
+            return Err(self.error_at(&next, "Expected a waypoint (e.g. X1-AB12-C3) or 'best market' after 'at'"));
+        }
+        Ok(())
+    }
+
+    fn parse_ship_selector(&mut self, params: &mut GoalParameters) -> Result<(), GoalParseError> {
+        let next = self.expect_token("Expected a ship count or ship symbol after 'with'")?;
+
+        if let Ok(count) = next.lower.parse::<usize>() {
+            if count == 0 {
+                return Err(self.error_at(&next, "Ship count must be at least 1"));
+            }
+            match self.peek() {
+                Some(token) if token.lower.starts_with("ship") || SHIP_ROLES.contains(&token.lower.trim_end_matches('s')) => {
+                    self.pos += 1;
+                    // "with 3 mining ships"
+                    if let Some(token) = self.peek()
+                        && (token.lower == "ships" || token.lower == "ship") {
+                        self.pos += 1;
+                    }
+                }
+                Some(token) => {
+                    let token = token.clone();
+                    return Err(self.error_at(&token, "Expected 'ships' after ship count"));
+                }
+                None => return Err(self.error_at_end("Expected 'ships' after ship count")),
+            }
+            params.ships = ShipSelector::Count(count);
+            return Ok(());
+        }
+
+        if !is_ship_symbol(&next.lower) {
+            return Err(self.error_at(&next, "Expected a ship count (e.g. '3 ships') or ship symbol (e.g. AGENT-1)"));
+        }
+
+        let mut symbols = vec![next.text.to_uppercase()];
+        while let Some(token) = self.peek().cloned() {
+            if is_ship_symbol(&token.lower) {
+                symbols.push(token.text.to_uppercase());
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        params.ships = ShipSelector::Symbols(symbols);
+        Ok(())
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn expect_token(&mut self, message: &str) -> Result<Token, GoalParseError> {
+        match self.tokens.get(self.pos).cloned() {
+            Some(token) => {
+                self.pos += 1;
+                Ok(token)
+            }
+            None => Err(self.error_at_end(message)),
+        }
+    }
+
+    fn expect_symbol(&mut self, message: &str, is_valid: fn(&str) -> bool) -> Result<String, GoalParseError> {
+        let token = self.expect_token(message)?;
+        if is_valid(&token.lower) {
+            Ok(token.text.to_uppercase())
+        } else {
+            Err(self.error_at(&token, message))
+        }
+    }
+
+    fn error_at(&self, token: &Token, message: &str) -> GoalParseError {
+        GoalParseError {
+            message: message.to_string(),
+            input: self.input.to_string(),
+            token: Some(token.text.clone()),
+            column: token.column,
+        }
+    }
+
+    /// Error at the current token, or at the end of input if there is none
+    fn error_here(&self, message: &str) -> GoalParseError {
+        match self.peek() {
+            Some(token) => self.error_at(token, message),
+            None => self.error_at_end(message),
+        }
+    }
+
+    fn error_at_end(&self, message: &str) -> GoalParseError {
+        GoalParseError {
+            message: message.to_string(),
+            input: self.input.to_string(),
+            token: None,
+            column: self.input.chars().count(),
+        }
+    }
+}
+
+/// Parse a `--goal` command into structured clauses
+pub fn parse_goal_command(input: &str) -> Result<GoalCommand, GoalParseError> {
+    GoalParser::new(input).parse()
+}
+
+fn tokenize(input: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut start = 0;
+
+    for (column, c) in input.chars().enumerate() {
+        if c.is_whitespace() || c == ',' {
+            if !current.is_empty() {
+                tokens.push(make_token(&current, start));
+                current.clear();
+            }
+            if c == ',' {
+                tokens.push(make_token(",", column));
+            }
+        } else {
+            if current.is_empty() {
+                start = column;
+            }
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(make_token(&current, start));
+    }
+
+    // A comma directly after a ship list is a list separator, not a clause separator
+    let mut filtered: Vec<Token> = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        if token.lower == "," {
+            let prev_is_ship = filtered.last().map(|t| is_ship_symbol(&t.lower)).unwrap_or(false);
+            let next_is_ship = tokens.get(i + 1).map(|t| is_ship_symbol(&t.lower)).unwrap_or(false);
+            if prev_is_ship && next_is_ship {
+                continue;
+            }
+        }
+        filtered.push(token.clone());
+    }
+    filtered
+}
+
+fn make_token(text: &str, column: usize) -> Token {
+    Token {
+        text: text.to_string(),
+        lower: text.to_lowercase(),
+        column,
+    }
+}
+
+fn symbol_parts(token: &str) -> Vec<&str> {
+    token.split('-').collect()
+}
+
+fn is_sector(part: &str) -> bool {
+    part.len() >= 2 && part.starts_with('x') && part[1..].chars().all(|c| c.is_ascii_digit())
+}
+
+/// X1-AB12-C3
+fn is_waypoint_symbol(token: &str) -> bool {
+    let parts = symbol_parts(token);
+    parts.len() == 3 && is_sector(parts[0]) && parts.iter().all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// X1-AB12
+fn is_system_symbol(token: &str) -> bool {
+    let parts = symbol_parts(token);
+    parts.len() == 2 && is_sector(parts[0]) && !parts[1].is_empty() && parts[1].chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// AGENT-1, AGENT-1A
+fn is_ship_symbol(token: &str) -> bool {
+    let parts = symbol_parts(token);
+    parts.len() == 2
+        && !is_sector(parts[0])
+        && !parts[0].is_empty()
+        && parts[0].chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !parts[1].is_empty()
+        && parts[1].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn parse_duration(token: &str) -> Option<Duration> {
+    let (number, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+    let value: i64 = number.parse().ok()?;
+    match unit {
+        "s" | "sec" | "secs" => Some(Duration::seconds(value)),
+        "m" | "min" | "mins" => Some(Duration::minutes(value)),
+        "h" | "hr" | "hrs" => Some(Duration::hours(value)),
+        "d" | "day" | "days" => Some(Duration::days(value)),
+        _ => None,
+    }
+}
+
+fn parse_deadline(token: &str) -> Option<DateTime<Utc>> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(token) {
+        return Some(datetime.with_timezone(&Utc));
+    }
+    if let Ok(naive) = chrono::NaiveDateTime::parse_from_str(token, "%Y-%m-%dT%H:%M") {
+        return Some(naive.and_utc());
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(token, "%Y-%m-%d") {
+        return date.and_hms_opt(23, 59, 59).map(|naive| naive.and_utc());
+    }
+    if let Ok(time) = NaiveTime::parse_from_str(token, "%H:%M") {
+        // A bare time means the next occurrence of it (UTC)
+        let now = Utc::now();
+        let today = now.date_naive().and_time(time).and_utc();
+        return Some(if today > now { today } else { today + Duration::days(1) });
+    }
+    None
+}
+
+fn normalize_role(role: &str) -> String {
+    match role {
+        "refinery" => "refiner",
+        "cargo" => "hauler",
+        "mining" => "miner",
+        "probe" => "scout",
+        other => other,
+    }
+    .to_string()
+}
+
+fn normalize_ship_type(object: &str) -> String {
+    match object {
+        "MINER" | "EXCAVATOR" => "MINING".to_string(),
+        "FREIGHTER" | "CARGO" => "HAULER".to_string(),
+        "SATELLITE" => "PROBE".to_string(),
+        other => other.to_string(),
+    }
+}
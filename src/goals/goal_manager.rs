@@ -1,9 +1,18 @@
 // Goal Manager - Manages goal queue and execution
-use crate::goals::{Goal, GoalPriority, GoalStatus, GoalContext, GoalResult, FleetStatus};
+use crate::goals::{Goal, GoalPriority, GoalContext, GoalResult, FleetStatus, ResourceAllocator, ShipSelector,
+                   GoalInterpreter, GoalProgressTracker};
+use crate::storage::{GoalStore, StoredGoal, StoredGoalState};
 use crate::client::PriorityApiClient;
 use crate::{o_debug, o_info, o_error};
+use chrono::{DateTime, Duration, Utc};
 use std::collections::{BinaryHeap, HashMap};
 use std::cmp::Ordering;
+use tokio::task::JoinHandle;
+
+pub const GOAL_STORE_PATH: &str = "storage/goal_queue.json";
+
+/// How long a running goal may overrun its deadline before it is stopped
+const DEADLINE_GRACE_SECONDS: i64 = 60;
 
 struct QueuedGoal {
     goal: Box<dyn Goal>,
@@ -32,109 +41,403 @@ impl Ord for QueuedGoal {
     }
 }
 
+/// A goal executing on its own tokio task, with the ships reserved for it
+struct RunningGoal {
+    handle: JoinHandle<(Box<dyn Goal>, Result<GoalResult, String>)>,
+    ships: Vec<String>,
+    description: String,
+    priority: GoalPriority,
+    deadline: Option<DateTime<Utc>>,
+}
+
+impl RunningGoal {
+    fn is_overdue(&self) -> bool {
+        self.deadline.is_some_and(|deadline| Utc::now() > deadline + Duration::seconds(DEADLINE_GRACE_SECONDS))
+    }
+}
+
+/// Whether a queued goal's dependencies allow it to start
+enum DependencyState {
+    Ready,
+    Waiting,
+    Failed(String),
+}
+
 pub struct GoalManager {
     goal_queue: BinaryHeap<QueuedGoal>,
-    active_goals: HashMap<String, Box<dyn Goal>>,
+    running_goals: HashMap<String, RunningGoal>,
     completed_goals: Vec<(String, GoalResult)>,
     failed_goals: Vec<(String, String)>, // goal_id, error_message
+    resource_allocator: ResourceAllocator,
+    progress: GoalProgressTracker,
+    store: Option<GoalStore>,
     max_concurrent_goals: usize,
+    paused: bool,
 }
 
 impl GoalManager {
     pub fn new() -> Self {
         Self {
             goal_queue: BinaryHeap::new(),
-            active_goals: HashMap::new(),
+            running_goals: HashMap::new(),
             completed_goals: Vec::new(),
             failed_goals: Vec::new(),
+            resource_allocator: ResourceAllocator::new(),
+            progress: GoalProgressTracker::default(),
+            store: None,
             max_concurrent_goals: 3, // Can run up to 3 goals concurrently
+            paused: false,
         }
     }
 
-    /// Add a goal to the execution queue
+    /// Create a manager that saves its queue and goal progress to disk
+    pub fn with_store(storage_path: &str) -> Self {
+        Self {
+            store: Some(GoalStore::new(storage_path)),
+            ..Self::new()
+        }
+    }
+
+    /// Re-queue goals left unfinished by a previous run, with their progress.
+    /// Finished goals go back into the history so dependencies on them resolve.
+    pub fn restore_goals(&mut self, interpreter: &GoalInterpreter) -> usize {
+        let Some(store) = &self.store else {
+            return 0;
+        };
+        let stored_goals: Vec<StoredGoal> = store.goals().to_vec();
+        let mut restored = 0;
+        
+        for stored in stored_goals {
+            match &stored.state {
+                StoredGoalState::Completed => {
+                    self.completed_goals.push((stored.id.clone(), GoalResult {
+                        success: true,
+                        message: format!("{} (completed before restart)", stored.description),
+                        ships_used: stored.progress.ships.clone(),
+                        resources_consumed: HashMap::new(),
+                        credits_spent: -stored.progress.credits as i32,
+                        execution_time: 0.0,
+                    }));
+                }
+                StoredGoalState::Failed(reason) => {
+                    self.failed_goals.push((stored.id.clone(), reason.clone()));
+                }
+                StoredGoalState::Queued | StoredGoalState::Running => {
+                    match interpreter.restore_goal(&stored) {
+                        Ok(goal) => {
+                            o_info!("🔁 Resuming goal: {} [{}] ({} units done)", stored.description, stored.id, stored.progress.units_completed);
+                            self.progress.set(&stored.id, stored.progress.clone());
+                            self.goal_queue.push(QueuedGoal {
+                                goal,
+                                queued_at: std::time::Instant::now(),
+                            });
+                            self.persist(|store| store.set_state(&stored.id, StoredGoalState::Queued));
+                            restored += 1;
+                        }
+                        Err(e) => {
+                            o_error!("❌ Could not restore goal {}: {}", stored.id, e);
+                            self.fail_goal(&stored.id, format!("Could not restore after restart: {}", e));
+                        }
+                    }
+                }
+            }
+        }
+        
+        restored
+    }
+
+    /// Whether a previous run left goals queued or running
+    pub fn has_stored_goals(storage_path: &str) -> bool {
+        GoalStore::new(storage_path).has_unfinished_goals()
+    }
+
+    /// Discard every saved goal
+    pub fn clear_stored_goals(&mut self) {
+        if let Some(store) = &mut self.store {
+            match store.clear_all() {
+                Ok(count) => o_info!("🧹 Discarded {} saved goals", count),
+                Err(e) => o_error!("❌ Failed to clear saved goals: {}", e),
+            }
+        }
+    }
+
+    fn persist(&mut self, update: impl FnOnce(&mut GoalStore) -> Result<(), Box<dyn std::error::Error>>) {
+        if let Some(store) = &mut self.store
+            && let Err(e) = update(store) {
+            o_error!("❌ Failed to save goal queue: {}", e);
+        }
+    }
+
+    /// Add a goal to the execution queue. It starts once every goal it depends on has completed.
     pub fn add_goal(&mut self, goal: Box<dyn Goal>) {
         let goal_id = goal.id();
         let priority = goal.priority();
+        let dependencies = goal.dependencies();
+        
+        if self.running_goals.contains_key(&goal_id) || self.goal_queue.iter().any(|queued| queued.goal.id() == goal_id) {
+            o_info!("📋 Goal {} is already queued - keeping its saved progress", goal_id);
+            return;
+        }
+        
+        // A re-run goal starts fresh rather than inheriting an old outcome
+        self.completed_goals.retain(|(id, _)| *id != goal_id);
+        self.failed_goals.retain(|(id, _)| *id != goal_id);
+        self.progress.remove(&goal_id);
+        if let Some(action) = goal.action() {
+            let stored = StoredGoal {
+                id: goal_id.clone(),
+                action,
+                description: goal.description(),
+                priority,
+                params: goal.parameters(),
+                state: StoredGoalState::Queued,
+                progress: Default::default(),
+                queued_at: Utc::now(),
+                finished_at: None,
+            };
+            self.persist(|store| store.upsert_goal(stored));
+        }
         
         o_info!("📋 Adding goal to queue: {} [{}] (priority: {})", goal.description(), goal_id, priority as u8);
+        if !dependencies.is_empty() {
+            o_debug!("🔗 Goal {} waits for: {}", goal_id, dependencies.join(", "));
+        }
         
         self.goal_queue.push(QueuedGoal {
             goal,
             queued_at: std::time::Instant::now(),
         });
         
-        o_debug!("📊 Goal queue status: {} queued, {} active", 
-                self.goal_queue.len(), self.active_goals.len());
+        o_debug!("📊 Goal queue status: {} queued, {} running", 
+                self.goal_queue.len(), self.running_goals.len());
     }
 
-    /// Execute the next highest priority goals
+    /// Start every goal that is ready and has free ships, then wait for at least one
+    /// running goal to finish. Returns the results of the goals that finished.
     pub async fn execute_goals(&mut self, client: &PriorityApiClient, context: &GoalContext) -> Result<Vec<GoalResult>, Box<dyn std::error::Error>> {
-        let mut results = Vec::new();
+        let mut results = self.collect_finished_goals().await;
         
-        // Clean up completed active goals first
-        self.cleanup_completed_goals();
-        
-        // Start new goals if we have capacity and queued goals
-        while self.active_goals.len() < self.max_concurrent_goals && !self.goal_queue.is_empty() {
-            if let Some(queued_goal) = self.goal_queue.pop() {
-                let goal = queued_goal.goal;
-                let goal_id = goal.id();
-                
-                o_info!("🚀 Starting goal execution: {}", goal.description());
-                
-                // Validate goal before execution
-                match goal.validate(context).await {
-                    Ok(true) => {
-                        // Move goal to active execution
-                        self.active_goals.insert(goal_id.clone(), goal);
-                        o_debug!("✅ Goal validated and activated: {}", goal_id);
-                    }
-                    Ok(false) => {
-                        o_info!("⏸️ Goal validation failed (will retry later): {}", goal_id);
-                        // Put goal back in queue for later retry
-                        self.goal_queue.push(QueuedGoal {
-                            goal,
-                            queued_at: std::time::Instant::now(),
-                        });
-                        break; // Don't try more goals for now
-                    }
-                    Err(e) => {
-                        o_error!("❌ Goal validation error: {} - {}", goal_id, e);
-                        self.failed_goals.push((goal_id, e));
-                        continue; // Try next goal
-                    }
+        if !self.paused {
+            self.start_ready_goals(client, context).await;
+        }
+        
+        // Wait for progress so callers don't spin while goals run
+        while results.is_empty()
+            && !self.running_goals.is_empty()
+            && !self.running_goals.values().any(|running| running.handle.is_finished() || running.is_overdue()) {
+            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+            self.save_progress();
+        }
+        
+        self.stop_overdue_goals();
+        results.extend(self.collect_finished_goals().await);
+        self.save_progress();
+        Ok(results)
+    }
+
+    /// Copy running goals' progress into the goal store and write out new extraction records
+    fn save_progress(&mut self) {
+        let updates: Vec<_> = self.running_goals.keys()
+            .map(|goal_id| (goal_id.clone(), self.progress.get(goal_id)))
+            .collect();
+        self.persist(|store| store.update_progress(updates));
+        if let Err(e) = crate::storage::get_extraction_log().flush() {
+            o_debug!("⚠️ Failed to save extraction log: {}", e);
+        }
+    }
+
+    /// Stop running goals that have overrun their deadline
+    fn stop_overdue_goals(&mut self) {
+        let overdue: Vec<String> = self.running_goals.iter()
+            .filter(|(_, running)| running.is_overdue() && !running.handle.is_finished())
+            .map(|(goal_id, _)| goal_id.clone())
+            .collect();
+        
+        for goal_id in overdue {
+            if let Some(running) = self.running_goals.remove(&goal_id) {
+                running.handle.abort();
+                self.resource_allocator.release_ships(&goal_id);
+                let deadline = running.deadline.map(|d| d.to_rfc3339()).unwrap_or_default();
+                o_info!("⏰ Goal {} overran its deadline ({}) - stopping it", goal_id, deadline);
+                self.fail_goal(&goal_id, format!("Deadline {} passed before the goal finished", deadline));
+            }
+        }
+    }
+
+    async fn start_ready_goals(&mut self, client: &PriorityApiClient, context: &GoalContext) {
+        let mut deferred = Vec::new();
+        
+        while self.running_goals.len() < self.max_concurrent_goals {
+            let Some(queued_goal) = self.goal_queue.pop() else {
+                break;
+            };
+            let goal_id = queued_goal.goal.id();
+            
+            if queued_goal.goal.parameters().is_past_deadline() {
+                self.fail_goal(&goal_id, "Deadline passed before the goal could start".to_string());
+                continue;
+            }
+            
+            match self.dependency_state(&*queued_goal.goal, &deferred) {
+                DependencyState::Ready => {}
+                DependencyState::Waiting => {
+                    deferred.push(queued_goal);
+                    continue;
+                }
+                DependencyState::Failed(reason) => {
+                    self.fail_goal(&goal_id, reason);
+                    continue;
                 }
             }
+            
+            // Validate goal before execution
+            match queued_goal.goal.validate(context).await {
+                Ok(true) => {}
+                Ok(false) => {
+                    o_info!("⏸️ Goal validation failed (will retry later): {}", goal_id);
+                    deferred.push(queued_goal);
+                    continue;
+                }
+                Err(e) => {
+                    o_error!("❌ Goal validation error: {} - {}", goal_id, e);
+                    self.fail_goal(&goal_id, e);
+                    continue;
+                }
+            }
+            
+            // Goals whose ships are held by a running goal wait until those ships are released
+            let mut goal = queued_goal.goal;
+            let ships = match self.resource_allocator.allocate_ships(&*goal, context) {
+                Ok(ships) => ships,
+                Err(e) if !self.running_goals.is_empty() => {
+                    o_debug!("⏳ Goal {} waiting for ships: {}", goal_id, e);
+                    deferred.push(QueuedGoal { goal, queued_at: queued_goal.queued_at });
+                    continue;
+                }
+                Err(e) => {
+                    o_info!("⚠️ Resource allocation warning for goal '{}': {}", goal.description(), e);
+                    // Continue anyway - the goal execution may handle this gracefully
+                    Vec::new()
+                }
+            };
+            
+            // Pin the goal to its allocated ships so concurrent goals never share a ship
+            if !ships.is_empty()
+                && let Some(params) = goal.parameters_mut()
+                && !matches!(params.ships, ShipSelector::Symbols(_)) {
+                params.ships = ShipSelector::Symbols(ships.clone());
+            }
+            
+            self.spawn_goal(goal, ships, client, context);
         }
         
-        // Execute active goals (in practice, this would be concurrent)
-        // For now, we'll execute them sequentially for simplicity
-        let active_goal_ids: Vec<String> = self.active_goals.keys().cloned().collect();
-        
-        for goal_id in active_goal_ids {
-            if let Some(mut goal) = self.active_goals.remove(&goal_id) {
-                o_debug!("⚡ Executing goal: {}", goal.description());
-                
-                match goal.execute(client, context).await {
-                    Ok(result) => {
-                        o_info!("✅ Goal completed: {} - {}", goal_id, result.message);
-                        results.push(result.clone());
-                        self.completed_goals.push((goal_id, result));
-                    }
-                    Err(e) => {
-                        o_error!("❌ Goal execution failed: {} - {}", goal_id, e);
-                        self.failed_goals.push((goal_id, e.to_string()));
-                    }
+        for queued_goal in deferred {
+            self.goal_queue.push(queued_goal);
+        }
+    }
+
+    fn spawn_goal(&mut self, mut goal: Box<dyn Goal>, ships: Vec<String>, client: &PriorityApiClient, context: &GoalContext) {
+        let goal_id = goal.id();
+        let description = goal.description();
+        let priority = goal.priority();
+        let deadline = goal.parameters().deadline;
+        
+        o_info!("🚀 Starting goal execution: {}", description);
+        if !ships.is_empty() {
+            o_info!("📦 Allocated {} ships for goal {}: {}", ships.len(), goal_id, ships.join(", "));
+        }
+        self.progress.update(&goal_id, |progress| progress.ships = ships.clone());
+        let progress = self.progress.get(&goal_id);
+        self.persist(|store| {
+            store.update_progress(vec![(goal_id.clone(), progress)])?;
+            store.set_state(&goal_id, StoredGoalState::Running)
+        });
+        
+        let client = client.clone();
+        let mut context = context.clone();
+        context.progress = self.progress.clone();
+        let handle = tokio::spawn(async move {
+            let result = goal.execute(&client, &context).await.map_err(|e| e.to_string());
+            (goal, result)
+        });
+        
+        self.running_goals.insert(goal_id, RunningGoal { handle, ships, description, priority, deadline });
+    }
+
+    /// Reap finished goal tasks, release their ships and record the outcome
+    async fn collect_finished_goals(&mut self) -> Vec<GoalResult> {
+        let mut results = Vec::new();
+        let finished: Vec<String> = self.running_goals.iter()
+            .filter(|(_, running)| running.handle.is_finished())
+            .map(|(goal_id, _)| goal_id.clone())
+            .collect();
+        
+        for goal_id in finished {
+            let Some(running) = self.running_goals.remove(&goal_id) else {
+                continue;
+            };
+            self.resource_allocator.release_ships(&goal_id);
+            
+            match running.handle.await {
+                Ok((_, Ok(result))) if result.success => {
+                    o_info!("✅ Goal completed: {} - {}", goal_id, result.message);
+                    self.persist(|store| store.set_state(&goal_id, StoredGoalState::Completed));
+                    results.push(result.clone());
+                    self.completed_goals.push((goal_id, result));
                 }
+                Ok((_, Ok(result))) => {
+                    results.push(result.clone());
+                    self.fail_goal(&goal_id, result.message);
+                }
+                Ok((_, Err(e))) => self.fail_goal(&goal_id, e),
+                Err(e) => self.fail_goal(&goal_id, format!("Goal task stopped: {}", e)),
             }
         }
         
-        Ok(results)
+        results
+    }
+
+    /// `deferred` holds goals popped from the queue this round that will be queued again
+    fn dependency_state(&self, goal: &dyn Goal, deferred: &[QueuedGoal]) -> DependencyState {
+        let mut state = DependencyState::Ready;
+        
+        for dependency in goal.dependencies() {
+            if self.completed_goals.iter().any(|(goal_id, _)| *goal_id == dependency) {
+                continue;
+            }
+            if let Some((_, reason)) = self.failed_goals.iter().find(|(goal_id, _)| *goal_id == dependency) {
+                return DependencyState::Failed(format!("Dependency {} failed: {}", dependency, reason));
+            }
+            let known = self.running_goals.contains_key(&dependency)
+                || self.goal_queue.iter().chain(deferred).any(|queued| queued.goal.id() == dependency);
+            if !known {
+                return DependencyState::Failed(format!("Dependency {} is not a known goal", dependency));
+            }
+            state = DependencyState::Waiting;
+        }
+        
+        state
+    }
+
+    /// Record a goal failure and fail every queued goal that (transitively) depends on it
+    fn fail_goal(&mut self, goal_id: &str, reason: String) {
+        o_error!("❌ Goal failed: {} - {}", goal_id, reason);
+        self.failed_goals.push((goal_id.to_string(), reason.clone()));
+        self.persist(|store| store.set_state(goal_id, StoredGoalState::Failed(reason.clone())));
+        
+        let (dependents, remaining): (Vec<QueuedGoal>, Vec<QueuedGoal>) = std::mem::take(&mut self.goal_queue)
+            .into_iter()
+            .partition(|queued| queued.goal.dependencies().iter().any(|dependency| dependency == goal_id));
+        self.goal_queue = remaining.into_iter().collect();
+        
+        for dependent in dependents {
+            self.fail_goal(&dependent.goal.id(), format!("Dependency {} failed: {}", goal_id, reason));
+        }
     }
 
     /// Check if there are any goals pending or active
     pub fn has_pending_goals(&self) -> bool {
-        !self.goal_queue.is_empty() || !self.active_goals.is_empty()
+        !self.goal_queue.is_empty() || !self.running_goals.is_empty()
     }
 
     /// Get queue status for monitoring
@@ -143,13 +446,13 @@ impl GoalManager {
             .map(|qg| qg.goal.priority())
             .collect();
         
-        let active_priorities: Vec<GoalPriority> = self.active_goals.values()
-            .map(|g| g.priority())
+        let active_priorities: Vec<GoalPriority> = self.running_goals.values()
+            .map(|running| running.priority)
             .collect();
         
         GoalManagerStatus {
             queued_count: self.goal_queue.len(),
-            active_count: self.active_goals.len(),
+            active_count: self.running_goals.len(),
             completed_count: self.completed_goals.len(),
             failed_count: self.failed_goals.len(),
             highest_queued_priority: queued_priorities.iter().max().copied(),
@@ -159,65 +462,50 @@ impl GoalManager {
 
     /// Get descriptions of currently active goals
     pub fn get_active_goal_descriptions(&self) -> Vec<String> {
-        self.active_goals.values()
-            .map(|g| g.description())
+        self.running_goals.values()
+            .map(|running| format!("{} [{}]", running.description, running.ships.join(", ")))
             .collect()
     }
 
-    /// Cancel a goal by ID
+    /// Get the goals that failed, with the reason for each
+    pub fn get_failed_goals(&self) -> &[(String, String)] {
+        &self.failed_goals
+    }
+
+    /// Cancel a goal by ID. Goals depending on it fail with it.
     pub async fn cancel_goal(&mut self, goal_id: &str) -> Result<bool, String> {
-        // Try to cancel from active goals first
-        if let Some(mut goal) = self.active_goals.remove(goal_id) {
-            goal.cancel().await?;
-            o_info!("🛑 Cancelled active goal: {}", goal_id);
+        // Try to cancel from running goals first
+        if let Some(running) = self.running_goals.remove(goal_id) {
+            running.handle.abort();
+            self.resource_allocator.release_ships(goal_id);
+            o_info!("🛑 Cancelled running goal: {}", goal_id);
+            self.fail_goal(goal_id, "Cancelled".to_string());
             return Ok(true);
         }
         
         // Try to remove from queue
-        let mut temp_goals = Vec::new();
-        let mut found = false;
-        
-        while let Some(queued_goal) = self.goal_queue.pop() {
-            if queued_goal.goal.id() == goal_id {
-                found = true;
-                o_info!("🛑 Removed queued goal: {}", goal_id);
-                break;
-            } else {
-                temp_goals.push(queued_goal);
-            }
-        }
-        
-        // Restore queue
-        for goal in temp_goals {
-            self.goal_queue.push(goal);
-        }
-        
-        if !found {
+        let before = self.goal_queue.len();
+        self.goal_queue.retain(|queued| queued.goal.id() != goal_id);
+        if self.goal_queue.len() == before {
             return Err(format!("Goal not found: {}", goal_id));
         }
         
+        o_info!("🛑 Removed queued goal: {}", goal_id);
+        self.fail_goal(goal_id, "Cancelled".to_string());
         Ok(true)
     }
 
-    /// Pause all goals (for system maintenance, etc.)
+    /// Pause all goals (for system maintenance, etc.). Running goals finish; no new goals start.
     pub async fn pause_all_goals(&mut self) -> Result<(), String> {
-        o_info!("⏸️ Pausing all active goals");
-        
-        for goal in self.active_goals.values_mut() {
-            goal.pause().await?;
-        }
-        
+        o_info!("⏸️ Pausing goal execution ({} goals still running)", self.running_goals.len());
+        self.paused = true;
         Ok(())
     }
 
     /// Resume all paused goals
     pub async fn resume_all_goals(&mut self) -> Result<(), String> {
-        o_info!("▶️ Resuming all paused goals");
-        
-        for goal in self.active_goals.values_mut() {
-            goal.resume().await?;
-        }
-        
+        o_info!("▶️ Resuming goal execution");
+        self.paused = false;
         Ok(())
     }
 
@@ -228,12 +516,13 @@ impl GoalManager {
         
         self.completed_goals.clear();
         self.failed_goals.clear();
+        self.persist(|store| store.clear_finished().map(|_| ()));
         
         o_info!("🧹 Cleared goal history: {} completed, {} failed", completed_count, failed_count);
     }
 
-    /// Build context from current game state for goal execution
-    pub async fn build_context(&self, client: &PriorityApiClient) -> Result<GoalContext, Box<dyn std::error::Error>> {
+    /// Build context from current game state for goal execution, holding back the configured credit reserve
+    pub async fn build_context(&self, client: &PriorityApiClient, config: &crate::config::SpaceTradersConfig) -> Result<GoalContext, Box<dyn std::error::Error>> {
         o_debug!("🔄 Building goal execution context...");
         
         // Get current game state
@@ -252,7 +541,10 @@ impl GoalManager {
             known_waypoints: HashMap::new(), // Would be populated from cache in full implementation
             known_markets: HashMap::new(),   // Would be populated from cache in full implementation
             available_credits: credits,
+            credit_reserve: config.credits.min_reserve_credits,
+            fuel_safety_margin: config.fuel.fuel_safety_margin,
             fleet_status,
+            progress: Default::default(),
         })
     }
 
@@ -277,7 +569,7 @@ impl GoalManager {
             // Check if ship is available or busy
             if ship.nav.status == "IN_TRANSIT" {
                 busy_ships.insert(ship.symbol.clone(), "navigating".to_string());
-            } else if ship.fuel.current < 10 {
+            } else if ship.fuel.capacity > 0 && ship.fuel.current < 10 { // probes have no tank
                 busy_ships.insert(ship.symbol.clone(), "needs_fuel".to_string());
             } else {
                 available_ships.push(ship.symbol.clone());
@@ -292,17 +584,6 @@ impl GoalManager {
             probe_ships,
         }
     }
-
-    fn cleanup_completed_goals(&mut self) {
-        let completed: Vec<String> = self.active_goals.iter()
-            .filter(|(_, goal)| matches!(goal.status(), GoalStatus::Completed | GoalStatus::Failed(_)))
-            .map(|(id, _)| id.clone())
-            .collect();
-        
-        for goal_id in completed {
-            self.active_goals.remove(&goal_id);
-        }
-    }
 }
 
 #[derive(Debug, Clone)]
@@ -1,34 +1,21 @@
 // Goal Types - Concrete implementations of different goal types
-use crate::goals::{Goal, GoalPriority, GoalStatus, GoalContext, GoalResult, SurveyCache};
+use crate::goals::{Goal, GoalPriority, GoalStatus, GoalContext, GoalResult, GoalAction, GoalParameters, MarketTarget, ShipSelector};
 use crate::client::{PriorityApiClient, ApiPriority};
-use crate::models::transaction::Survey;
+use crate::operations::{has_refinery, recipe_for, CampaignEvent, RefineDecision, RefineryPlanner, RefineryStep, ResourceCampaign};
+use crate::storage::get_survey_pool;
 use crate::{o_debug, o_info};
 use async_trait::async_trait;
 use std::collections::HashMap;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MiningGoal {
     pub id: String,
     pub resource_type: String,
     pub target_quantity: i32,
     pub priority: GoalPriority,
     pub status: GoalStatus,
-    pub survey_cache: SurveyCache,
-    pub use_surveys: bool,
-}
-
-impl Clone for MiningGoal {
-    fn clone(&self) -> Self {
-        Self {
-            id: self.id.clone(),
-            resource_type: self.resource_type.clone(),
-            target_quantity: self.target_quantity,
-            priority: self.priority,
-            status: self.status.clone(),
-            survey_cache: SurveyCache::new(), // Create fresh cache for cloned goal
-            use_surveys: self.use_surveys,
-        }
-    }
+    pub params: GoalParameters,
+    pub use_surveys: bool, // draw from (and refill) the fleet survey pool
 }
 
 #[async_trait]
@@ -41,21 +28,37 @@ impl Goal for MiningGoal {
     fn status(&self) -> GoalStatus { self.status.clone() }
     fn estimated_duration(&self) -> f64 { (self.target_quantity as f64) * 30.0 } // 30 sec per unit
     fn required_resources(&self) -> Vec<String> { vec!["mining_ship".to_string()] }
+    fn action(&self) -> Option<GoalAction> { Some(GoalAction::Mine) }
+    fn parameters(&self) -> GoalParameters { self.params.clone() }
+    fn parameters_mut(&mut self) -> Option<&mut GoalParameters> { Some(&mut self.params) }
 
     async fn validate(&self, context: &GoalContext) -> Result<bool, String> {
         // Check if we have mining ships available
         if context.fleet_status.mining_ships.is_empty() {
             return Err("No mining ships available".to_string());
         }
-        
-        // Check if any mining ships are available (not busy)
-        let available_miners: Vec<_> = context.fleet_status.mining_ships.iter()
-            .filter(|ship| !context.fleet_status.busy_ships.contains_key(*ship))
-            .collect();
-        
+
+        if self.params.is_past_deadline() {
+            return Err("Goal deadline has already passed".to_string());
+        }
+
+        // Explicitly named ships must exist and be able to mine
+        if let ShipSelector::Symbols(symbols) = &self.params.ships {
+            for symbol in symbols {
+                if !context.fleet_status.mining_ships.contains(symbol) {
+                    return Err(format!("Ship {} is not a mining ship in this fleet", symbol));
+                }
+            }
+        }
+
+        let available_miners = self.select_mining_ships(context);
         if available_miners.is_empty() {
             return Err("All mining ships are busy".to_string());
         }
+        if let ShipSelector::Count(count) = self.params.ships
+            && available_miners.len() < count {
+            o_info!("⚠️ Requested {} mining ships but only {} are available", count, available_miners.len());
+        }
 
         Ok(true)
     }
@@ -66,142 +69,94 @@ impl Goal for MiningGoal {
                self.description());
         self.status = GoalStatus::Active;
 
-        // Find available mining ship
-        let mining_ship = context.fleet_status.mining_ships.iter()
-            .find(|ship| !context.fleet_status.busy_ships.contains_key(*ship))
-            .ok_or("No available mining ships")?;
-
-        let ship = client.get_ship(mining_ship).await?;
-        o_debug!("🚢 Using mining ship: {} at {}", ship.symbol, ship.nav.waypoint_symbol);
-
-        // Find mining location for the resource
-        let mining_location = self.find_mining_location(client, &self.resource_type, &ship.nav.system_symbol).await?;
-        o_info!("📍 Mining location found: {}", mining_location);
-
-        let mut total_mined = 0;
-        let start_time = std::time::Instant::now();
-        let mut active_survey: Option<Survey> = None;
-
-        // Navigate to mining location if needed
-        if ship.nav.waypoint_symbol != mining_location {
-            o_debug!("🗺️ Navigating {} to {}", ship.symbol, mining_location);
-            client.navigate_ship_with_priority(&ship.symbol, &mining_location, ApiPriority::Override).await?;
-            tokio::time::sleep(tokio::time::Duration::from_secs(10)).await;
+        let miner_symbols = self.select_mining_ships(context);
+        if miner_symbols.is_empty() {
+            return Err("No available mining ships".into());
         }
 
-        // Ensure ship is in orbit for mining
-        if ship.nav.status != "IN_ORBIT" {
-            client.orbit_ship_with_priority(&ship.symbol, ApiPriority::Override).await?;
+        // Units mined before a restart still count toward the target
+        let already_mined = context.progress.get(&self.id).units_completed;
+        if already_mined > 0 {
+            o_info!("🔁 Resuming mining goal with {}/{} {} already mined", already_mined, self.target_quantity, self.resource_type);
         }
 
-        while total_mined < self.target_quantity {
-            // Get survey if using survey-based mining and don't have an active one
-            if self.use_surveys && active_survey.is_none() {
-                o_debug!("🔍 Getting survey for {} at {}", self.resource_type, mining_location);
-                match self.survey_cache.get_best_survey(client, &ship.symbol, &mining_location, &self.resource_type).await {
-                    Ok(Some(survey)) => {
-                        active_survey = Some(survey.clone());
-                        o_info!("📋 Using survey {} for targeted {} mining", survey.signature, self.resource_type);
-                    }
-                    Ok(None) => {
-                        o_debug!("📋 No suitable survey found, using standard mining");
-                    }
-                    Err(e) => {
-                        o_debug!("⚠️ Survey failed: {} - falling back to standard mining", e);
-                    }
-                }
-            }
+        let mut campaign = ResourceCampaign::new(&self.resource_type, self.target_quantity);
+        campaign.ships = miner_symbols;
+        campaign.candidate_waypoints = self.params.waypoint().map(|waypoint| vec![waypoint.to_string()]).unwrap_or_default();
+        campaign.system_symbol = self.params.location.as_ref().map(|location| location.system_symbol());
+        campaign.already_collected = already_mined;
+        campaign.deadline = self.params.deadline;
+        campaign.use_surveys = self.use_surveys;
 
-            // Mine resources with or without survey
-            o_debug!("⛏️ {} mining at {}", ship.symbol, mining_location);
-            
-            let extraction_data = match if let Some(ref survey) = active_survey {
-                client.extract_resources_with_priority(&ship.symbol, Some(survey), ApiPriority::Override).await
-            } else {
-                client.extract_resources_with_priority(&ship.symbol, None, ApiPriority::Override).await
-            } {
-                Ok(data) => data,
-                Err(e) => {
-                    let error_str = e.to_string();
-                    if error_str.contains("survey") && error_str.contains("expired") {
-                        o_info!("📋 Survey expired, clearing active survey");
-                        active_survey = None; // Clear expired survey
-                        continue; // Try again without survey
-                    } else {
-                        o_debug!("⚠️ Mining operation failed: {}", error_str);
-                        // Exit loop, will handle retry at a higher level
-                        break;
-                    }
+        let start_time = std::time::Instant::now();
+        let mut events = campaign.subscribe();
+        let progress = context.progress.clone();
+        let goal_id = self.id.clone();
+        let (report, _) = tokio::join!(campaign.run(client), async move {
+            while let Some(event) = events.recv().await {
+                if let CampaignEvent::Extracted { total, .. } = event {
+                    progress.update(&goal_id, |progress| progress.units_completed = total);
                 }
-            };
-
-            // Extract the actual mining yield from the response
-            let yield_item = &extraction_data.extraction.extraction_yield;
-            
-            if yield_item.symbol == self.resource_type {
-                total_mined += yield_item.units;
-                o_info!("💎 Mined {} {} (total: {}/{}) {}", 
-                       yield_item.units, yield_item.symbol, total_mined, self.target_quantity,
-                       if active_survey.is_some() { "📋 [SURVEY]" } else { "" });
-            } else {
-                o_debug!("⚪ Mined {} {} (not target resource)", yield_item.units, yield_item.symbol);
             }
+        });
+        let report = report?;
 
-            // Check if cargo is full
-            let updated_ship = client.get_ship(&ship.symbol).await?;
-            if updated_ship.cargo.units >= updated_ship.cargo.capacity {
-                o_info!("📦 Cargo full, goal may need hauling support");
-                break;
-            }
-
-            // Brief pause between mining operations
-            tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
-        }
-
-        let execution_time = start_time.elapsed().as_secs_f64();
-        self.status = if total_mined >= self.target_quantity {
+        self.status = if report.success() {
             GoalStatus::Completed
+        } else if report.deadline_missed {
+            GoalStatus::Failed(format!("Deadline passed after mining {}/{} {}", report.collected, self.target_quantity, self.resource_type))
         } else {
             GoalStatus::Paused // Needs more resources or hauling
         };
 
-        // Log survey cache statistics
+        // Log survey pool statistics
         if self.use_surveys {
-            let stats = self.survey_cache.get_stats();
-            o_debug!("📊 Survey cache stats: {} surveys across {} waypoints", 
-                    stats.total_surveys, stats.waypoints_cached);
+            let stats = get_survey_pool().stats();
+            o_debug!("📊 Survey pool stats: {} surveys across {} waypoints", 
+                    stats.total_surveys, stats.waypoints);
         }
 
         Ok(GoalResult {
-            success: total_mined >= self.target_quantity,
+            success: report.success(),
             message: format!("Mined {}/{} {} units{}", 
-                           total_mined, self.target_quantity, self.resource_type,
+                           report.collected, self.target_quantity, self.resource_type,
                            if self.use_surveys { " using surveys" } else { "" }),
-            ships_used: vec![ship.symbol.clone()],
+            ships_used: report.ships_used,
             resources_consumed: HashMap::new(),
             credits_spent: 0,
-            execution_time,
+            execution_time: start_time.elapsed().as_secs_f64(),
         })
     }
 }
 
 impl MiningGoal {
-    async fn find_mining_location(&self, client: &PriorityApiClient, resource: &str, system: &str) -> Result<String, Box<dyn std::error::Error>> {
-        // Get system waypoints and find asteroid fields with the desired resource
-        let waypoints = client.get_system_waypoints(system, None).await?;
-        
-        for waypoint in waypoints {
-            if waypoint.waypoint_type.contains("ASTEROID") {
-                // In a full implementation, would check waypoint traits for specific resources
-                o_debug!("🗿 Found potential mining site: {} ({})", waypoint.symbol, waypoint.waypoint_type);
-                return Ok(waypoint.symbol);
-            }
+    /// Mining ships this goal may use, honoring the goal's ship selector
+    fn select_mining_ships(&self, context: &GoalContext) -> Vec<String> {
+        let available = context.fleet_status.mining_ships.iter()
+            .filter(|ship| !context.fleet_status.busy_ships.contains_key(*ship))
+            .cloned();
+
+        match &self.params.ships {
+            ShipSelector::Any => available.take(1).collect(),
+            ShipSelector::Count(count) => available.take(*count).collect(),
+            ShipSelector::Symbols(symbols) => available.filter(|ship| symbols.contains(ship)).collect(),
         }
-        
-        Err(format!("No mining locations found for {} in system {}", resource, system).into())
     }
 }
 
+/// Sleep until a ship's route arrival time (plus a small buffer)
+async fn wait_for_arrival(arrival: &str) {
+    let wait_seconds = match chrono::DateTime::parse_from_rfc3339(arrival) {
+        Ok(arrival_time) => {
+            let remaining = arrival_time.with_timezone(&chrono::Utc) - chrono::Utc::now();
+            remaining.num_seconds().max(0) as u64 + 3
+        }
+        Err(_) => 30,
+    };
+    o_debug!("⏳ Waiting {}s for arrival", wait_seconds);
+    tokio::time::sleep(tokio::time::Duration::from_secs(wait_seconds)).await;
+}
+
 #[derive(Debug, Clone)]
 pub struct RefiningGoal {
     pub id: String,
@@ -210,6 +165,7 @@ pub struct RefiningGoal {
     pub target_quantity: i32,
     pub priority: GoalPriority,
     pub status: GoalStatus,
+    pub params: GoalParameters,
 }
 
 #[async_trait]
@@ -221,11 +177,17 @@ impl Goal for RefiningGoal {
     fn priority(&self) -> GoalPriority { self.priority }
     fn status(&self) -> GoalStatus { self.status.clone() }
     fn estimated_duration(&self) -> f64 { (self.target_quantity as f64) * 45.0 } // 45 sec per unit
-    fn required_resources(&self) -> Vec<String> { vec!["hauler_ship".to_string(), self.input_resource.clone()] }
+    fn required_resources(&self) -> Vec<String> { vec!["refinery_ship".to_string(), self.input_resource.clone()] }
+    fn action(&self) -> Option<GoalAction> { Some(GoalAction::Refine) }
+    fn parameters(&self) -> GoalParameters { self.params.clone() }
+    fn parameters_mut(&mut self) -> Option<&mut GoalParameters> { Some(&mut self.params) }
 
     async fn validate(&self, context: &GoalContext) -> Result<bool, String> {
-        if context.fleet_status.hauler_ships.is_empty() {
-            return Err("No hauler ships available for refining transport".to_string());
+        if recipe_for(&self.output_resource).is_none() {
+            return Err(format!("{} cannot be produced by refining", self.output_resource));
+        }
+        if self.select_refinery(context).is_none() {
+            return Err("No ship with a refinery module available".to_string());
         }
         Ok(true)
     }
@@ -233,76 +195,131 @@ impl Goal for RefiningGoal {
     async fn execute(&mut self, client: &PriorityApiClient, context: &GoalContext) -> Result<GoalResult, Box<dyn std::error::Error>> {
         o_info!("🏭 Executing refining goal: {}", self.description());
         self.status = GoalStatus::Active;
+        let start_time = std::time::Instant::now();
 
-        // Step 1: Find refinery facilities in current system
-        let system_symbol = &context.ships[0].nav.system_symbol; // Use first ship's system
-        let waypoints = client.get_system_waypoints(system_symbol, None).await?;
-        
-        let refineries: Vec<_> = waypoints.iter()
-            .filter(|w| w.traits.iter().any(|t| 
-                t.description.to_lowercase().contains("refin") || 
-                t.description.to_lowercase().contains("smelt") ||
-                t.description.to_lowercase().contains("process")))
-            .collect();
-            
-        if refineries.is_empty() {
-            return Err(format!("No refineries found in system {}", system_symbol).into());
-        }
-        
-        let refinery = &refineries[0];
-        o_info!("🏭 Found refinery at: {}", refinery.symbol);
-        
-        // Step 2: Find ships with raw materials that can be refined
-        let haulers_with_materials: Vec<_> = context.ships.iter()
-            .filter(|ship| {
-                context.fleet_status.hauler_ships.contains(&ship.symbol) &&
-                ship.cargo.inventory.iter().any(|item| 
-                    item.symbol.ends_with("_ORE") || 
-                    item.symbol.contains("RAW_"))
-            })
-            .collect();
-            
-        if haulers_with_materials.is_empty() {
-            return Err("No hauler ships with raw materials for refining".into());
-        }
-        
-        let ship = haulers_with_materials[0];
-        o_info!("🚢 Using ship {} for refining transport", ship.symbol);
-        
-        // Step 3: Navigate to refinery if needed
-        if ship.nav.waypoint_symbol != refinery.symbol {
-            o_info!("🚀 Navigating to refinery: {}", refinery.symbol);
-            client.navigate_ship_with_priority(&ship.symbol, &refinery.symbol, crate::client::ApiPriority::ActiveGoal).await?;
-            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await; // Brief wait for arrival
-        }
-        
-        // Step 4: Dock at refinery
-        if ship.nav.status != "DOCKED" {
-            client.dock_ship_with_priority(&ship.symbol, crate::client::ApiPriority::ActiveGoal).await?;
+        let refinery_symbol = self.select_refinery(context)
+            .ok_or("No ship with a refinery module available")?;
+        let mut planner = RefineryPlanner::new();
+        let recipe = planner.recipe(&self.output_resource).cloned()
+            .ok_or_else(|| format!("No refine recipe for {}", self.output_resource))?;
+        o_info!("🏭 {} refining {} → {} ({} in, {} out per refine)",
+               refinery_symbol, recipe.input, recipe.produce, recipe.input_units, recipe.output_units);
+
+        let pool = get_survey_pool();
+        match planner.compare(&recipe.produce, pool.good_value(&recipe.input), pool.good_value(&recipe.produce)) {
+            RefineDecision::Refine { gain_per_refine } => o_info!("⚖️ Refining gains {:.0} credits per refine over selling raw {}", gain_per_refine, recipe.input),
+            RefineDecision::SellRaw { loss_per_refine } => o_info!("⚠️ Selling raw {} would earn {:.0} more per refine - refining as requested", recipe.input, loss_per_refine),
+            RefineDecision::Unknown => o_debug!("⚖️ No prices known yet for {} or {}", recipe.input, recipe.produce),
         }
-        
-        o_info!("🏭 Ship docked at refinery. Raw materials available:");
-        for item in &ship.cargo.inventory {
-            if item.symbol.ends_with("_ORE") || item.symbol.contains("RAW_") {
-                o_info!("  - {} x{}", item.symbol, item.units);
+
+        // Output refined before a restart still counts toward the target
+        let mut produced = context.progress.get(&self.id).units_completed;
+        let mut input_consumed = 0;
+        while produced < self.target_quantity {
+            if self.params.is_past_deadline() {
+                o_info!("⏰ Refining deadline reached with {}/{} {}", produced, self.target_quantity, recipe.produce);
+                break;
+            }
+
+            let mut refinery = client.get_ship(&refinery_symbol).await?;
+
+            // Keep enough input on hand that the next refine can start when the cooldown ends
+            if planner.input_shortfall(&recipe, &refinery.cargo) > 0 {
+                let fleet = client.get_ships().await?;
+                let transfers = planner.plan_top_up(&recipe.produce, &refinery, &fleet);
+                let mut topped_up = false;
+                for transfer in transfers {
+                    let result = client.transfer_cargo_with_priority(&transfer.from_ship, &transfer.good, transfer.units, &refinery_symbol, ApiPriority::ActiveGoal)
+                        .await.map_err(|e| e.to_string());
+                    match result {
+                        Ok(_) => {
+                            o_info!("🔄 {} topped up {} with {} x{}", transfer.from_ship, refinery_symbol, transfer.good, transfer.units);
+                            topped_up = true;
+                        }
+                        Err(e) => o_debug!("⚠️ Top-up from {} failed: {}", transfer.from_ship, e),
+                    }
+                }
+                if topped_up {
+                    refinery = client.get_ship(&refinery_symbol).await?;
+                }
+            }
+
+            match planner.next_step(&recipe.produce, &refinery.cargo) {
+                Some(RefineryStep::Refine) => {
+                    let result = client.refine_cargo_with_priority(&refinery_symbol, &recipe.produce, ApiPriority::ActiveGoal)
+                        .await.map_err(|e| e.to_string());
+                    match result {
+                        Ok(refine_data) => {
+                            planner.observe_refine(&refine_data);
+                            let output: i32 = refine_data.produced.iter()
+                                .filter(|good| good.trade_symbol == recipe.produce)
+                                .map(|good| good.units)
+                                .sum();
+                            input_consumed += refine_data.consumed.iter().map(|good| good.units).sum::<i32>();
+                            produced += output;
+                            context.progress.update(&self.id, |progress| progress.units_completed = produced);
+                            o_info!("✨ {} refined {} {} (total: {}/{})", refinery_symbol, output, recipe.produce, produced, self.target_quantity);
+                        }
+                        Err(e) if e.contains("cooldown") => {
+                            o_debug!("⏳ {} refinery still cooling down", refinery_symbol);
+                            planner.record_cooldown(&refinery.cooldown);
+                            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+                        }
+                        Err(e) => return Err(format!("Refining {} failed: {}", recipe.produce, e).into()),
+                    }
+                }
+                Some(RefineryStep::WaitForCooldown { seconds }) => {
+                    o_debug!("⏳ Next refine on {} in {:.0}s", refinery_symbol, seconds);
+                    tokio::time::sleep(tokio::time::Duration::from_secs_f64(seconds + 0.5)).await;
+                }
+                Some(RefineryStep::NeedInput { units }) => {
+                    o_info!("📦 {} needs {} more {} and no ship at {} can supply it",
+                           refinery_symbol, units, recipe.input, refinery.nav.waypoint_symbol);
+                    break;
+                }
+                None => break,
             }
         }
-        
-        // Note: Actual refining would require specific SpaceTraders API endpoints
-        // For now, we've positioned the ship at a refinery ready for refining operations
-        
-        self.status = GoalStatus::Completed;
+
+        let mut resources_consumed = HashMap::new();
+        resources_consumed.insert(recipe.input.clone(), input_consumed);
+        self.status = if produced >= self.target_quantity {
+            GoalStatus::Completed
+        } else {
+            GoalStatus::Failed(format!("Only refined {}/{} {}", produced, self.target_quantity, recipe.produce))
+        };
         Ok(GoalResult {
-            success: true,
-            message: format!("Positioned ship at {} refinery for {} -> {} processing", refinery.symbol, self.input_resource, self.output_resource),
-            ships_used: vec![ship.symbol.clone()],
-            resources_consumed: HashMap::new(),
+            success: produced >= self.target_quantity,
+            message: format!("Refined {}/{} {} on {}", produced, self.target_quantity, recipe.produce, refinery_symbol),
+            ships_used: vec![refinery_symbol],
+            resources_consumed,
             credits_spent: 0,
-            execution_time: 180.0,
+            execution_time: start_time.elapsed().as_secs_f64(),
         })
     }
 }
 
+impl RefiningGoal {
+    /// The refinery ship to use: a named ship if the goal picks one, otherwise the
+    /// refinery-equipped ship already holding the most input
+    fn select_refinery(&self, context: &GoalContext) -> Option<String> {
+        let stock = |ship: &crate::models::Ship| -> i32 {
+            ship.cargo.inventory.iter()
+                .filter(|item| item.symbol == self.input_resource)
+                .map(|item| item.units)
+                .sum()
+        };
+        context.ships.iter()
+            .filter(|ship| has_refinery(ship))
+            .filter(|ship| match &self.params.ships {
+                ShipSelector::Symbols(symbols) => symbols.contains(&ship.symbol),
+                _ => true,
+            })
+            .max_by_key(|ship| stock(ship))
+            .map(|ship| ship.symbol.clone())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SellingGoal {
     pub id: String,
@@ -310,6 +327,7 @@ pub struct SellingGoal {
     pub target_quantity: Option<i32>,  // None means sell all available
     pub priority: GoalPriority,
     pub status: GoalStatus,
+    pub params: GoalParameters,
 }
 
 #[async_trait]
@@ -326,6 +344,9 @@ impl Goal for SellingGoal {
     fn status(&self) -> GoalStatus { self.status.clone() }
     fn estimated_duration(&self) -> f64 { 120.0 } // 2 minutes for selling operations
     fn required_resources(&self) -> Vec<String> { vec!["ship_with_cargo".to_string()] }
+    fn action(&self) -> Option<GoalAction> { Some(GoalAction::Sell) }
+    fn parameters(&self) -> GoalParameters { self.params.clone() }
+    fn parameters_mut(&mut self) -> Option<&mut GoalParameters> { Some(&mut self.params) }
 
     async fn validate(&self, context: &GoalContext) -> Result<bool, String> {
         // Check if any ships have cargo to sell
@@ -344,24 +365,42 @@ impl Goal for SellingGoal {
         o_info!("💰 Executing selling goal: {}", self.description());
         self.status = GoalStatus::Active;
 
-        let mut total_revenue = 0;
+        // Sales made before a restart still count toward the target
+        let progress = context.progress.get(&self.id);
+        let mut units_sold = progress.units_completed;
+        let mut total_revenue = progress.credits as i32;
         let mut ships_used = Vec::new();
 
         // Find ships with cargo
         let cargo_ships: Vec<_> = context.ships.iter()
             .filter(|ship| ship.cargo.units > 0)
+            .filter(|ship| match &self.params.ships {
+                ShipSelector::Symbols(symbols) => symbols.contains(&ship.symbol),
+                _ => true,
+            })
+            .take(match self.params.ships {
+                ShipSelector::Count(count) => count,
+                _ => usize::MAX,
+            })
             .collect();
 
         for ship in cargo_ships {
             o_debug!("🚢 Selling cargo from: {} ({} items)", ship.symbol, ship.cargo.units);
             
-            // Find nearest marketplace - simplified implementation
-            let marketplace = self.find_nearest_marketplace(client, &ship.nav.system_symbol).await?;
+            let marketplace = match &self.params.market {
+                Some(MarketTarget::Waypoint(waypoint)) => waypoint.clone(),
+                Some(MarketTarget::Best) => self.find_best_marketplace(client, ship).await?,
+                None => self.find_nearest_marketplace(client, &ship.nav.system_symbol).await?,
+            };
             
             // Navigate to marketplace if needed
             if ship.nav.waypoint_symbol != marketplace {
-                client.navigate_ship_with_priority(&ship.symbol, &marketplace, ApiPriority::Override).await?;
-                tokio::time::sleep(tokio::time::Duration::from_secs(10)).await; // Wait for arrival
+                if ship.nav.status == "DOCKED" {
+                    client.orbit_ship_with_priority(&ship.symbol, ApiPriority::Override).await?;
+                }
+                let nav_data = client.navigate_ship_with_priority(&ship.symbol, &marketplace, ApiPriority::Override).await?;
+                wait_for_arrival(&nav_data.nav.route.arrival).await;
+                client.dock_ship_with_priority(&ship.symbol, ApiPriority::Override).await?;
             }
             
             // Dock for selling
@@ -373,10 +412,13 @@ impl Goal for SellingGoal {
             for cargo_item in &ship.cargo.inventory {
                 if self.should_sell_item(&cargo_item.symbol) {
                     let units_to_sell = if let Some(target_qty) = self.target_quantity {
-                        target_qty.min(cargo_item.units)
+                        (target_qty - units_sold).min(cargo_item.units)
                     } else {
                         cargo_item.units
                     };
+                    if units_to_sell <= 0 {
+                        continue;
+                    }
                     
                     o_debug!("💱 Selling {} {} from {}", units_to_sell, cargo_item.symbol, ship.symbol);
                     let sell_result = client.sell_cargo_with_priority(
@@ -387,6 +429,11 @@ impl Goal for SellingGoal {
                     ).await?;
                     
                     total_revenue += sell_result.transaction.total_price;
+                    units_sold += units_to_sell;
+                    context.progress.update(&self.id, |progress| {
+                        progress.units_completed = units_sold;
+                        progress.credits = total_revenue as i64;
+                    });
                     o_info!("✅ Sold {} {} for {} credits", 
                            units_to_sell, cargo_item.symbol, sell_result.transaction.total_price);
                 }
@@ -415,6 +462,45 @@ impl SellingGoal {
         }
     }
     
+    /// Marketplace in the ship's system paying the most for the cargo being sold
+    async fn find_best_marketplace(&self, client: &PriorityApiClient, ship: &crate::models::Ship) -> Result<String, Box<dyn std::error::Error>> {
+        let system = &ship.nav.system_symbol;
+        let waypoints = client.get_system_waypoints(system, None).await?;
+        let mut best: Option<(String, i64)> = None;
+
+        for waypoint in waypoints.iter().filter(|w| w.traits.iter().any(|t| t.symbol == "MARKETPLACE")) {
+            let market = match client.get_market_with_priority(system, &waypoint.symbol, ApiPriority::Override).await {
+                Ok(market) => market,
+                Err(e) => {
+                    o_debug!("⚠️ Could not read market {}: {}", waypoint.symbol, e);
+                    continue;
+                }
+            };
+            let Some(trade_goods) = market.trade_goods else { continue };
+
+            let value: i64 = ship.cargo.inventory.iter()
+                .filter(|item| self.should_sell_item(&item.symbol))
+                .filter_map(|item| trade_goods.iter()
+                    .find(|good| good.symbol == item.symbol)
+                    .map(|good| good.sell_price as i64 * item.units as i64))
+                .sum();
+
+            o_debug!("💱 {} would pay {} credits for {}'s cargo", waypoint.symbol, value, ship.symbol);
+            if value > 0 && best.as_ref().is_none_or(|(_, best_value)| value > *best_value) {
+                best = Some((waypoint.symbol.clone(), value));
+            }
+        }
+
+        match best {
+            Some((waypoint, value)) => {
+                o_info!("🏆 Best market for {}: {} ({} credits)", ship.symbol, waypoint, value);
+                Ok(waypoint)
+            }
+            // No price data yet (markets need a ship present) - fall back to the nearest one
+            None => self.find_nearest_marketplace(client, system).await,
+        }
+    }
+
     async fn find_nearest_marketplace(&self, client: &PriorityApiClient, system: &str) -> Result<String, Box<dyn std::error::Error>> {
         let waypoints = client.get_system_waypoints(system, None).await?;
         
@@ -435,6 +521,7 @@ pub struct ShipPurchaseGoal {
     pub ship_type: String,
     pub priority: GoalPriority,
     pub status: GoalStatus,
+    pub params: GoalParameters,
 }
 
 #[derive(Debug, Clone)]
@@ -444,6 +531,7 @@ pub struct ResourcePurchaseGoal {
     pub target_quantity: i32,
     pub priority: GoalPriority,
     pub status: GoalStatus,
+    pub params: GoalParameters,
 }
 
 #[derive(Debug, Clone)]
@@ -452,6 +540,7 @@ pub struct ExplorationGoal {
     pub target_type: String, // "SHIPYARDS", "MARKETS", system symbol, etc.
     pub priority: GoalPriority,
     pub status: GoalStatus,
+    pub params: GoalParameters,
 }
 
 #[derive(Debug, Clone)]
@@ -462,6 +551,7 @@ pub struct TransferGoal {
     pub quantity: Option<i32>, // None means transfer all available
     pub priority: GoalPriority,
     pub status: GoalStatus,
+    pub params: GoalParameters,
 }
 
 #[derive(Debug, Clone)]
@@ -470,6 +560,7 @@ pub struct DebugGoal {
     pub target: String, // What to debug
     pub priority: GoalPriority,
     pub status: GoalStatus,
+    pub params: GoalParameters,
 }
 
 #[derive(Debug, Clone)]
@@ -479,6 +570,7 @@ pub struct ShipRoleGoal {
     pub desired_role: String, // "refiner", "hauler", "miner", etc.
     pub priority: GoalPriority,
     pub status: GoalStatus,
+    pub params: GoalParameters,
 }
 
 #[async_trait]
@@ -494,6 +586,9 @@ impl Goal for TransferGoal {
     fn status(&self) -> GoalStatus { self.status.clone() }
     fn estimated_duration(&self) -> f64 { 30.0 }
     fn required_resources(&self) -> Vec<String> { vec!["ship_with_cargo".to_string(), "target_ship".to_string()] }
+    fn action(&self) -> Option<GoalAction> { Some(GoalAction::Transfer) }
+    fn parameters(&self) -> GoalParameters { self.params.clone() }
+    fn parameters_mut(&mut self) -> Option<&mut GoalParameters> { Some(&mut self.params) }
     
     async fn validate(&self, context: &GoalContext) -> Result<bool, String> {
         // Check if target ship exists
@@ -519,7 +614,8 @@ impl Goal for TransferGoal {
         o_info!("🔄 Executing transfer goal: {}", self.description());
         self.status = GoalStatus::Active;
         
-        let mut total_transferred = 0;
+        // Transfers made before a restart still count toward the target
+        let mut total_transferred = context.progress.get(&self.id).units_completed;
         let mut ships_used = Vec::new();
         let start_time = std::time::Instant::now();
         
@@ -593,6 +689,7 @@ impl Goal for TransferGoal {
             ).await {
                 Ok(_) => {
                     total_transferred += transfer_amount;
+                    context.progress.update(&self.id, |progress| progress.units_completed = total_transferred);
                     ships_used.push(source_ship.symbol.clone());
                     o_info!("✅ Transferred {} {} (total: {})", 
                            transfer_amount, self.resource_type, total_transferred);
@@ -643,6 +740,9 @@ impl Goal for ShipRoleGoal {
     fn status(&self) -> GoalStatus { self.status.clone() }
     fn estimated_duration(&self) -> f64 { 120.0 } // 2 minutes for analysis and designation
     fn required_resources(&self) -> Vec<String> { vec!["ship_role_manager".to_string()] }
+    fn action(&self) -> Option<GoalAction> { Some(GoalAction::Designate) }
+    fn parameters(&self) -> GoalParameters { self.params.clone() }
+    fn parameters_mut(&mut self) -> Option<&mut GoalParameters> { Some(&mut self.params) }
     
     async fn validate(&self, context: &GoalContext) -> Result<bool, String> {
         // If specific ship is requested, check if it exists
@@ -732,52 +832,522 @@ impl Goal for ShipRoleGoal {
     }
 }
 
-// Implement Goal trait for remaining types (simplified for now)
-#[async_trait] impl Goal for ShipPurchaseGoal {
+#[async_trait]
+impl Goal for ShipPurchaseGoal {
     fn id(&self) -> String { self.id.clone() }
     fn description(&self) -> String { format!("Purchase {} ship", self.ship_type) }
     fn priority(&self) -> GoalPriority { self.priority }
     fn status(&self) -> GoalStatus { self.status.clone() }
     fn estimated_duration(&self) -> f64 { 300.0 }
     fn required_resources(&self) -> Vec<String> { vec!["credits".to_string()] }
-    async fn validate(&self, _context: &GoalContext) -> Result<bool, String> { Ok(true) }
-    async fn execute(&mut self, _client: &PriorityApiClient, _context: &GoalContext) -> Result<GoalResult, Box<dyn std::error::Error>> {
-        o_info!("🚢 Ship purchase goal: {} (placeholder)", self.ship_type);
+    fn action(&self) -> Option<GoalAction> { Some(GoalAction::Buy) }
+    fn parameters(&self) -> GoalParameters { self.params.clone() }
+    fn parameters_mut(&mut self) -> Option<&mut GoalParameters> { Some(&mut self.params) }
+
+    async fn validate(&self, context: &GoalContext) -> Result<bool, String> {
+        if context.agent.credits <= context.credit_reserve {
+            return Err(format!("Credits ({}) are at or below the {} credit reserve", context.agent.credits, context.credit_reserve));
+        }
+        if context.ships.iter().all(|ship| ship.nav.status == "IN_TRANSIT") {
+            return Err("No ship available to visit a shipyard".to_string());
+        }
+        Ok(true)
+    }
+
+    async fn execute(&mut self, client: &PriorityApiClient, context: &GoalContext) -> Result<GoalResult, Box<dyn std::error::Error>> {
+        o_info!("🚢 Executing ship purchase goal: {}", self.description());
+        self.status = GoalStatus::Active;
+        let start_time = std::time::Instant::now();
+
+        let ship_type = shipyard_ship_type(&self.ship_type);
+        let system = goal_system(&self.params, context);
+
+        // Find shipyards that sell this ship type, with prices where we have a ship present
+        let shipyard_waypoints = client.get_system_waypoints_with_traits(&system, "SHIPYARD").await?;
+        let mut offers: Vec<(String, Option<i32>)> = Vec::new();
+        for waypoint in &shipyard_waypoints {
+            let shipyard = match client.get_shipyard(&system, &waypoint.symbol).await {
+                Ok(shipyard) => shipyard,
+                Err(e) => {
+                    o_debug!("⚠️ Could not read shipyard {}: {}", waypoint.symbol, e);
+                    continue;
+                }
+            };
+            if !shipyard.ship_types.iter().any(|t| t.ship_type == ship_type) {
+                continue;
+            }
+            let price = shipyard.ships.as_ref()
+                .and_then(|ships| ships.iter().find(|s| s.ship_type == ship_type))
+                .map(|s| s.purchase_price);
+            o_debug!("🏭 {} sells {} ({})", waypoint.symbol, ship_type,
+                    price.map(|p| format!("{} credits", p)).unwrap_or_else(|| "price unknown".to_string()));
+            offers.push((waypoint.symbol.clone(), price));
+        }
+
+        if offers.is_empty() {
+            return Err(self.fail(format!("No shipyard in {} sells {}", system, ship_type)));
+        }
+
+        // Cheapest known price first, unpriced shipyards after
+        offers.sort_by_key(|(_, price)| price.unwrap_or(i32::MAX));
+        let shipyard_symbol = offers[0].0.clone();
+
+        // A ship must be present at the shipyard to buy from it
+        let courier = match context.ships.iter().find(|s| s.nav.waypoint_symbol == shipyard_symbol && s.nav.status != "IN_TRANSIT") {
+            Some(ship) => ship,
+            None => {
+                let ship = select_courier(context, &self.params, &system)
+                    .ok_or_else(|| self.fail(format!("No available ship in {} to send to shipyard {}", system, shipyard_symbol)))?;
+                o_info!("🗺️ Sending {} to shipyard {}", ship.symbol, shipyard_symbol);
+                move_ship_to(client, context, ship, &shipyard_symbol).await?;
+                ship
+            }
+        };
+
+        let shipyard = client.get_shipyard(&system, &shipyard_symbol).await?;
+        let price = shipyard.ships.as_ref()
+            .and_then(|ships| ships.iter().find(|s| s.ship_type == ship_type))
+            .map(|s| s.purchase_price)
+            .ok_or_else(|| self.fail(format!("{} is not currently offering {}", shipyard_symbol, ship_type)))?;
+
+        let agent = client.get_agent().await?;
+        let credits_after = agent.credits - price as i64;
+        if credits_after < context.credit_reserve {
+            return Err(self.fail(format!("{} costs {} credits; buying it would leave {} (reserve is {})",
+                                         ship_type, price, credits_after, context.credit_reserve)));
+        }
+
+        o_info!("💰 Purchasing {} at {} for {} credits", ship_type, shipyard_symbol, price);
+        let purchase = client.purchase_ship(&ship_type, &shipyard_symbol).await?;
+        o_info!("✅ Purchased {} ({} credits remaining)", purchase.ship.symbol, purchase.agent.credits);
+
         self.status = GoalStatus::Completed;
-        Ok(GoalResult { success: true, message: "Ship purchase planned".to_string(), ships_used: vec![], resources_consumed: HashMap::new(), credits_spent: 0, execution_time: 1.0 })
+        Ok(GoalResult {
+            success: true,
+            message: format!("Purchased {} {} at {} for {} credits", ship_type, purchase.ship.symbol, shipyard_symbol, purchase.transaction.price),
+            ships_used: vec![courier.symbol.clone()],
+            resources_consumed: HashMap::new(),
+            credits_spent: purchase.transaction.price,
+            execution_time: start_time.elapsed().as_secs_f64(),
+        })
     }
 }
 
-#[async_trait] impl Goal for ResourcePurchaseGoal {
+impl ShipPurchaseGoal {
+    fn fail(&mut self, reason: String) -> Box<dyn std::error::Error> {
+        o_info!("❌ Ship purchase failed: {}", reason);
+        self.status = GoalStatus::Failed(reason.clone());
+        reason.into()
+    }
+}
+
+#[async_trait]
+impl Goal for ResourcePurchaseGoal {
     fn id(&self) -> String { self.id.clone() }
     fn description(&self) -> String { format!("Purchase {} units of {}", self.target_quantity, self.resource_type) }
     fn priority(&self) -> GoalPriority { self.priority }
     fn status(&self) -> GoalStatus { self.status.clone() }
     fn estimated_duration(&self) -> f64 { 180.0 }
     fn required_resources(&self) -> Vec<String> { vec!["credits".to_string(), "cargo_space".to_string()] }
-    async fn validate(&self, _context: &GoalContext) -> Result<bool, String> { Ok(true) }
-    async fn execute(&mut self, _client: &PriorityApiClient, _context: &GoalContext) -> Result<GoalResult, Box<dyn std::error::Error>> {
-        o_info!("🛒 Resource purchase goal: {} {} (placeholder)", self.target_quantity, self.resource_type);
-        self.status = GoalStatus::Completed;
-        Ok(GoalResult { success: true, message: "Resource purchase planned".to_string(), ships_used: vec![], resources_consumed: HashMap::new(), credits_spent: 0, execution_time: 1.0 })
+    fn action(&self) -> Option<GoalAction> { Some(GoalAction::Buy) }
+    fn parameters(&self) -> GoalParameters { self.params.clone() }
+    fn parameters_mut(&mut self) -> Option<&mut GoalParameters> { Some(&mut self.params) }
+
+    async fn validate(&self, context: &GoalContext) -> Result<bool, String> {
+        if context.agent.credits <= context.credit_reserve {
+            return Err(format!("Credits ({}) are at or below the {} credit reserve", context.agent.credits, context.credit_reserve));
+        }
+        if select_cargo_ships(context, &self.params.ships).is_empty() {
+            return Err("No available ships with free cargo space".to_string());
+        }
+        Ok(true)
+    }
+
+    async fn execute(&mut self, client: &PriorityApiClient, context: &GoalContext) -> Result<GoalResult, Box<dyn std::error::Error>> {
+        o_info!("🛒 Executing resource purchase goal: {}", self.description());
+        self.status = GoalStatus::Active;
+        let start_time = std::time::Instant::now();
+
+        let mut budget = context.agent.credits - context.credit_reserve;
+        if budget <= 0 {
+            return Err(self.fail(format!("No budget: credits are at the {} credit reserve", context.credit_reserve)));
+        }
+
+        let buyers = select_cargo_ships(context, &self.params.ships);
+        if buyers.is_empty() {
+            return Err(self.fail("No available ships with free cargo space".to_string()));
+        }
+
+        // Purchases made before a restart still count toward the target
+        let progress = context.progress.get(&self.id);
+        let mut remaining = self.target_quantity - progress.units_completed;
+        let mut credits_spent = -progress.credits as i32;
+        let mut ships_used = Vec::new();
+
+        for buyer in buyers {
+            if remaining <= 0 || budget <= 0 {
+                break;
+            }
+
+            let system = buyer.nav.system_symbol.clone();
+            let market_symbol = match &self.params.market {
+                Some(MarketTarget::Waypoint(waypoint)) => waypoint.clone(),
+                _ => match self.find_cheapest_market(client, &system).await? {
+                    Some(waypoint) => waypoint,
+                    None => return Err(self.fail(format!("No market in {} sells {}", system, self.resource_type))),
+                },
+            };
+
+            if buyer.nav.waypoint_symbol != market_symbol {
+                o_info!("🗺️ Sending {} to market {}", buyer.symbol, market_symbol);
+                move_ship_to(client, context, buyer, &market_symbol).await?;
+            }
+            client.dock_ship_with_priority(&buyer.symbol, ApiPriority::ActiveGoal).await?;
+
+            let market = client.get_market_with_priority(&system, &market_symbol, ApiPriority::ActiveGoal).await?;
+            let Some(good) = market.trade_goods.as_ref()
+                .and_then(|goods| goods.iter().find(|g| g.symbol == self.resource_type))
+                .cloned() else {
+                o_info!("⚠️ {} does not sell {}", market_symbol, self.resource_type);
+                continue;
+            };
+
+            let mut free_space = buyer.cargo.capacity - buyer.cargo.units;
+            while remaining > 0 && free_space > 0 {
+                let affordable = (budget / good.purchase_price.max(1) as i64).min(i32::MAX as i64) as i32;
+                let units = remaining.min(free_space).min(good.trade_volume).min(affordable);
+                if units <= 0 {
+                    o_info!("💸 Budget exhausted ({} credits left above reserve)", budget);
+                    break;
+                }
+
+                let purchase = client.purchase_cargo_with_priority(&buyer.symbol, &self.resource_type, units, ApiPriority::ActiveGoal).await?;
+                let cost = purchase.transaction.total_price;
+                o_info!("✅ {} bought {} {} for {} credits", buyer.symbol, units, self.resource_type, cost);
+
+                remaining -= units;
+                free_space -= units;
+                budget -= cost as i64;
+                credits_spent += cost;
+                context.progress.update(&self.id, |progress| {
+                    progress.units_completed = self.target_quantity - remaining;
+                    progress.credits = -credits_spent as i64;
+                });
+            }
+
+            if !ships_used.contains(&buyer.symbol) {
+                ships_used.push(buyer.symbol.clone());
+            }
+        }
+
+        let purchased = self.target_quantity - remaining;
+        self.status = if remaining <= 0 {
+            GoalStatus::Completed
+        } else {
+            GoalStatus::Failed(format!("Only purchased {}/{} {}", purchased, self.target_quantity, self.resource_type))
+        };
+
+        Ok(GoalResult {
+            success: remaining <= 0,
+            message: format!("Purchased {}/{} {} for {} credits", purchased, self.target_quantity, self.resource_type, credits_spent),
+            ships_used,
+            resources_consumed: HashMap::new(),
+            credits_spent,
+            execution_time: start_time.elapsed().as_secs_f64(),
+        })
     }
 }
 
-#[async_trait] impl Goal for ExplorationGoal {
+impl ResourcePurchaseGoal {
+    fn fail(&mut self, reason: String) -> Box<dyn std::error::Error> {
+        o_info!("❌ Resource purchase failed: {}", reason);
+        self.status = GoalStatus::Failed(reason.clone());
+        reason.into()
+    }
+
+    /// Cheapest market by live price, falling back to any market listing the resource
+    async fn find_cheapest_market(&self, client: &PriorityApiClient, system: &str) -> Result<Option<String>, Box<dyn std::error::Error>> {
+        let waypoints = client.get_system_waypoints_with_traits(system, "MARKETPLACE").await?;
+        let mut cheapest: Option<(String, i32)> = None;
+        let mut listed: Option<String> = None;
+
+        for waypoint in &waypoints {
+            let market = match client.get_market_with_priority(system, &waypoint.symbol, ApiPriority::ActiveGoal).await {
+                Ok(market) => market,
+                Err(e) => {
+                    o_debug!("⚠️ Could not read market {}: {}", waypoint.symbol, e);
+                    continue;
+                }
+            };
+
+            if let Some(good) = market.trade_goods.as_ref().and_then(|goods| goods.iter().find(|g| g.symbol == self.resource_type)) {
+                if cheapest.as_ref().is_none_or(|(_, price)| good.purchase_price < *price) {
+                    cheapest = Some((waypoint.symbol.clone(), good.purchase_price));
+                }
+            } else if listed.is_none()
+                && market.exports.iter().chain(market.exchange.iter()).any(|g| g.symbol == self.resource_type) {
+                listed = Some(waypoint.symbol.clone());
+            }
+        }
+
+        if let Some((waypoint, price)) = &cheapest {
+            o_info!("🏆 Cheapest {} in {}: {} at {} credits/unit", self.resource_type, system, waypoint, price);
+        }
+        Ok(cheapest.map(|(waypoint, _)| waypoint).or(listed))
+    }
+}
+
+#[async_trait]
+impl Goal for ExplorationGoal {
     fn id(&self) -> String { self.id.clone() }
-    fn description(&self) -> String { format!("Explore {}", self.target_type) }
+    fn description(&self) -> String {
+        match &self.params.location {
+            Some(location) if location.system_symbol() != self.target_type => format!("Explore {} in {}", self.target_type, location.system_symbol()),
+            _ => format!("Explore {}", self.target_type),
+        }
+    }
     fn priority(&self) -> GoalPriority { self.priority }
     fn status(&self) -> GoalStatus { self.status.clone() }
     fn estimated_duration(&self) -> f64 { 600.0 }
     fn required_resources(&self) -> Vec<String> { vec!["probe_ship".to_string()] }
-    async fn validate(&self, _context: &GoalContext) -> Result<bool, String> { Ok(true) }
-    async fn execute(&mut self, _client: &PriorityApiClient, _context: &GoalContext) -> Result<GoalResult, Box<dyn std::error::Error>> {
-        o_info!("🔍 Exploration goal: {} (placeholder)", self.target_type);
-        self.status = GoalStatus::Completed;
-        Ok(GoalResult { success: true, message: "Exploration planned".to_string(), ships_used: vec![], resources_consumed: HashMap::new(), credits_spent: 0, execution_time: 1.0 })
+    fn action(&self) -> Option<GoalAction> { Some(GoalAction::Explore) }
+    fn parameters(&self) -> GoalParameters { self.params.clone() }
+    fn parameters_mut(&mut self) -> Option<&mut GoalParameters> { Some(&mut self.params) }
+
+    async fn validate(&self, context: &GoalContext) -> Result<bool, String> {
+        if self.select_probes(context).is_empty() {
+            return Err("No probe ships available for exploration".to_string());
+        }
+        Ok(true)
+    }
+
+    async fn execute(&mut self, client: &PriorityApiClient, context: &GoalContext) -> Result<GoalResult, Box<dyn std::error::Error>> {
+        o_info!("🔍 Executing exploration goal: {}", self.description());
+        self.status = GoalStatus::Active;
+        let start_time = std::time::Instant::now();
+
+        let probes = self.select_probes(context);
+        let system = match &self.params.location {
+            Some(location) => location.system_symbol(),
+            None => match probes.first() {
+                Some(probe) => probe.nav.system_symbol.clone(),
+                None => return Err(self.fail("No probe ships available for exploration".to_string())),
+            },
+        };
+
+        // Probes can only be routed within their current system
+        let probes: Vec<_> = probes.into_iter().filter(|p| p.nav.system_symbol == system).collect();
+        if probes.is_empty() {
+            return Err(self.fail(format!("No probe ships in {}; cross-system routing is not supported", system)));
+        }
+
+        let waypoints = client.get_system_waypoints(&system, None).await?;
+        let wanted_trait = match self.target_type.as_str() {
+            "SHIPYARDS" => "SHIPYARD",
+            "MARKETS" => "MARKETPLACE",
+            _ => "UNCHARTED",
+        };
+        let mut targets: Vec<_> = waypoints.iter()
+            .filter(|w| w.traits.iter().any(|t| t.symbol == wanted_trait))
+            .collect();
+
+        if targets.is_empty() {
+            self.status = GoalStatus::Completed;
+            return Ok(GoalResult {
+                success: true,
+                message: format!("Nothing to explore in {}: no {} waypoints", system, wanted_trait.to_lowercase()),
+                ships_used: vec![],
+                resources_consumed: HashMap::new(),
+                credits_spent: 0,
+                execution_time: start_time.elapsed().as_secs_f64(),
+            });
+        }
+
+        // Deal targets out to probes, nearest to the first probe first
+        let origin = (probes[0].nav.route.destination.x, probes[0].nav.route.destination.y);
+        targets.sort_by_key(|w| (w.x - origin.0).pow(2) + (w.y - origin.1).pow(2));
+        let mut queues: Vec<(&crate::models::Ship, std::collections::VecDeque<&crate::models::Waypoint>)> =
+            probes.iter().map(|p| (*p, std::collections::VecDeque::new())).collect();
+        for (i, target) in targets.iter().enumerate() {
+            let slot = i % queues.len();
+            queues[slot].1.push_back(target);
+        }
+        o_info!("🛰️ {} probe(s) exploring {} {} waypoint(s) in {}", queues.len(), targets.len(), wanted_trait.to_lowercase(), system);
+
+        let mut positions: HashMap<String, String> = probes.iter()
+            .map(|p| (p.symbol.clone(), p.nav.waypoint_symbol.clone()))
+            .collect();
+        let mut charted = 0;
+        let mut visited = 0;
+        let mut ships_used = Vec::new();
+
+        // Each wave sends every probe to its next target, then waits for the slowest arrival
+        while queues.iter().any(|(_, queue)| !queue.is_empty()) {
+            let mut wave = Vec::new();
+            let mut latest_arrival: Option<String> = None;
+
+            for (probe, queue) in queues.iter_mut() {
+                let Some(target) = queue.pop_front() else { continue };
+                if positions.get(&probe.symbol) != Some(&target.symbol) {
+                    client.orbit_ship_with_priority(&probe.symbol, ApiPriority::Background).await.ok();
+                    let arrival = match client.navigate_ship_with_priority(&probe.symbol, &target.symbol, ApiPriority::Background).await {
+                        Ok(nav_data) => nav_data.nav.route.arrival,
+                        Err(e) => {
+                            o_info!("⚠️ {} could not navigate to {}: {}", probe.symbol, target.symbol, e);
+                            continue;
+                        }
+                    };
+                    if latest_arrival.as_ref().is_none_or(|latest| arrival > *latest) {
+                        latest_arrival = Some(arrival);
+                    }
+                    positions.insert(probe.symbol.clone(), target.symbol.clone());
+                }
+                wave.push((probe.symbol.clone(), target));
+            }
+
+            if let Some(arrival) = latest_arrival {
+                wait_for_arrival(&arrival).await;
+            }
+
+            for (probe_symbol, target) in wave {
+                if !ships_used.contains(&probe_symbol) {
+                    ships_used.push(probe_symbol.clone());
+                }
+                match wanted_trait {
+                    "UNCHARTED" => match client.create_chart_with_priority(&probe_symbol, ApiPriority::Background).await {
+                        Ok(chart) => {
+                            charted += 1;
+                            o_info!("🗺️ {} charted {} ({} traits)", probe_symbol, target.symbol, chart.waypoint.traits.len());
+                        }
+                        Err(e) => o_info!("⚠️ {} could not chart {}: {}", probe_symbol, target.symbol, e),
+                    },
+                    "SHIPYARD" => match client.get_shipyard(&system, &target.symbol).await {
+                        Ok(shipyard) => {
+                            visited += 1;
+                            for ship in shipyard.ships.iter().flatten() {
+                                o_info!("🏭 {} sells {} for {} credits", target.symbol, ship.ship_type, ship.purchase_price);
+                            }
+                        }
+                        Err(e) => o_info!("⚠️ Could not read shipyard {}: {}", target.symbol, e),
+                    },
+                    _ => match client.get_market_with_priority(&system, &target.symbol, ApiPriority::Background).await {
+                        Ok(market) => {
+                            visited += 1;
+                            o_info!("🏪 {} trades {} goods", target.symbol, market.trade_goods.map(|g| g.len()).unwrap_or(0));
+                        }
+                        Err(e) => o_info!("⚠️ Could not read market {}: {}", target.symbol, e),
+                    },
+                }
+            }
+        }
+
+        let success = charted + visited > 0;
+        let message = if wanted_trait == "UNCHARTED" {
+            format!("Charted {}/{} waypoints in {}", charted, targets.len(), system)
+        } else {
+            format!("Visited {}/{} {} waypoints in {}", visited, targets.len(), wanted_trait.to_lowercase(), system)
+        };
+        self.status = if success { GoalStatus::Completed } else { GoalStatus::Failed(message.clone()) };
+
+        Ok(GoalResult {
+            success,
+            message,
+            ships_used,
+            resources_consumed: HashMap::new(),
+            credits_spent: 0,
+            execution_time: start_time.elapsed().as_secs_f64(),
+        })
+    }
+}
+
+impl ExplorationGoal {
+    fn fail(&mut self, reason: String) -> Box<dyn std::error::Error> {
+        o_info!("❌ Exploration failed: {}", reason);
+        self.status = GoalStatus::Failed(reason.clone());
+        reason.into()
+    }
+
+    fn select_probes<'a>(&self, context: &'a GoalContext) -> Vec<&'a crate::models::Ship> {
+        context.ships.iter()
+            .filter(|ship| context.fleet_status.probe_ships.contains(&ship.symbol))
+            .filter(|ship| ship.nav.status != "IN_TRANSIT")
+            .filter(|ship| match &self.params.ships {
+                ShipSelector::Symbols(symbols) => symbols.contains(&ship.symbol),
+                _ => true,
+            })
+            .take(match self.params.ships {
+                ShipSelector::Count(count) => count,
+                _ => usize::MAX,
+            })
+            .collect()
     }
 }
 
+/// Map a goal ship type ("MINING", "HAULER", ...) to a shipyard ship type symbol
+pub fn shipyard_ship_type(ship_type: &str) -> String {
+    match ship_type {
+        "MINING" => "SHIP_MINING_DRONE".to_string(),
+        "HAULER" => "SHIP_LIGHT_HAULER".to_string(),
+        "PROBE" => "SHIP_PROBE".to_string(),
+        "SIPHON" => "SHIP_SIPHON_DRONE".to_string(),
+        "REFINERY" => "SHIP_REFINING_FREIGHTER".to_string(),
+        other if other.starts_with("SHIP_") => other.to_string(),
+        other => format!("SHIP_{}", other),
+    }
+}
+
+/// System a goal targets: its location, else where our ships are, else headquarters
+fn goal_system(params: &GoalParameters, context: &GoalContext) -> String {
+    if let Some(location) = &params.location {
+        return location.system_symbol();
+    }
+    context.ships.first()
+        .map(|ship| ship.nav.system_symbol.clone())
+        .unwrap_or_else(|| context.agent.headquarters.split('-').take(2).collect::<Vec<_>>().join("-"))
+}
+
+/// Available ships with free cargo space, haulers first, honoring the ship selector
+pub fn select_cargo_ships<'a>(context: &'a GoalContext, selector: &ShipSelector) -> Vec<&'a crate::models::Ship> {
+    let mut ships: Vec<_> = context.ships.iter()
+        .filter(|ship| ship.nav.status != "IN_TRANSIT" && ship.cargo.capacity > ship.cargo.units)
+        .filter(|ship| match selector {
+            ShipSelector::Symbols(symbols) => symbols.contains(&ship.symbol),
+            _ => !context.fleet_status.busy_ships.contains_key(&ship.symbol),
+        })
+        .collect();
+    ships.sort_by_key(|ship| (!context.fleet_status.hauler_ships.contains(&ship.symbol), -(ship.cargo.capacity - ship.cargo.units)));
+
+    match selector {
+        ShipSelector::Any => ships.truncate(1),
+        ShipSelector::Count(count) => ships.truncate(*count),
+        ShipSelector::Symbols(_) => {}
+    }
+    ships
+}
+
+/// Pick a ship to send on an errand: probes first, never a busy miner
+fn select_courier<'a>(context: &'a GoalContext, params: &GoalParameters, system: &str) -> Option<&'a crate::models::Ship> {
+    context.ships.iter()
+        .filter(|ship| ship.nav.system_symbol == system && ship.nav.status != "IN_TRANSIT")
+        .filter(|ship| match &params.ships {
+            ShipSelector::Symbols(symbols) => symbols.contains(&ship.symbol),
+            _ => !context.fleet_status.busy_ships.contains_key(&ship.symbol),
+        })
+        .min_by_key(|ship| {
+            if context.fleet_status.probe_ships.contains(&ship.symbol) { 0 }
+            else if context.fleet_status.mining_ships.contains(&ship.symbol) { 2 }
+            else { 1 }
+        })
+}
+
+/// Fly a ship to a waypoint and dock, refuelling on the way when its tank cannot cover the trip
+async fn move_ship_to(client: &PriorityApiClient, context: &GoalContext, ship: &crate::models::Ship, destination: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let mut config = crate::config::SpaceTradersConfig::default();
+    config.fuel.fuel_safety_margin = context.fuel_safety_margin;
+    let planner = crate::operations::NavigationPlanner::new(client.client().clone(), config);
+    planner.travel_with_refuel(client, ship, destination).await
+}
+
 #[async_trait] impl Goal for DebugGoal {
     fn id(&self) -> String { self.id.clone() }
     fn description(&self) -> String { format!("Debug {}", self.target) }
@@ -785,6 +1355,9 @@ impl Goal for ShipRoleGoal {
     fn status(&self) -> GoalStatus { self.status.clone() }
     fn estimated_duration(&self) -> f64 { 60.0 }
     fn required_resources(&self) -> Vec<String> { vec![] }
+    fn action(&self) -> Option<GoalAction> { Some(GoalAction::Debug) }
+    fn parameters(&self) -> GoalParameters { self.params.clone() }
+    fn parameters_mut(&mut self) -> Option<&mut GoalParameters> { Some(&mut self.params) }
     async fn validate(&self, _context: &GoalContext) -> Result<bool, String> { Ok(true) }
     async fn execute(&mut self, client: &PriorityApiClient, context: &GoalContext) -> Result<GoalResult, Box<dyn std::error::Error>> {
         o_info!("🐛 Debug goal: {}", self.target);
@@ -827,4 +1400,19 @@ impl Goal for ShipRoleGoal {
         self.status = GoalStatus::Completed;
         Ok(GoalResult { success: true, message: "Debug analysis completed".to_string(), ships_used: vec![], resources_consumed: HashMap::new(), credits_spent: 0, execution_time: 1.0 })
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shipyard_ship_type() {
+        assert_eq!(shipyard_ship_type("MINING"), "SHIP_MINING_DRONE");
+        assert_eq!(shipyard_ship_type("HAULER"), "SHIP_LIGHT_HAULER");
+        assert_eq!(shipyard_ship_type("PROBE"), "SHIP_PROBE");
+        assert_eq!(shipyard_ship_type("SIPHON"), "SHIP_SIPHON_DRONE");
+        assert_eq!(shipyard_ship_type("REFINERY"), "SHIP_REFINING_FREIGHTER");
+        assert_eq!(shipyard_ship_type("SHIP_ORE_HOUND"), "SHIP_ORE_HOUND");
+        assert_eq!(shipyard_ship_type("INTERCEPTOR"), "SHIP_INTERCEPTOR");
+    }
+}
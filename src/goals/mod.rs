@@ -2,25 +2,28 @@
 pub mod goal_types;
 pub mod goal_manager;
 pub mod goal_interpreter;
+pub mod goal_dsl;
 pub mod goal_decomposer;
 pub mod resource_allocator;
 pub mod context_engine;
-pub mod survey_cache;
 
 pub use goal_types::*;
 pub use goal_manager::GoalManager;
-pub use goal_interpreter::GoalInterpreter;
+pub use goal_interpreter::{GoalInterpreter, InterpretedGoal};
+pub use goal_dsl::{GoalAction, GoalParameters, GoalLocation, MarketTarget, ShipSelector, ClauseLink, GoalParseError};
 pub use goal_decomposer::GoalDecomposer;
 pub use resource_allocator::ResourceAllocator;
 pub use context_engine::ContextEngine;
-pub use survey_cache::SurveyCache;
 
 use crate::client::{PriorityApiClient, ApiPriority};
 use crate::models::*;
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum GoalPriority {
     Deferred = 10,
     Exploration = 20, 
@@ -63,7 +66,46 @@ pub struct GoalContext {
     pub known_waypoints: HashMap<String, Vec<Waypoint>>,
     pub known_markets: HashMap<String, Market>,
     pub available_credits: i32,
+    pub credit_reserve: i64, // credits goals must not spend below
+    pub fuel_safety_margin: i32, // spare fuel ships keep when planning a trip
     pub fleet_status: FleetStatus,
+    pub progress: GoalProgressTracker,
+}
+
+/// How far a goal has got, persisted so a restarted goal picks up where it stopped
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GoalProgress {
+    pub units_completed: i32, // units mined, sold, bought or transferred
+    pub credits: i64, // credits earned (positive) or spent (negative)
+    pub ships: Vec<String>, // ships allocated to the goal
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Shared progress for all goals; clones see the same records
+#[derive(Debug, Clone, Default)]
+pub struct GoalProgressTracker {
+    progress: Arc<Mutex<HashMap<String, GoalProgress>>>,
+}
+
+impl GoalProgressTracker {
+    pub fn get(&self, goal_id: &str) -> GoalProgress {
+        self.progress.lock().unwrap().get(goal_id).cloned().unwrap_or_default()
+    }
+
+    pub fn set(&self, goal_id: &str, progress: GoalProgress) {
+        self.progress.lock().unwrap().insert(goal_id.to_string(), progress);
+    }
+
+    pub fn update(&self, goal_id: &str, update: impl FnOnce(&mut GoalProgress)) {
+        let mut progress = self.progress.lock().unwrap();
+        let entry = progress.entry(goal_id.to_string()).or_default();
+        update(entry);
+        entry.updated_at = Some(Utc::now());
+    }
+
+    pub fn remove(&self, goal_id: &str) {
+        self.progress.lock().unwrap().remove(goal_id);
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -93,6 +135,17 @@ pub trait Goal: Send + Sync {
     fn status(&self) -> GoalStatus;
     fn estimated_duration(&self) -> f64; // seconds
     fn required_resources(&self) -> Vec<String>; // ship types, materials, etc.
+    fn action(&self) -> Option<GoalAction> { None }
+    fn parameters(&self) -> GoalParameters { GoalParameters::default() }
+    fn parameters_mut(&mut self) -> Option<&mut GoalParameters> { None }
+    /// Goal IDs that must complete before this goal can start
+    fn dependencies(&self) -> Vec<String> { self.parameters().depends_on }
+    fn add_dependency(&mut self, goal_id: &str) {
+        if let Some(params) = self.parameters_mut()
+            && !params.depends_on.iter().any(|id| id == goal_id) {
+            params.depends_on.push(goal_id.to_string());
+        }
+    }
     
     async fn validate(&self, context: &GoalContext) -> Result<bool, String>;
     async fn execute(&mut self, client: &PriorityApiClient, context: &GoalContext) -> Result<GoalResult, Box<dyn std::error::Error>>;
@@ -1,5 +1,6 @@
 // Resource Allocator - Assigns ships and resources to goals
-use crate::goals::{GoalContext, Goal};
+use crate::control::get_control;
+use crate::goals::{GoalContext, Goal, ShipSelector};
 use crate::{o_debug, o_info};
 use std::collections::HashMap;
 
@@ -14,81 +15,85 @@ impl ResourceAllocator {
         }
     }
 
-    /// Allocate ships to a goal based on its requirements
+    /// Allocate ships to a goal based on its requirements and ship selector
     pub fn allocate_ships(&mut self, goal: &dyn Goal, context: &GoalContext) -> Result<Vec<String>, String> {
         let required_resources = goal.required_resources();
+        let params = goal.parameters();
+        let goal_id = goal.id();
         let mut allocated_ships = Vec::new();
         
         o_debug!("🎯 Allocating resources for goal: {}", goal.description());
         o_debug!("📋 Required resources: {:?}", required_resources);
         
+        // Explicitly named ships are allocated as-is
+        if let ShipSelector::Symbols(symbols) = &params.ships {
+            if let Some((ship, owner)) = symbols.iter()
+                .find_map(|ship| self.ship_assignments.get(ship).filter(|owner| **owner != goal_id).map(|owner| (ship, owner))) {
+                return Err(format!("Ship {} is already allocated to goal {}", ship, owner));
+            }
+            if let Some(ship) = symbols.iter().find(|ship| get_control().is_paused(ship)) {
+                return Err(format!("Ship {} is paused", ship));
+            }
+            for ship in symbols {
+                self.ship_assignments.insert(ship.clone(), goal_id.clone());
+            }
+            o_info!("✅ Allocated {} requested ships to goal: {}", symbols.len(), goal.description());
+            return Ok(symbols.clone());
+        }
+        
+        // How many ships of the main kind to take; None means all that are available
+        let wanted = match params.ships {
+            ShipSelector::Count(count) => Some(count),
+            _ => None,
+        };
+        let mut needs_ships = false;
+        
         for resource_type in required_resources {
-            match resource_type.as_str() {
-                "mining_ship" => {
-                    if let Some(ship) = self.find_available_mining_ship(context) {
-                        allocated_ships.push(ship.clone());
-                        self.ship_assignments.insert(ship.clone(), goal.id());
-                        o_debug!("⛏️ Allocated mining ship: {}", ship);
-                    } else {
-                        return Err("No available mining ships".to_string());
-                    }
-                }
-                
-                "hauler_ship" => {
-                    if let Some(ship) = self.find_available_hauler_ship(context) {
-                        allocated_ships.push(ship.clone());
-                        self.ship_assignments.insert(ship.clone(), goal.id());
-                        o_debug!("🚛 Allocated hauler ship: {}", ship);
-                    } else {
-                        return Err("No available hauler ships".to_string());
-                    }
-                }
-                
-                "probe_ship" => {
-                    if let Some(ship) = self.find_available_probe_ship(context) {
-                        allocated_ships.push(ship.clone());
-                        self.ship_assignments.insert(ship.clone(), goal.id());
-                        o_debug!("🛰️ Allocated probe ship: {}", ship);
-                    } else {
-                        return Err("No available probe ships".to_string());
-                    }
-                }
-                
+            let found: Vec<String> = match resource_type.as_str() {
+                "mining_ship" => self.take_available(&context.fleet_status.mining_ships, wanted.or(Some(1)), context),
+                "hauler_ship" => self.take_available(&context.fleet_status.hauler_ships, Some(1), context),
+                "probe_ship" => self.take_available(&context.fleet_status.probe_ships, wanted, context),
                 "ship_with_cargo" => {
-                    if let Some(ship) = self.find_ship_with_cargo(context) {
-                        allocated_ships.push(ship.clone());
-                        self.ship_assignments.insert(ship.clone(), goal.id());
-                        o_debug!("📦 Allocated cargo ship: {}", ship);
-                    } else {
-                        return Err("No ships with cargo available".to_string());
-                    }
+                    let with_cargo: Vec<String> = context.ships.iter()
+                        .filter(|ship| ship.cargo.units > 0)
+                        .map(|ship| ship.symbol.clone())
+                        .collect();
+                    self.take_available(&with_cargo, wanted, context)
+                }
+                "cargo_space" => {
+                    let with_space: Vec<String> = context.ships.iter()
+                        .filter(|ship| ship.cargo.units < ship.cargo.capacity)
+                        .map(|ship| ship.symbol.clone())
+                        .collect();
+                    self.take_available(&with_space, wanted.or(Some(1)), context)
                 }
-                
+                "target_ship" => params.target_ship.iter().cloned().collect(),
                 "credits" => {
                     o_debug!("💰 Credits available: {}", context.available_credits);
                     // Credits are handled at the goal level, not ship allocation
+                    continue;
                 }
-                
-                "cargo_space" => {
-                    // Find ship with available cargo space
-                    if let Some(ship) = self.find_ship_with_cargo_space(context) {
-                        if !allocated_ships.contains(&ship) {
-                            allocated_ships.push(ship.clone());
-                            self.ship_assignments.insert(ship.clone(), goal.id());
-                            o_debug!("📦 Allocated ship with cargo space: {}", ship);
-                        }
-                    } else {
-                        return Err("No ships with available cargo space".to_string());
-                    }
-                }
-                
                 _ => {
                     o_debug!("⚠️ Unknown resource type: {}", resource_type);
+                    continue;
+                }
+            };
+            
+            needs_ships = true;
+            if found.is_empty() {
+                self.release_ships(&goal_id);
+                return Err(format!("No available ships for {}", resource_type));
+            }
+            for ship in found {
+                if !allocated_ships.contains(&ship) {
+                    o_debug!("🚢 Allocated {} for {}", ship, resource_type);
+                    self.ship_assignments.insert(ship.clone(), goal_id.clone());
+                    allocated_ships.push(ship);
                 }
             }
         }
         
-        if allocated_ships.is_empty() {
+        if needs_ships && allocated_ships.is_empty() {
             return Err(format!("Could not allocate any ships for goal: {}", goal.description()));
         }
         
@@ -96,6 +101,11 @@ impl ResourceAllocator {
         Ok(allocated_ships)
     }
 
+    /// Goal a ship is currently allocated to, if any
+    pub fn assigned_goal(&self, ship_id: &str) -> Option<&String> {
+        self.ship_assignments.get(ship_id)
+    }
+
     /// Release ships from a completed or failed goal
     pub fn release_ships(&mut self, goal_id: &str) -> Vec<String> {
         let released_ships: Vec<String> = self.ship_assignments.iter()
@@ -142,41 +152,14 @@ impl ResourceAllocator {
 
     // Private helper methods
     
-    fn find_available_mining_ship(&self, context: &GoalContext) -> Option<String> {
-        context.fleet_status.mining_ships.iter()
-            .find(|ship_id| self.is_ship_available(ship_id, context))
-            .cloned()
-    }
-    
-    fn find_available_hauler_ship(&self, context: &GoalContext) -> Option<String> {
-        context.fleet_status.hauler_ships.iter()
-            .find(|ship_id| self.is_ship_available(ship_id, context))
+    fn take_available(&self, candidates: &[String], limit: Option<usize>, context: &GoalContext) -> Vec<String> {
+        candidates.iter()
+            .filter(|ship_id| self.is_ship_available(ship_id, context) && !get_control().is_paused(ship_id))
+            .take(limit.unwrap_or(usize::MAX))
             .cloned()
+            .collect()
     }
     
-    fn find_available_probe_ship(&self, context: &GoalContext) -> Option<String> {
-        context.fleet_status.probe_ships.iter()
-            .find(|ship_id| self.is_ship_available(ship_id, context))
-            .cloned()
-    }
-    
-    fn find_ship_with_cargo(&self, context: &GoalContext) -> Option<String> {
-        context.ships.iter()
-            .find(|ship| {
-                ship.cargo.units > 0 && self.is_ship_available(&ship.symbol, context)
-            })
-            .map(|ship| ship.symbol.clone())
-    }
-    
-    fn find_ship_with_cargo_space(&self, context: &GoalContext) -> Option<String> {
-        context.ships.iter()
-            .find(|ship| {
-                ship.cargo.units < ship.cargo.capacity && 
-                self.is_ship_available(&ship.symbol, context)
-            })
-            .map(|ship| ship.symbol.clone())
-    }
-
     /// Find the best ship for a specific goal type
     pub fn find_optimal_ship(&self, goal_type: &str, context: &GoalContext) -> Option<String> {
         match goal_type {
@@ -10,6 +10,11 @@ pub mod debug;
 pub mod config;
 pub mod verbosity;
 pub mod output_broker;
+pub mod events;
+pub mod structured_log;
+pub mod metrics;
+pub mod dashboard;
+pub mod control;
 pub mod goals;
 
 // Re-export commonly used types
@@ -2,9 +2,11 @@
 // Modular architecture for 100% autonomous gameplay
 
 use spacetraders_cc::{Admiral, admiral::load_agent_token, output_broker, o_error, o_info, o_debug};
-use spacetraders_cc::goals::{GoalManager, GoalInterpreter, GoalDecomposer, ResourceAllocator, ContextEngine};
-use spacetraders_cc::client::{PriorityApiClient};
-use clap::Parser;
+use spacetraders_cc::goals::GoalManager;
+use spacetraders_cc::goals::goal_manager::GOAL_STORE_PATH;
+use spacetraders_cc::control::{self, ControlCommand, ForcedRole};
+use spacetraders_cc::storage;
+use clap::{Parser, Subcommand};
 
 #[derive(Parser)]
 #[command(name = "spacetraders_cc")]
@@ -22,21 +24,114 @@ struct Args {
     #[arg(short = 'v', long = "verbose", action = clap::ArgAction::Count, help = "Increase verbosity (-v basic, -vv full debug)")]
     verbose: u8,
     
-    #[arg(short = 'g', long = "goal", help = "Execute a specific goal instead of autonomous operations (e.g., 'mine iron', 'refine copper', 'explore system')")]
+    #[arg(short = 'g', long = "goal", help = "Execute a specific goal instead of autonomous operations (e.g., 'mine 200 iron ore at X1-AB12-C3 with 3 ships by 18:00 then sell at best market')")]
     goal: Option<String>,
+    
+    #[arg(long, help = "Discard saved goals from a previous run instead of resuming them")]
+    clear_goals: bool,
+    
+    #[arg(long, help = "Show a live fleet dashboard instead of scrolling console output")]
+    tui: bool,
+    
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Send a command to a running bot through its control API
+    Ctl {
+        #[arg(long, help = "Control API address (defaults to [control] bind in config.toml)")]
+        addr: Option<String>,
+        
+        #[command(subcommand)]
+        action: CtlAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum CtlAction {
+    /// Show fleet, contract and override state
+    Status,
+    /// Queue a goal, in the same syntax as --goal
+    Goal {
+        #[arg(required = true, num_args = 1.., help = "Goal command, e.g. mine 200 iron ore then sell at best market")]
+        goal: Vec<String>,
+    },
+    /// Stop giving new tasks to a ship, or to the whole fleet when no ship is named
+    Pause { ship: Option<String> },
+    /// Let a paused ship, or the whole fleet, take tasks again
+    Resume { ship: Option<String> },
+    /// Force a ship into a role (miner, siphoner, hauler, explorer), or `auto` to clear it
+    Role { ship: String, role: String },
+    /// Stop the bot gracefully
+    Shutdown,
+}
+
+/// Client side of `ctl`: send one command and print the bot's answer
+async fn run_ctl(addr: Option<String>, action: CtlAction) -> Result<(), Box<dyn std::error::Error>> {
+    let addr = match addr {
+        Some(addr) => addr,
+        None => spacetraders_cc::config::ConfigManager::new("config.toml")
+            .map(|manager| manager.config().control.bind.clone())
+            .unwrap_or_else(|_| spacetraders_cc::config::ControlConfig::default().bind),
+    };
+    let command = match action {
+        CtlAction::Status => ControlCommand::Status,
+        CtlAction::Goal { goal } => ControlCommand::Goal { goal: goal.join(" ") },
+        CtlAction::Pause { ship } => ControlCommand::Pause { ship },
+        CtlAction::Resume { ship } => ControlCommand::Resume { ship },
+        CtlAction::Role { ship, role } if role.eq_ignore_ascii_case("auto") => ControlCommand::Role { ship, role: None },
+        CtlAction::Role { ship, role } => {
+            let role = ForcedRole::parse(&role)
+                .ok_or_else(|| format!("Unknown role '{}': use miner, siphoner, hauler, explorer or auto", role))?;
+            ControlCommand::Role { ship, role: Some(role) }
+        }
+        CtlAction::Shutdown => ControlCommand::Shutdown,
+    };
+    
+    let response = control::send_command(&addr, &command).await?;
+    match &response.status {
+        Some(status) => println!("{}", serde_json::to_string_pretty(status)?),
+        None => println!("{}", response.message),
+    }
+    if !response.ok {
+        return Err(response.message.into());
+    }
+    Ok(())
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let args = Args::parse();
+    let mut args = Args::parse();
     
+    if let Some(Command::Ctl { addr, action }) = args.command.take() {
+        return run_ctl(addr, action).await;
+    }
+    
+    let result = run_agent(args).await;
+    // Nothing is printed after this, so the final summary has to be out first
+    output_broker::get_output_broker().flush().await;
+    result
+}
+
+async fn run_agent(args: Args) -> Result<(), Box<dyn std::error::Error>> {
     // Initialize output broker
     output_broker::init_output_broker();
+    control::shutdown_on_ctrl_c();
     
     // Set verbosity level in both old and new systems during transition
     spacetraders_cc::verbosity::set_verbosity_level(args.verbose);
     output_broker::get_output_broker().set_verbosity_level(args.verbose).await;
     
+    if args.tui {
+        // The dashboard owns the screen; console messages still reach the JSON log
+        output_broker::get_output_broker().set_console_enabled(false).await;
+        let dashboard = spacetraders_cc::dashboard::get_dashboard();
+        spacetraders_cc::events::get_event_bus().register(dashboard.clone());
+        dashboard.spawn(spacetraders_cc::metrics::get_metrics(), std::time::Duration::from_secs(1));
+    }
+    
     o_info!("🚀 SpaceTraders Autonomous Agent Starting...");
     o_info!("🏗️  Using new modular architecture!");
     o_info!("🎯 PRIME DIRECTIVE: 100% autonomous gameplay");
@@ -57,6 +152,44 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     admiral.set_api_logging(args.debug_api_log);
     admiral.set_full_debug(args.full_debug);
     
+    // A server reset wipes the universe, so stored data about the old one goes too. This runs before any
+    // store is loaded, or the in-memory copies would write the old data straight back.
+    match admiral.client.get_server_status().await {
+        Ok(status) => match storage::clear_on_server_reset(storage::STORAGE_DIR, &status.reset_date) {
+            Ok(true) => o_info!("🧹 Server was reset on {} - cleared storage from the previous universe", status.reset_date),
+            Ok(false) => o_debug!("💾 Storage belongs to the current universe (reset {})", status.reset_date),
+            Err(e) => o_error!("⚠️ Could not clear storage after server reset {}: {}", status.reset_date, e),
+        },
+        Err(e) => o_error!("⚠️ Could not check the server reset date: {}", e),
+    }
+    
+    // Sales reach the income ledger through the event bus
+    spacetraders_cc::events::get_event_bus().register(storage::get_income_ledger().clone());
+    
+    // Optional Prometheus endpoint for long runs
+    let metrics_config = admiral.config().metrics.clone();
+    if metrics_config.enabled {
+        let metrics = spacetraders_cc::metrics::get_metrics();
+        spacetraders_cc::events::get_event_bus().register(metrics.clone());
+        match metrics_config.bind.parse() {
+            Ok(addr) => if let Err(e) = metrics.serve(addr).await {
+                o_error!("⚠️ Could not start metrics endpoint on {}: {}", metrics_config.bind, e);
+            },
+            Err(e) => o_error!("⚠️ Invalid metrics bind address {}: {}", metrics_config.bind, e),
+        }
+    }
+    
+    // Local control API for steering the bot while it runs
+    let control_config = admiral.config().control.clone();
+    if control_config.enabled {
+        match control_config.bind.parse() {
+            Ok(addr) => if let Err(e) = control::get_control().serve(addr).await {
+                o_error!("⚠️ Could not start control API on {}: {}", control_config.bind, e);
+            },
+            Err(e) => o_error!("⚠️ Invalid control bind address {}: {}", control_config.bind, e),
+        }
+    }
+    
     // Test authentication first
     let _agent = match admiral.client.get_agent().await {
         Ok(agent) => {
@@ -76,17 +209,39 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     o_info!("\n🎖️  Admiral ready for operations!");
     
-    // Check if a specific goal was provided
-    if let Some(goal_command) = args.goal {
+    if args.clear_goals {
+        GoalManager::with_store(GOAL_STORE_PATH).clear_stored_goals();
+    }
+    
+    // Check if a specific goal was provided, or goals were left unfinished by a previous run
+    let resume_goals = GoalManager::has_stored_goals(GOAL_STORE_PATH);
+    if args.goal.is_some() || resume_goals {
         o_info!("\n🎯 GOAL MODE: Executing specific goal instead of autonomous operations");
-        o_info!("📋 Goal command: '{}'", goal_command);
+        match &args.goal {
+            Some(goal_command) => o_info!("📋 Goal command: '{}'", goal_command),
+            None => o_info!("🔁 Resuming goals saved by a previous run (use --clear-goals to discard them)"),
+        }
         
-        match execute_goal(&mut admiral, &goal_command).await {
-            Ok(()) => {
+        let result = tokio::select! {
+            result = admiral.execute_goal(args.goal.as_deref()) => Some(result),
+            _ = control::get_control().wait_for_shutdown() => None,
+        };
+        match result {
+            None => {
+                if let Err(e) = storage::get_survey_pool().flush() {
+                    o_error!("⚠️ Failed to save survey pool: {}", e);
+                }
+                if let Err(e) = storage::get_extraction_log().flush() {
+                    o_error!("⚠️ Failed to save extraction log: {}", e);
+                }
+                o_info!("\n🛑 GOAL INTERRUPTED - unfinished goals stay saved for the next run");
+                o_info!("🎖️  Admiral reporting: Goal execution terminated by user command");
+            }
+            Some(Ok(())) => {
                 o_info!("\n🎉 GOAL COMPLETED!");
                 o_info!("🎖️  Admiral reporting: Goal execution successful");
             }
-            Err(e) => {
+            Some(Err(e)) => {
                 o_error!("\n❌ Goal execution failed: {}", e);
                 o_error!("🎖️  Admiral reporting: Goal incomplete - system error");
                 return Err(e);
@@ -113,116 +268,3 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     Ok(())
 }
-
-async fn execute_goal(admiral: &Admiral, goal_command: &str) -> Result<(), Box<dyn std::error::Error>> {
-    o_info!("🧠 Initializing goal execution system...");
-    
-    // Create priority-aware API client
-    let priority_client = PriorityApiClient::new(admiral.client.clone());
-    
-    // Initialize goal system components
-    let mut goal_manager = GoalManager::new();
-    let goal_interpreter = GoalInterpreter::new();
-    let goal_decomposer = GoalDecomposer::new();
-    let mut resource_allocator = ResourceAllocator::new();
-    let mut context_engine = ContextEngine::new();
-    
-    // Parse the natural language goal
-    o_info!("🎯 Interpreting goal: '{}'", goal_command);
-    let goal = goal_interpreter.parse_goal(goal_command).await
-        .map_err(|e| format!("Failed to interpret goal '{}': {}", goal_command, e))?;
-    
-    o_info!("✅ Goal parsed: {}", goal.description());
-    
-    // Build execution context
-    o_info!("🔄 Building execution context...");
-    let context = context_engine.build_context(&priority_client).await?;
-    
-    // Validate goal feasibility
-    o_info!("✅ Validating goal feasibility...");
-    context_engine.validate_goal_feasibility(goal_command, &context)
-        .map_err(|e| format!("Goal validation failed: {}", e))?;
-    
-    // Decompose complex goals into sub-goals if needed
-    let goals = if goal_decomposer.needs_decomposition(&*goal) {
-        o_info!("🔧 Decomposing complex goal into sub-goals...");
-        let sub_goals = goal_decomposer.decompose(goal).await;
-        o_info!("📋 Created {} sub-goals for execution", sub_goals.len());
-        sub_goals
-    } else {
-        vec![goal]
-    };
-    
-    // Add all goals to the manager with resource allocation
-    for goal in goals {
-        // Try to allocate resources for this goal
-        match resource_allocator.allocate_ships(&*goal, &context) {
-            Ok(allocated_ships) => {
-                o_info!("📦 Allocated {} ships for goal: {}", allocated_ships.len(), goal.description());
-                for ship in &allocated_ships {
-                    o_debug!("  - {}", ship);
-                }
-            }
-            Err(e) => {
-                o_info!("⚠️ Resource allocation warning for goal '{}': {}", goal.description(), e);
-                // Continue anyway - the goal execution may handle this gracefully
-            }
-        }
-        goal_manager.add_goal(goal);
-    }
-    
-    // Execute goals
-    o_info!("🚀 Starting goal execution...");
-    let start_time = std::time::Instant::now();
-    
-    while goal_manager.has_pending_goals() {
-        // Update context for current execution cycle
-        let current_context = context_engine.build_context(&priority_client).await?;
-        
-        // Execute next batch of goals
-        let results = goal_manager.execute_goals(&priority_client, &current_context).await?;
-        
-        // Report results
-        for result in results {
-            if result.success {
-                o_info!("✅ Goal result: {}", result.message);
-                if !result.ships_used.is_empty() {
-                    o_info!("🚢 Ships used: {:?}", result.ships_used);
-                }
-                if result.credits_spent != 0 {
-                    if result.credits_spent > 0 {
-                        o_info!("💸 Credits spent: {}", result.credits_spent);
-                    } else {
-                        o_info!("💰 Credits earned: {}", -result.credits_spent);
-                    }
-                }
-            } else {
-                o_error!("❌ Goal failed: {}", result.message);
-            }
-        }
-        
-        // Brief pause between execution cycles
-        tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
-        
-        // Safety timeout
-        if start_time.elapsed().as_secs() > 600 { // 10 minute timeout
-            o_error!("⏰ Goal execution timeout - stopping after 10 minutes");
-            break;
-        }
-    }
-    
-    let execution_time = start_time.elapsed().as_secs_f64();
-    let status = goal_manager.get_status();
-    
-    o_info!("\n📊 Goal Execution Summary:");
-    o_info!("  ⏱️  Total execution time: {:.1} seconds", execution_time);
-    o_info!("  ✅ Goals completed: {}", status.completed_count);
-    o_info!("  ❌ Goals failed: {}", status.failed_count);
-    o_info!("  📋 Goals remaining: {}", status.queued_count);
-    
-    if status.failed_count > 0 {
-        return Err("Some goals failed during execution".into());
-    }
-    
-    Ok(())
-}
\ No newline at end of file
@@ -0,0 +1,256 @@
+// Metrics - fleet and API telemetry in the Prometheus text format, served on an optional local endpoint
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use crate::events::{EventSubscriber, GameEvent, PublishedEvent};
+use crate::models::{Contract, Ship};
+use crate::{o_error, o_info};
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShipGauges {
+    pub state: String,
+    pub cargo_units: i32,
+    pub cargo_capacity: i32,
+    pub fuel_current: i32,
+    pub fuel_capacity: i32,
+}
+
+/// Point-in-time copy of the headline numbers, for displays that do not want the text format
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsSnapshot {
+    pub credits: Option<i64>,
+    pub api_queue_depth: i64,
+    pub api_requests: u64,
+    pub rate_limited: u64,
+    /// (contract, good, fulfilled, required)
+    pub contract_progress: Vec<(String, String, i32, i32)>,
+}
+
+#[derive(Debug, Default)]
+struct MetricsState {
+    api_requests: BTreeMap<(String, String), u64>,
+    rate_limited: u64,
+    api_queue_depth: i64,
+    credits: Option<i64>,
+    ships: BTreeMap<String, ShipGauges>,
+    extracted_units: BTreeMap<String, u64>,
+    sales_revenue: BTreeMap<String, i64>,
+    /// (contract, good) -> (fulfilled, required)
+    contract_progress: BTreeMap<(String, String), (i32, i32)>,
+}
+
+/// Shared handle to the metrics registry; clones see the same values
+#[derive(Debug, Clone, Default)]
+pub struct Metrics {
+    state: Arc<Mutex<MetricsState>>,
+}
+
+static GLOBAL_METRICS: OnceLock<Metrics> = OnceLock::new();
+
+/// The metrics registry shared by the client and operations layers
+pub fn get_metrics() -> &'static Metrics {
+    GLOBAL_METRICS.get_or_init(Metrics::default)
+}
+
+/// `/v2/my/ships/AGENT-1/navigate` becomes `/my/ships/:id/navigate` so labels stay few
+pub fn endpoint_label(url: &str) -> String {
+    let path = url.split('?').next().unwrap_or(url);
+    let path = path.find("/v2/").map(|start| &path[start + 3..]).unwrap_or(path);
+    path.split('/')
+        .map(|segment| {
+            if segment.chars().all(|c| c.is_ascii_lowercase() || c == '-') {
+                segment
+            } else {
+                ":id"
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+impl Metrics {
+    pub fn record_api_request(&self, url: &str, status: Option<u16>) {
+        let status = status.map(|status| status.to_string()).unwrap_or_else(|| "error".to_string());
+        let mut state = self.state.lock().unwrap();
+        if status == "429" {
+            state.rate_limited += 1;
+        }
+        *state.api_requests.entry((endpoint_label(url), status)).or_insert(0) += 1;
+    }
+
+    pub fn adjust_api_queue_depth(&self, change: i64) {
+        self.state.lock().unwrap().api_queue_depth += change;
+    }
+
+    pub fn record_credits(&self, credits: i64) {
+        self.state.lock().unwrap().credits = Some(credits);
+    }
+
+    pub fn record_ship(&self, ship: &Ship, state: &str) {
+        self.state.lock().unwrap().ships.insert(ship.symbol.clone(), ShipGauges {
+            state: state.to_string(),
+            cargo_units: ship.cargo.units,
+            cargo_capacity: ship.cargo.capacity,
+            fuel_current: ship.fuel.current,
+            fuel_capacity: ship.fuel.capacity,
+        });
+    }
+
+    pub fn ship(&self, ship_symbol: &str) -> Option<ShipGauges> {
+        self.state.lock().unwrap().ships.get(ship_symbol).cloned()
+    }
+
+    /// Replace contract progress with the accepted, unfulfilled contracts
+    pub fn record_contracts(&self, contracts: &[Contract]) {
+        let mut state = self.state.lock().unwrap();
+        state.contract_progress.clear();
+        for contract in contracts.iter().filter(|contract| contract.accepted && !contract.fulfilled) {
+            for delivery in &contract.terms.deliver {
+                state.contract_progress.insert((contract.id.clone(), delivery.trade_symbol.clone()),
+                    (delivery.units_fulfilled, delivery.units_required));
+            }
+        }
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let state = self.state.lock().unwrap();
+        MetricsSnapshot {
+            credits: state.credits,
+            api_queue_depth: state.api_queue_depth,
+            api_requests: state.api_requests.values().sum(),
+            rate_limited: state.rate_limited,
+            contract_progress: state.contract_progress.iter()
+                .map(|((contract, good), (done, required))| (contract.clone(), good.clone(), *done, *required))
+                .collect(),
+        }
+    }
+
+    /// Everything in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let state = self.state.lock().unwrap();
+        let mut out = String::new();
+        let family = |out: &mut String, name: &str, kind: &str, help: &str| {
+            let _ = writeln!(out, "# HELP {} {}\n# TYPE {} {}", name, help, name, kind);
+        };
+
+        family(&mut out, "spacetraders_api_requests_total", "counter", "API requests by endpoint and response status");
+        for ((endpoint, status), count) in &state.api_requests {
+            let _ = writeln!(out, "spacetraders_api_requests_total{{endpoint=\"{}\",status=\"{}\"}} {}", endpoint, status, count);
+        }
+        family(&mut out, "spacetraders_api_rate_limited_total", "counter", "Responses rejected with 429");
+        let _ = writeln!(out, "spacetraders_api_rate_limited_total {}", state.rate_limited);
+        family(&mut out, "spacetraders_api_queue_depth", "gauge", "Requests waiting in the API broker");
+        let _ = writeln!(out, "spacetraders_api_queue_depth {}", state.api_queue_depth);
+
+        if let Some(credits) = state.credits {
+            family(&mut out, "spacetraders_credits", "gauge", "Agent credits");
+            let _ = writeln!(out, "spacetraders_credits {}", credits);
+        }
+
+        family(&mut out, "spacetraders_ship_state", "gauge", "1 for the state each ship is in");
+        for (ship, gauges) in &state.ships {
+            let _ = writeln!(out, "spacetraders_ship_state{{ship=\"{}\",state=\"{}\"}} 1", ship, gauges.state);
+        }
+        for (name, help, value) in [
+            ("spacetraders_ship_cargo_units", "Cargo units held", (|g: &ShipGauges| g.cargo_units) as fn(&ShipGauges) -> i32),
+            ("spacetraders_ship_cargo_capacity", "Cargo capacity", |g| g.cargo_capacity),
+            ("spacetraders_ship_fuel", "Fuel on board", |g| g.fuel_current),
+            ("spacetraders_ship_fuel_capacity", "Fuel capacity", |g| g.fuel_capacity),
+        ] {
+            family(&mut out, name, "gauge", help);
+            for (ship, gauges) in &state.ships {
+                let _ = writeln!(out, "{}{{ship=\"{}\"}} {}", name, ship, value(gauges));
+            }
+        }
+
+        family(&mut out, "spacetraders_extracted_units_total", "counter", "Units extracted or siphoned by good");
+        for (good, units) in &state.extracted_units {
+            let _ = writeln!(out, "spacetraders_extracted_units_total{{good=\"{}\"}} {}", good, units);
+        }
+        family(&mut out, "spacetraders_sales_revenue_total", "counter", "Credits earned from sales by good");
+        for (good, credits) in &state.sales_revenue {
+            let _ = writeln!(out, "spacetraders_sales_revenue_total{{good=\"{}\"}} {}", good, credits);
+        }
+        for (name, help, fulfilled) in [
+            ("spacetraders_contract_units_fulfilled", "Units delivered to active contracts", true),
+            ("spacetraders_contract_units_required", "Units required by active contracts", false),
+        ] {
+            family(&mut out, name, "gauge", help);
+            for ((contract, good), (done, required)) in &state.contract_progress {
+                let _ = writeln!(out, "{}{{contract=\"{}\",good=\"{}\"}} {}", name, contract, good,
+                                 if fulfilled { done } else { required });
+            }
+        }
+        out
+    }
+
+    /// Serve `/metrics` on a local address until the process exits; returns the bound address
+    pub async fn serve(&self, addr: SocketAddr) -> std::io::Result<SocketAddr> {
+        let listener = TcpListener::bind(addr).await?;
+        let addr = listener.local_addr()?;
+        o_info!("📈 Metrics available at http://{}/metrics", addr);
+        let metrics = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = match listener.accept().await {
+                    Ok(connection) => connection,
+                    Err(e) => {
+                        o_error!("⚠️ Metrics endpoint accept failed: {}", e);
+                        continue;
+                    }
+                };
+                let metrics = metrics.clone();
+                tokio::spawn(async move {
+                    let mut request = [0u8; 1024];
+                    let Ok(read) = stream.read(&mut request).await else {
+                        return;
+                    };
+                    let request = String::from_utf8_lossy(&request[..read]);
+                    let response = if request.starts_with("GET /metrics") {
+                        let body = metrics.render();
+                        format!("HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                                body.len(), body)
+                    } else {
+                        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string()
+                    };
+                    let _ = stream.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+        Ok(addr)
+    }
+}
+
+/// Extraction and sales totals are counted from the event bus
+impl EventSubscriber for Metrics {
+    fn name(&self) -> &str {
+        "metrics"
+    }
+
+    fn handle(&mut self, event: &PublishedEvent) {
+        let mut state = self.state.lock().unwrap();
+        match &event.event {
+            GameEvent::Extraction { good, units, .. } => {
+                *state.extracted_units.entry(good.clone()).or_insert(0) += (*units).max(0) as u64;
+            }
+            GameEvent::Sale { good, total_price, .. } => {
+                *state.sales_revenue.entry(good.clone()).or_insert(0) += total_price;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_labels_hide_symbols() {
+        assert_eq!(endpoint_label("https://api.spacetraders.io/v2/my/ships/MINER-1/navigate"), "/my/ships/:id/navigate");
+        assert_eq!(endpoint_label("https://api.spacetraders.io/v2/systems/X1-AB12/waypoints?traits=MARKETPLACE"), "/systems/:id/waypoints");
+    }
+}
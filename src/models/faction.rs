@@ -16,4 +16,10 @@ pub struct FactionTrait {
     pub symbol: String,
     pub name: String,
     pub description: String,
-}
\ No newline at end of file
+}
+/// Agent standing with a faction, as returned by `/my/factions`
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AgentFaction {
+    pub symbol: String,
+    pub reputation: i32,
+}
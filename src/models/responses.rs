@@ -66,6 +66,11 @@ pub struct ExtractionResponse {
     pub data: crate::models::ExtractionData,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SiphonResponse {
+    pub data: crate::models::SiphonData,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SurveyResponse {
     pub data: crate::models::SurveyData,
@@ -127,6 +132,17 @@ pub struct ScannedWaypoint {
     pub faction: Option<crate::models::waypoint::WaypointFaction>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ChartResponse {
+    pub data: ChartData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChartData {
+    pub chart: crate::models::waypoint::Chart,
+    pub waypoint: crate::models::Waypoint,
+}
+
 // New response types for additional API endpoints
 #[derive(Debug, Deserialize)]
 pub struct SystemsResponse {
@@ -180,6 +196,11 @@ pub struct FactionResponse {
     pub data: crate::models::faction::Faction,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct AgentFactionsResponse {
+    pub data: Vec<crate::models::faction::AgentFaction>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct JumpGateResponse {
     pub data: crate::models::navigation::JumpGate,
@@ -237,6 +258,30 @@ pub struct ModuleRemovalData {
     pub transaction: ModuleTransaction,
 }
 
+// Mount management responses
+#[derive(Debug, Deserialize)]
+pub struct MountInstallResponse {
+    pub data: MountChangeData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MountRemovalResponse {
+    pub data: MountChangeData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ShipMountsResponse {
+    pub data: Vec<crate::models::ShipMount>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MountChangeData {
+    pub agent: crate::models::Agent,
+    pub mounts: Vec<crate::models::ShipMount>,
+    pub cargo: crate::models::ShipCargo,
+    pub transaction: ModuleTransaction,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ModuleTransaction {
     #[serde(rename = "waypointSymbol")]
@@ -271,6 +316,16 @@ pub struct RepairData {
     pub transaction: RepairTransaction,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ScrapValueResponse {
+    pub data: ScrapValue,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScrapValue {
+    pub transaction: RepairTransaction, // same shape as a repair quote
+}
+
 #[derive(Debug, Deserialize)]
 pub struct RepairTransaction {
     #[serde(rename = "waypointSymbol")]
@@ -280,4 +335,13 @@ pub struct RepairTransaction {
     #[serde(rename = "totalPrice")]
     pub total_price: i32,
     pub timestamp: String,
-}
\ No newline at end of file
+}
+/// Server status from `GET /`; not wrapped in `data`
+#[derive(Debug, Deserialize)]
+pub struct ServerStatus {
+    pub status: String,
+    pub version: String,
+    /// Day the universe was last reset, e.g. "2025-01-05"
+    #[serde(rename = "resetDate")]
+    pub reset_date: String,
+}
@@ -87,6 +87,12 @@ pub struct ShipModule {
     pub symbol: String,
     pub capacity: Option<i32>,
     pub range: Option<i32>,
+    #[serde(rename = "powerOutput", default)]
+    pub power_output: Option<i32>, // reactors only
+    #[serde(default)]
+    pub condition: Option<f64>, // reactors and engines only
+    #[serde(default)]
+    pub integrity: Option<f64>,
     pub name: String,
     pub description: String,
     pub requirements: ShipRequirements,
@@ -28,6 +28,13 @@ pub struct ExtractionData {
     pub cargo: crate::models::ShipCargo,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SiphonData {
+    pub cooldown: crate::models::ShipCooldown,
+    pub siphon: ExtractionResult,
+    pub cargo: crate::models::ShipCargo,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ExtractionResult {
     #[serde(rename = "shipSymbol")]
@@ -68,6 +75,21 @@ pub struct SellTransaction {
     pub timestamp: String,
 }
 
+impl From<SellTransaction> for crate::models::MarketTransaction {
+    fn from(transaction: SellTransaction) -> Self {
+        Self {
+            waypoint_symbol: transaction.waypoint_symbol,
+            ship_symbol: transaction.ship_symbol,
+            trade_symbol: transaction.trade_symbol,
+            transaction_type: transaction.transaction_type,
+            units: transaction.units,
+            price_per_unit: transaction.price_per_unit,
+            total_price: transaction.total_price,
+            timestamp: transaction.timestamp,
+        }
+    }
+}
+
 // Refueling structures
 #[derive(Debug, Deserialize)]
 pub struct RefuelData {
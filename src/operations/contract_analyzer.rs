@@ -2,7 +2,7 @@
 use crate::models::Contract;
 use crate::client::priority_client::PriorityApiClient;
 use crate::goals::goal_types::ShipRoleGoal;
-use crate::goals::{Goal, GoalPriority, GoalStatus};
+use crate::goals::{Goal, GoalPriority, GoalStatus, GoalParameters};
 use crate::{o_debug, o_info};
 use std::collections::HashMap;
 
@@ -225,6 +225,10 @@ impl ContractAnalyzer {
                     desired_role: "refiner".to_string(),
                     priority: GoalPriority::Urgent, // High priority for contract requirements
                     status: GoalStatus::Pending,
+                    params: GoalParameters {
+                        role: Some("refiner".to_string()),
+                        ..Default::default()
+                    },
                 };
                 
                 o_info!("  📋 Generated goal: Find and designate refiner #{}", i + 1);
@@ -246,6 +250,10 @@ impl ContractAnalyzer {
                     desired_role: "hauler".to_string(),
                     priority: GoalPriority::Economic,
                     status: GoalStatus::Pending,
+                    params: GoalParameters {
+                        role: Some("hauler".to_string()),
+                        ..Default::default()
+                    },
                 };
                 
                 o_info!("  📋 Generated goal: Find and designate hauler #{}", i + 1);
@@ -1,19 +1,24 @@
 // Contract operations module
-use crate::client::SpaceTradersClient;
+use crate::client::{PriorityApiClient, SpaceTradersClient};
+use crate::config::SpaceTradersConfig;
 use crate::models::*;
-use crate::operations::{ShipOperations, ProductKnowledge};
+use crate::operations::{ShipOperations, ProductKnowledge, NavigationPlanner};
+use crate::storage::{FactionStore, ShipStateStore, SHIP_STATE_PATH};
 use crate::{o_error, o_summary, o_info, o_debug};
 use tokio::time::{sleep, Duration};
 
+pub const FACTION_STORE_PATH: &str = "storage/faction_history.json";
+
 pub struct ContractOperations<'a> {
     client: &'a SpaceTradersClient,
+    config: &'a SpaceTradersConfig,
     ship_ops: ShipOperations<'a>,
 }
 
 impl<'a> ContractOperations<'a> {
-    pub fn new(client: &'a SpaceTradersClient) -> Self {
+    pub fn new(client: &'a SpaceTradersClient, config: &'a SpaceTradersConfig) -> Self {
         let ship_ops = ShipOperations::new(client);
-        Self { client, ship_ops }
+        Self { client, config, ship_ops }
     }
 
     // Basic contract operations
@@ -69,13 +74,17 @@ impl<'a> ContractOperations<'a> {
             }
         }
 
-        // Find the best unaccepted contract
+        // Find the best unaccepted contract, biased toward factions that have paid well before
+        let mut faction_store = FactionStore::new(FACTION_STORE_PATH);
         let mut best_contract = None;
         let mut best_score = 0i64;
 
         for contract in &contracts {
             if !contract.accepted {
-                let score = self.score_contract(contract);
+                if let Err(e) = faction_store.record_negotiated(contract) {
+                    o_debug!("  ⚠️ Could not record contract offer: {}", e);
+                }
+                let score = self.score_contract(contract) + faction_store.score_faction(&contract.faction_symbol) as i64;
                 o_info!("📝 Found contract: {} (Type: {})", contract.id, contract.contract_type);
                 o_info!("  Faction: {}", contract.faction_symbol);
                 o_info!("  Payment: {} on accepted, {} on fulfilled", 
@@ -99,7 +108,10 @@ impl<'a> ContractOperations<'a> {
         if let Some(contract) = best_contract {
             o_info!("🤝 Accepting contract {}...", contract.id);
             match self.accept_contract(&contract.id).await {
-                Ok(_) => {
+                Ok(accept_data) => {
+                    if let Err(e) = faction_store.record_accepted(&accept_data.contract) {
+                        o_debug!("  ⚠️ Could not record accepted contract: {}", e);
+                    }
                     o_summary!("  ✅ Contract accepted successfully!");
                     Ok(Some((*contract).clone()))
                 }
@@ -211,6 +223,7 @@ impl<'a> ContractOperations<'a> {
                 
                 match self.fulfill_contract(&contract.id).await {
                     Ok(fulfill_data) => {
+                        self.record_contract_fulfilled(&fulfill_data.contract);
                         o_summary!("🎆 CONTRACT FULFILLED SUCCESSFULLY!");
                         o_summary!("  💰 Payment received: {} credits", contract.terms.payment.on_fulfilled);
                         o_summary!("  📊 New agent credits: {}", fulfill_data.agent.credits);
@@ -448,6 +461,7 @@ impl<'a> ContractOperations<'a> {
             
             match self.fulfill_contract(&contract.id).await {
                 Ok(fulfill_data) => {
+                    self.record_contract_fulfilled(&fulfill_data.contract);
                     o_summary!("🎆 CONTRACT FULFILLED SUCCESSFULLY!");
                     o_summary!("  💰 Payment received: {} credits", contract.terms.payment.on_fulfilled);
                     o_summary!("  📊 New agent credits: {}", fulfill_data.agent.credits);
@@ -475,177 +489,102 @@ impl<'a> ContractOperations<'a> {
 
     /// Negotiate new contracts when needed (e.g., when all current contracts are completed)
     /// 
+    /// Factions are ranked by their contract history and our reputation with them
+    /// (see `FactionStore`). For each faction in turn we look for a ship already at
+    /// its headquarters, otherwise we send the cheapest idle ship in that system there.
+    /// 
     /// Requirements for successful contract negotiation:
     /// 1. Ship must be at a faction waypoint
     /// 2. Ship must be DOCKED (will automatically dock if in orbit)
     /// 3. Agent must have available contract slots (max 1 contract at a time)
     /// 4. Ship must not be in transit
     pub async fn negotiate_new_contract(&self) -> Result<Option<Contract>, Box<dyn std::error::Error>> {
-        o_info!("🤝 Starting contract negotiation process...");
+        o_info!("🤝 Starting faction-aware contract negotiation...");
         
-        // Get ships that are at faction waypoints
-        let ships = self.client.get_ships().await?;
-        let mut suitable_ships = Vec::new();
+        let mut faction_store = FactionStore::new(FACTION_STORE_PATH);
+        self.refresh_faction_reputation(&mut faction_store).await;
         
-        for ship in &ships {
-            // Skip ships that are in transit
-            if ship.nav.status == "IN_TRANSIT" {
-                o_info!("  ⚠️ {} in transit - skipping for contract negotiation", ship.symbol);
-                continue;
-            }
-            
-            // Get waypoint info to check for faction presence
-            let waypoint_parts: Vec<&str> = ship.nav.waypoint_symbol.split('-').collect();
-            let system_symbol = format!("{}-{}", waypoint_parts[0], waypoint_parts[1]);
-            
-            match self.client.get_waypoint(&system_symbol, &ship.nav.waypoint_symbol).await {
-                Ok(waypoint) => {
-                    if let Some(faction) = &waypoint.faction {
-                        o_info!("  ✅ {} at faction waypoint {} ({})", 
-                                ship.symbol, 
-                                waypoint.symbol, 
-                                faction.symbol);
-                        suitable_ships.push((ship, waypoint));
-                    } else {
-                        o_error!("  ❌ {} at {} (no faction)", ship.symbol, ship.nav.waypoint_symbol);
-                    }
-                }
-                Err(e) => {
-                    o_info!("  ⚠️ Could not check waypoint {} for {}: {}", 
-                            ship.nav.waypoint_symbol, ship.symbol, e);
-                }
-            }
-        }
+        let factions = self.get_all_factions().await?;
+        let ships = self.client.get_ships().await?;
+        let busy = self.ships_on_missions(&ships);
+        
+        // Only factions with a headquarters in a system where we have ships are reachable
+        let reachable: Vec<String> = factions.iter()
+            .filter(|faction| {
+                self.extract_system_from_waypoint(&faction.headquarters)
+                    .map(|system| ships.iter().any(|ship| ship.nav.system_symbol == system))
+                    .unwrap_or(false)
+            })
+            .map(|faction| faction.symbol.clone())
+            .collect();
         
-        if suitable_ships.is_empty() {
-            o_error!("  ❌ No ships at faction waypoints for contract negotiation");
+        if reachable.is_empty() {
+            o_error!("  ❌ No faction headquarters reachable from our ships' systems");
             o_info!("  💡 Ships need to visit faction-controlled waypoints to negotiate contracts");
             return Ok(None);
         }
         
-        // Try to negotiate with the first suitable ship
-        let (ship, waypoint) = &suitable_ships[0];
-        o_info!("  🤝 Attempting contract negotiation with {} at {}", ship.symbol, waypoint.symbol);
-        
-        // CRITICAL: Ship must be docked to negotiate contracts!
-        if ship.nav.status != "DOCKED" {
-            o_info!("  🛸 Ship not docked - docking {} at {}...", ship.symbol, waypoint.symbol);
-            match self.client.dock_ship(&ship.symbol).await {
-                Ok(_) => o_info!("    ✅ Successfully docked for contract negotiation"),
-                Err(e) => {
-                    o_error!("    ❌ Failed to dock {}: {}", ship.symbol, e);
-                    o_info!("    🔄 Trying next ship...");
-                    // Try with other ships if docking failed
-                    for (ship, waypoint) in suitable_ships.iter().skip(1).take(2) {
-                        o_info!("  🔄 Trying with {} at {}...", ship.symbol, waypoint.symbol);
-                        if ship.nav.status != "DOCKED" {
-                            if let Err(e) = self.client.dock_ship(&ship.symbol).await {
-                                o_error!("    ❌ Also failed to dock {}: {}", ship.symbol, e);
-                                continue;
-                            }
-                        }
-                        // Try to negotiate with this ship now that it's docked
-                        match self.client.negotiate_contract(&ship.symbol).await {
-                            Ok(new_contract) => {
-                                o_summary!("  ✅ Success with docked ship {}! Contract: {}", ship.symbol, new_contract.id);
-                                match self.accept_contract(&new_contract.id).await {
-                                    Ok(_) => {
-                                        o_summary!("  🤝 Contract {} accepted!", new_contract.id);
-                                        return Ok(Some(new_contract));
-                                    }
-                                    Err(e) => {
-                                        o_info!("  ⚠️ Could not accept: {}", e);
-                                        return Ok(Some(new_contract));
-                                    }
-                                }
-                            }
-                            Err(e) => {
-                                o_error!("  ❌ Still failed with docked {}: {}", ship.symbol, e);
-                                continue;
-                            }
-                        }
-                    }
-                    return Ok(None);
-                }
-            }
-        }
-        
-        match self.client.negotiate_contract(&ship.symbol).await {
-            Ok(new_contract) => {
-                o_summary!("  ✅ Successfully negotiated new contract: {}", new_contract.id);
-                o_info!("    Faction: {}", new_contract.faction_symbol);
-                o_info!("    Type: {}", new_contract.contract_type);
-                o_info!("    Payment: {} on accepted, {} on fulfilled", 
-                        new_contract.terms.payment.on_accepted, 
-                        new_contract.terms.payment.on_fulfilled);
-                
-                // Show delivery requirements
-                for delivery in &new_contract.terms.deliver {
-                    o_info!("    📦 Deliver: {} x{} to {}", 
-                            delivery.trade_symbol, 
-                            delivery.units_required,
-                            delivery.destination_symbol);
-                }
-                
-                // Automatically accept the newly negotiated contract
-                match self.accept_contract(&new_contract.id).await {
-                    Ok(_) => {
-                        o_summary!("  🤝 Contract {} accepted automatically!", new_contract.id);
-                        return Ok(Some(new_contract));
-                    }
-                    Err(e) => {
-                        o_info!("  ⚠️ Could not accept negotiated contract: {}", e);
-                        // Still return the contract even if acceptance failed
-                        return Ok(Some(new_contract));
+        for (faction_symbol, score) in faction_store.rank_factions(&reachable) {
+            let Some(faction) = factions.iter().find(|f| f.symbol == faction_symbol) else {
+                continue;
+            };
+            
+            o_info!("  🎯 Targeting {} (score {:.1}, HQ {})", faction.symbol, score, faction.headquarters);
+            
+            match self.negotiate_with_faction(faction, &ships, &busy).await {
+                Ok(Some(new_contract)) => {
+                    o_summary!("  ✅ Successfully negotiated new contract: {}", new_contract.id);
+                    o_info!("    Faction: {}", new_contract.faction_symbol);
+                    o_info!("    Type: {}", new_contract.contract_type);
+                    o_info!("    Payment: {} on accepted, {} on fulfilled", 
+                            new_contract.terms.payment.on_accepted, 
+                            new_contract.terms.payment.on_fulfilled);
+                    
+                    for delivery in &new_contract.terms.deliver {
+                        o_info!("    📦 Deliver: {} x{} to {}", 
+                                delivery.trade_symbol, 
+                                delivery.units_required,
+                                delivery.destination_symbol);
                     }
-                }
-            }
-            Err(e) => {
-                let error_msg = e.to_string();
-                if error_msg.contains("400 Bad Request") {
-                    o_error!("  ❌ Contract negotiation failed: Ship not at faction waypoint or other requirement not met");
-                    o_info!("    Details: {}", error_msg);
-                } else if error_msg.contains("409") {
-                    o_error!("  ❌ Contract negotiation failed: Already have maximum contracts (1)");
-                    o_info!("    💡 This suggests the completed contract is still blocking the slot");
-                } else {
-                    o_error!("  ❌ Contract negotiation failed: {}", error_msg);
-                }
-                
-                // Try with other ships if available
-                for (ship, waypoint) in suitable_ships.iter().skip(1).take(2) {
-                    o_info!("  🔄 Trying with {} at {}...", ship.symbol, waypoint.symbol);
                     
-                    // Ensure ship is docked before negotiating
-                    if ship.nav.status != "DOCKED" {
-                        if let Err(e) = self.client.dock_ship(&ship.symbol).await {
-                            o_error!("    ❌ Failed to dock {}: {}", ship.symbol, e);
-                            continue;
-                        }
-                        o_info!("    ✅ {} docked for negotiation", ship.symbol);
+                    if let Err(e) = faction_store.record_negotiated(&new_contract) {
+                        o_debug!("  ⚠️ Could not record negotiated contract: {}", e);
                     }
                     
-                    match self.client.negotiate_contract(&ship.symbol).await {
-                        Ok(new_contract) => {
-                            o_summary!("  ✅ Success with {}! Contract: {}", ship.symbol, new_contract.id);
-                            
-                            // Auto-accept
-                            match self.accept_contract(&new_contract.id).await {
-                                Ok(_) => {
-                                    o_summary!("  🤝 Contract {} accepted!", new_contract.id);
-                                    return Ok(Some(new_contract));
-                                }
-                                Err(e) => {
-                                    o_info!("  ⚠️ Could not accept: {}", e);
-                                    return Ok(Some(new_contract));
-                                }
+                    // Automatically accept the newly negotiated contract
+                    match self.accept_contract(&new_contract.id).await {
+                        Ok(accept_data) => {
+                            o_summary!("  🤝 Contract {} accepted automatically!", new_contract.id);
+                            if let Err(e) = faction_store.record_accepted(&accept_data.contract) {
+                                o_debug!("  ⚠️ Could not record accepted contract: {}", e);
                             }
+                            self.refresh_faction_reputation(&mut faction_store).await;
+                            return Ok(Some(accept_data.contract));
                         }
                         Err(e) => {
-                            o_error!("  ❌ Also failed with {}: {}", ship.symbol, e);
+                            o_info!("  ⚠️ Could not accept negotiated contract: {}", e);
+                            // Still return the contract even if acceptance failed
+                            return Ok(Some(new_contract));
                         }
                     }
                 }
+                Ok(None) => {
+                    o_info!("  ⏭️ No ship available to negotiate with {}", faction.symbol);
+                }
+                Err(e) => {
+                    let error_msg = e.to_string();
+                    if error_msg.contains("400") {
+                        o_error!("  ❌ Negotiation with {} failed: Ship not at faction waypoint or other requirement not met", faction.symbol);
+                        o_info!("    Details: {}", error_msg);
+                    } else if error_msg.contains("409") {
+                        o_error!("  ❌ Negotiation with {} failed: Already have maximum contracts (1)", faction.symbol);
+                        o_info!("    💡 This suggests the completed contract is still blocking the slot");
+                        // Every other faction will refuse for the same reason
+                        return Ok(None);
+                    } else {
+                        o_error!("  ❌ Negotiation with {} failed: {}", faction.symbol, error_msg);
+                    }
+                }
             }
         }
         
@@ -654,6 +593,112 @@ impl<'a> ContractOperations<'a> {
         Ok(None)
     }
     
+    /// Negotiate a contract with a specific faction at its headquarters.
+    /// 
+    /// Uses a ship already at the headquarters if there is one, otherwise moves the
+    /// cheapest suitable ship there first. Ships in `busy` are left alone.
+    /// Returns `Ok(None)` if no ship can reach it.
+    pub async fn negotiate_with_faction(&self, faction: &Faction, ships: &[Ship], busy: &[String]) -> Result<Option<Contract>, Box<dyn std::error::Error>> {
+        let at_headquarters = ships.iter()
+            .filter(|ship| !busy.contains(&ship.symbol))
+            .find(|ship| ship.nav.waypoint_symbol == faction.headquarters && ship.nav.status != "IN_TRANSIT");
+        
+        let (ship_symbol, docked) = match at_headquarters {
+            Some(ship) => {
+                o_info!("  ✅ {} already at {} headquarters {}", ship.symbol, faction.symbol, faction.headquarters);
+                (ship.symbol.clone(), ship.nav.status == "DOCKED")
+            }
+            None => {
+                let Some(courier) = self.select_negotiation_ship(ships, &faction.headquarters, busy) else {
+                    return Ok(None);
+                };
+                o_info!("  🚀 Sending {} to {} headquarters {}", courier.symbol, faction.symbol, faction.headquarters);
+                self.move_ship_to_waypoint(courier, &faction.headquarters).await?;
+                (courier.symbol.clone(), true)
+            }
+        };
+        
+        // CRITICAL: Ship must be docked to negotiate contracts!
+        if !docked {
+            o_info!("  🛸 Docking {} for contract negotiation...", ship_symbol);
+            self.ship_ops.dock(&ship_symbol).await?;
+        }
+        
+        let contract = self.client.negotiate_contract(&ship_symbol).await?;
+        Ok(Some(contract))
+    }
+    
+    /// Pick the cheapest idle ship to send to a faction headquarters.
+    /// 
+    /// Probes are preferred, then empty non-mining ships with the smallest hold.
+    /// Ships in `busy`, in transit or in another system are never chosen.
+    pub fn select_negotiation_ship<'b>(&self, ships: &'b [Ship], headquarters: &str, busy: &[String]) -> Option<&'b Ship> {
+        let hq_system = self.extract_system_from_waypoint(headquarters)?;
+        
+        ships.iter()
+            .filter(|ship| !busy.contains(&ship.symbol))
+            .filter(|ship| ship.nav.status != "IN_TRANSIT" && ship.nav.system_symbol == hq_system)
+            .min_by_key(|ship| (
+                ship.registration.role != "SATELLITE",
+                self.ship_ops.has_mining_capability(ship),
+                ship.cargo.units > 0,
+                ship.cargo.capacity,
+            ))
+    }
+    
+    /// Ships with a stored mission or queued action, which covers mining station members
+    /// working a station task, and ships the operator has paused
+    fn ships_on_missions(&self, ships: &[Ship]) -> Vec<String> {
+        let store = ShipStateStore::new(SHIP_STATE_PATH, self.config.caching.ship_state_staleness_minutes);
+        ships.iter()
+            .filter(|ship| !store.missions(&ship.symbol).is_empty() || crate::control::get_control().is_paused(&ship.symbol))
+            .map(|ship| ship.symbol.clone())
+            .collect()
+    }
+    
+    /// Every faction, a page at a time (the API returns at most 20 per page)
+    async fn get_all_factions(&self) -> Result<Vec<Faction>, Box<dyn std::error::Error>> {
+        const PAGE_SIZE: i32 = 20;
+        let mut factions = Vec::new();
+        for page in 1.. {
+            let batch = self.client.get_factions(Some(page), Some(PAGE_SIZE)).await?;
+            let last_page = batch.len() < PAGE_SIZE as usize;
+            factions.extend(batch);
+            if last_page {
+                break;
+            }
+        }
+        Ok(factions)
+    }
+    
+    /// Fly a ship to a waypoint and dock there, stopping to refuel first when its tank cannot cover the trip
+    async fn move_ship_to_waypoint(&self, ship: &Ship, waypoint_symbol: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let planner = NavigationPlanner::new(self.client.clone(), self.config.clone());
+        planner.travel_with_refuel(&PriorityApiClient::new(self.client.clone()), ship, waypoint_symbol).await
+    }
+    
+    /// Pull current faction reputations into the faction history
+    async fn refresh_faction_reputation(&self, faction_store: &mut FactionStore) {
+        match self.client.get_my_factions().await {
+            Ok(standings) => {
+                for standing in standings {
+                    if let Err(e) = faction_store.record_reputation(&standing.symbol, standing.reputation) {
+                        o_debug!("  ⚠️ Could not record reputation for {}: {}", standing.symbol, e);
+                    }
+                }
+            }
+            Err(e) => o_debug!("  ⚠️ Could not fetch faction reputation: {}", e),
+        }
+    }
+    
+    /// Record a fulfilled contract against its faction's history
+    fn record_contract_fulfilled(&self, contract: &Contract) {
+        let mut faction_store = FactionStore::new(FACTION_STORE_PATH);
+        if let Err(e) = faction_store.record_fulfilled(contract) {
+            o_debug!("  ⚠️ Could not record fulfilled contract: {}", e);
+        }
+    }
+    
     /// Handle marketplace trading for contract materials that can't be mined
     /// Returns true if trading operations were initiated
     pub async fn handle_marketplace_trading(&self, contract: &Contract) -> Result<bool, Box<dyn std::error::Error>> {
@@ -24,17 +24,23 @@ impl<'a> FleetOperations<'a> {
         ships.iter().filter(|ship| self.ship_ops.has_mining_capability(ship)).collect()
     }
 
+    pub fn get_siphon_ships<'b>(&self, ships: &'b [Ship]) -> Vec<&'b Ship> {
+        ships.iter().filter(|ship| self.ship_ops.has_siphon_capability(ship)).collect()
+    }
+
     pub fn get_hauler_ships<'b>(&self, ships: &'b [Ship]) -> Vec<&'b Ship> {
         ships.iter().filter(|ship| self.ship_ops.is_hauler(ship)).collect()
     }
 
     pub fn analyze_fleet(&self, ships: &[Ship]) -> FleetAnalysis {
         let miners = self.get_mining_ships(ships);
+        let siphoners = self.get_siphon_ships(ships);
         let haulers = self.get_hauler_ships(ships);
         
         FleetAnalysis {
             total_ships: ships.len(),
             mining_ships: miners.len(),
+            siphon_ships: siphoners.len(),
             hauler_ships: haulers.len(),
             total_cargo_capacity: ships.iter().map(|s| s.cargo.capacity).sum(),
             total_cargo_used: ships.iter().map(|s| s.cargo.units).sum(),
@@ -295,6 +301,7 @@ impl<'a> FleetOperations<'a> {
 pub struct FleetAnalysis {
     pub total_ships: usize,
     pub mining_ships: usize,
+    pub siphon_ships: usize,
     pub hauler_ships: usize,
     pub total_cargo_capacity: i32,
     pub total_cargo_used: i32,
@@ -1,23 +1,39 @@
 // Fleet Coordinator - Manages ship actors and task assignment
-use crate::client::SpaceTradersClient;
+use crate::client::{ApiRequestBroker, SpaceTradersClient};
 use crate::{o_error, o_summary, o_info, o_debug};
 use crate::models::*;
 use crate::operations::ship_actor::*;
 use crate::operations::ship_prioritizer::*;
 use crate::operations::task_planner::*;
-use crate::storage::{ShipStateStore, SurveyCache};
+use crate::operations::mining_station::*;
+use crate::operations::siphoning::SiphonOperations;
+use crate::operations::fleet_planner::*;
+use crate::operations::maintenance::*;
+use crate::operations::missions::*;
+use crate::storage::{ShipStateStore, SurveyCache, SHIP_STATE_PATH, SURVEY_CACHE_PATH, get_condition_history, get_extraction_log, get_income_ledger, get_survey_pool};
 use crate::config::SpaceTradersConfig;
+use crate::control::{get_control, ForcedRole};
+use crate::dashboard::get_dashboard;
+use crate::metrics::get_metrics;
 use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 use tokio::time::{sleep, Duration, Instant};
 use std::time::SystemTime;
 use std::collections::HashMap;
 
 pub struct FleetCoordinator {
     client: SpaceTradersClient,
-    ship_queues: HashMap<String, mpsc::UnboundedSender<ShipAction>>,
+    ship_queues: HashMap<String, mpsc::UnboundedSender<ShipCommand>>,
+    actor_handles: Vec<JoinHandle<()>>,
     ship_states: HashMap<String, ShipState>,
     status_receiver: mpsc::UnboundedReceiver<(String, ShipState)>,
     status_sender: mpsc::UnboundedSender<(String, ShipState)>,
+    report_receiver: mpsc::UnboundedReceiver<ActionReport>,
+    report_sender: mpsc::UnboundedSender<ActionReport>,
+    next_action_id: ActionId,
+    /// Actions sent to ships whose outcome has not been reported yet
+    in_flight: HashMap<ActionId, (String, ShipAction)>,
+    last_reports: HashMap<String, ActionReport>,
     prioritizer: ShipPrioritizer,
     fleet_metrics: Vec<ShipPerformanceMetrics>,
     ship_cache: ShipStateStore,
@@ -26,22 +42,34 @@ pub struct FleetCoordinator {
     cached_contracts: Option<Vec<Contract>>,
     contract_cache_timestamp: Option<SystemTime>,
     config: SpaceTradersConfig,
+    mining_station: Option<MiningStation>,
+    maintenance: MaintenanceManager,
+    /// (ship type, shipyard) purchases that failed this run, so expansion checks do not retry them
+    failed_purchases: Vec<(String, String)>,
 }
 
 impl FleetCoordinator {
     pub fn new(client: SpaceTradersClient, config: SpaceTradersConfig) -> Self {
         let (status_sender, status_receiver) = mpsc::unbounded_channel();
+        let (report_sender, report_receiver) = mpsc::unbounded_channel();
         let prioritizer = ShipPrioritizer::new(client.clone());
-        let ship_cache = ShipStateStore::new("storage/ship_states.json", config.caching.ship_state_staleness_minutes);
-        let survey_cache = SurveyCache::new("storage/survey_cache.json", config.caching.survey_cache_hours);
+        let ship_cache = ShipStateStore::new(SHIP_STATE_PATH, config.caching.ship_state_staleness_minutes);
+        let survey_cache = SurveyCache::new(SURVEY_CACHE_PATH, config.caching.survey_cache_hours);
         let task_planner = TaskPlanner::new(client.clone(), config.clone());
+        let maintenance = MaintenanceManager::new(config.fleet.repair_condition_threshold);
         
         Self {
             client,
             ship_queues: HashMap::new(),
+            actor_handles: Vec::new(),
             ship_states: HashMap::new(),
             status_receiver,
             status_sender,
+            report_receiver,
+            report_sender,
+            next_action_id: 1,
+            in_flight: HashMap::new(),
+            last_reports: HashMap::new(),
             prioritizer,
             fleet_metrics: Vec::new(),
             ship_cache,
@@ -50,11 +78,15 @@ impl FleetCoordinator {
             cached_contracts: None,
             contract_cache_timestamp: None,
             config,
+            mining_station: None,
+            maintenance,
+            failed_purchases: Vec::new(),
         }
     }
 
     /// Update configuration for hot-reloading
     pub fn update_config(&mut self, new_config: SpaceTradersConfig) {
+        self.maintenance.set_repair_threshold(new_config.fleet.repair_condition_threshold);
         self.config = new_config;
     }
 
@@ -75,20 +107,68 @@ impl FleetCoordinator {
         }
         
         // Spawn actors for all ships
-        for ship in ships {
-            self.spawn_ship_actor(ship).await?;
+        for ship in &ships {
+            self.spawn_ship_actor(ship.clone()).await?;
         }
         
+        self.resume_missions(&ships).await;
+        
         o_summary!("✅ Fleet initialization complete - {} ship actors spawned with cached states", self.ship_queues.len());
         self.ship_cache.print_cache_status();
         Ok(())
     }
 
+    /// Check each stored mission against the ship's live state and re-issue the unfinished ones in their old order
+    async fn resume_missions(&mut self, ships: &[Ship]) {
+        let missions: Vec<(Ship, Vec<ShipMission>)> = ships.iter()
+            .map(|ship| (ship.clone(), self.ship_cache.missions(&ship.symbol).to_vec()))
+            .filter(|(_, missions)| !missions.is_empty())
+            .collect();
+        if missions.is_empty() {
+            return;
+        }
+
+        let active_contracts: Vec<String> = match self.client.get_contracts().await {
+            Ok(contracts) => contracts.into_iter()
+                .filter(|contract| contract.accepted && !contract.fulfilled)
+                .map(|contract| contract.id)
+                .collect(),
+            Err(e) => {
+                o_error!("⚠️ Could not check contracts, keeping stored missions for later: {}", e);
+                return;
+            }
+        };
+
+        for (ship, ship_missions) in missions {
+            // Action IDs start over each run, so the stored ones are replaced as the missions are re-issued
+            let _ = self.ship_cache.clear_mission(&ship.symbol, None);
+            for mission in ship_missions {
+                match mission.reconcile(&ship, &active_contracts) {
+                    MissionResume::Resume { from_step } => {
+                        o_info!("♻️ {} resuming {:?} at step {}/{}: {}", ship.symbol, mission.action,
+                                from_step + 1, mission.steps.len().max(1), mission.step_description(from_step));
+                        let action = mission.action.clone();
+                        let resumed = ShipMission { completed_steps: from_step, ..mission };
+                        if let Err(e) = self.dispatch_mission(&ship.symbol, resumed, false) {
+                            o_error!("❌ Failed to resume {:?} for {}: {}", action, ship.symbol, e);
+                        }
+                    }
+                    MissionResume::Completed => {
+                        o_info!("✅ {} finished {:?} before the restart", ship.symbol, mission.action);
+                    }
+                    MissionResume::Abandoned(reason) => {
+                        o_info!("🗑️ {} dropping stored mission: {}", ship.symbol, reason);
+                    }
+                }
+            }
+        }
+    }
+
     async fn spawn_ship_actor(&mut self, ship: Ship) -> Result<(), Box<dyn std::error::Error>> {
         let ship_symbol = ship.symbol.clone();
         
-        // Create action channel for this ship
-        let (action_sender, action_receiver) = mpsc::unbounded_channel();
+        // Create command channel for this ship
+        let (command_sender, command_receiver) = mpsc::unbounded_channel();
         
         // Clone client for the actor (shares the same broker for centralized rate limiting)
         let client_clone = self.client.clone();
@@ -97,8 +177,9 @@ impl FleetCoordinator {
         // Create and spawn the ship actor
         let mut actor = ShipActor::new(
             ship_symbol.clone(),
-            action_receiver,
+            command_receiver,
             status_sender_clone,
+            self.report_sender.clone(),
             client_clone,
             self.config.clone(),
         );
@@ -112,14 +193,17 @@ impl FleetCoordinator {
             status: ShipActorStatus::Idle,
         };
         
+        get_metrics().record_ship(&ship, initial_state.status.label());
+        get_dashboard().update_ship(&initial_state);
+        
         // Store references
-        self.ship_queues.insert(ship_symbol.clone(), action_sender);
+        self.ship_queues.insert(ship_symbol.clone(), command_sender);
         self.ship_states.insert(ship_symbol.clone(), initial_state);
         
         // Spawn the actor task
-        tokio::spawn(async move {
+        self.actor_handles.push(tokio::spawn(async move {
             actor.run().await;
-        });
+        }));
         
         o_info!("🤖 Spawned actor for {}", ship_symbol);
         Ok(())
@@ -151,6 +235,7 @@ impl FleetCoordinator {
                     crate::operations::ship_actor::ShipAction::Refuel { station } => format!("Refueling at {}", station),
                     crate::operations::ship_actor::ShipAction::SellCargo { marketplace } => format!("Selling at {}", marketplace),
                     crate::operations::ship_actor::ShipAction::DeliverCargo { destination, .. } => format!("Delivering to {}", destination),
+                    crate::operations::ship_actor::ShipAction::Repair { shipyard } => format!("Repairing at {}", shipyard),
                     _ => "Other task".to_string(),
                 })
             } else {
@@ -255,6 +340,21 @@ impl FleetCoordinator {
             }
         }
         
+        let yield_report = get_extraction_log().report();
+        if !yield_report.by_waypoint.is_empty() {
+            o_summary!("📈 EXTRACTION YIELD");
+            o_summary!("═══════════════════════════════════════");
+            for (waypoint, stats) in yield_report.ranked_waypoints() {
+                o_summary!("   ⛏️ {}: {:.3} units/cooldown-s ({} extractions, {:.1} avg units)",
+                        waypoint, stats.units_per_cooldown_second(), stats.extractions, stats.average_units());
+            }
+            for (loadout, stats) in yield_report.ranked_loadouts() {
+                o_summary!("   🔧 {}: {:.3} units/cooldown-s ({} extractions)",
+                        loadout, stats.units_per_cooldown_second(), stats.extractions);
+            }
+            o_summary!("");
+        }
+        
         o_summary!("═══════════════════════════════════════\n");
         Ok(())
     }
@@ -267,7 +367,7 @@ impl FleetCoordinator {
         // Start the main coordination loop
         let mut cycle_count = 0;
         
-        loop {
+        while !get_control().shutdown_requested() {
             cycle_count += 1;
             o_summary!("\n🔄 ═══ COORDINATION CYCLE #{} ═══", cycle_count);
             
@@ -276,12 +376,13 @@ impl FleetCoordinator {
             
             // Process status updates from ships
             self.process_status_updates().await;
+            self.process_action_reports();
             
             // Check for new ships that might have been added
             self.discover_new_ships().await?;
             
             // Check if we should purchase additional ships
-            self.check_ship_expansion(contract).await?;
+            self.check_ship_expansion().await?;
             
             // Assign tasks based on current fleet state
             self.assign_tasks(contract).await?;
@@ -289,8 +390,15 @@ impl FleetCoordinator {
             // Print fleet status at end of cycle
             self.print_fleet_status("END OF CYCLE").await?;
             
+            if let Err(e) = get_extraction_log().flush() {
+                o_error!("⚠️ Failed to save extraction log: {}", e);
+            }
+            
             // Wait before next cycle
-            sleep(Duration::from_secs(10)).await;
+            tokio::select! {
+                _ = sleep(Duration::from_secs(10)) => {}
+                _ = get_control().wait_for_shutdown() => break,
+            }
             
             // Check if contract is complete
             if self.is_contract_complete(contract).await? {
@@ -302,14 +410,96 @@ impl FleetCoordinator {
         Ok(())
     }
 
+    /// Wait for every actor to stop after a shutdown signal, then save what they reported and flush the stores
+    pub async fn shutdown(&mut self) {
+        o_summary!("🛑 Stopping {} ship actors...", self.actor_handles.len());
+        for handle in self.actor_handles.drain(..) {
+            if let Err(e) = handle.await {
+                o_error!("⚠️ Ship actor ended abnormally: {}", e);
+            }
+        }
+        self.ship_queues.clear();
+        
+        // Requests the actors sent before stopping finish before anything is written
+        ApiRequestBroker::global().drain().await;
+        
+        self.process_status_updates().await;
+        self.process_action_reports();
+        if let Err(e) = self.ship_cache.flush() {
+            o_error!("⚠️ Failed to save ship states: {}", e);
+        }
+        if let Err(e) = self.survey_cache.flush() {
+            o_error!("⚠️ Failed to save survey cache: {}", e);
+        }
+        if let Err(e) = get_survey_pool().flush() {
+            o_error!("⚠️ Failed to save survey pool: {}", e);
+        }
+        if let Err(e) = get_extraction_log().flush() {
+            o_error!("⚠️ Failed to save extraction log: {}", e);
+        }
+        o_summary!("💾 Fleet state saved");
+    }
+
     async fn process_status_updates(&mut self) {
         // Process all pending status updates
         while let Ok((ship_symbol, new_state)) = self.status_receiver.try_recv() {
             o_debug!("📡 Status update from {}: {:?}", ship_symbol, new_state.status);
+            get_metrics().record_ship(&new_state.ship, new_state.status.label());
+            get_dashboard().update_ship(&new_state);
+            if let Some((action_id, progress)) = self.ship_cache.mission(&ship_symbol)
+                    .map(|mission| (mission.action_id, mission.progress(&new_state.ship)))
+                && let Err(e) = self.ship_cache.set_mission_progress(&ship_symbol, action_id, progress) {
+                o_error!("⚠️ Failed to save mission progress for {}: {}", ship_symbol, e);
+            }
             self.ship_states.insert(ship_symbol, new_state);
         }
     }
 
+    /// Collect what happened to actions the ships have finished with
+    fn process_action_reports(&mut self) {
+        while let Ok(report) = self.report_receiver.try_recv() {
+            self.in_flight.remove(&report.action_id);
+            // A preempting action has already replaced the mission, so only a matching one is cleared;
+            // an interrupted one is left for the next run to resume
+            if report.outcome != ActionOutcome::Interrupted
+                && let Err(e) = self.ship_cache.clear_mission(&report.ship_symbol, Some(report.action_id)) {
+                o_error!("⚠️ Failed to clear mission for {}: {}", report.ship_symbol, e);
+            }
+            match &report.outcome {
+                ActionOutcome::Completed => o_debug!("📬 {} action #{} completed", report.ship_symbol, report.action_id),
+                ActionOutcome::Failed(e) => o_debug!("📬 {} action #{} failed: {}", report.ship_symbol, report.action_id, e),
+                ActionOutcome::Cancelled => o_info!("📬 {} action #{} cancelled", report.ship_symbol, report.action_id),
+                ActionOutcome::Preempted { by } => o_info!("📬 {} action #{} preempted by #{}", report.ship_symbol, report.action_id, by),
+                ActionOutcome::Interrupted => o_info!("📬 {} action #{} interrupted by shutdown", report.ship_symbol, report.action_id),
+            }
+            if let Some(advice) = &report.scrap_advice {
+                o_summary!("🔧 {} will not be sent for repairs again unless its condition improves or the repair threshold changes",
+                          report.ship_symbol);
+                self.maintenance.decline_repair(advice.clone(), get_condition_history());
+            }
+            for transaction in &report.transactions {
+                o_debug!("   💱 {} {} {} x{} for {}", report.ship_symbol, transaction.transaction_type,
+                        transaction.trade_symbol, transaction.units, transaction.total_price);
+            }
+            self.last_reports.insert(report.ship_symbol.clone(), report);
+        }
+    }
+
+    /// The most recent action outcome a ship reported
+    pub fn last_report(&self, ship_symbol: &str) -> Option<&ActionReport> {
+        self.last_reports.get(ship_symbol)
+    }
+
+    /// IDs of actions sent to a ship that have not finished yet, oldest first
+    pub fn in_flight_actions(&self, ship_symbol: &str) -> Vec<ActionId> {
+        let mut ids: Vec<ActionId> = self.in_flight.iter()
+            .filter(|(_, (ship, _))| ship == ship_symbol)
+            .map(|(id, _)| *id)
+            .collect();
+        ids.sort();
+        ids
+    }
+
     async fn assign_tasks(&mut self, contract: &Contract) -> Result<(), Box<dyn std::error::Error>> {
         let needed_materials: Vec<String> = contract.terms.deliver
             .iter()
@@ -339,6 +529,13 @@ impl FleetCoordinator {
         
         o_debug!("📊 Using {} ships ({} from cache)", ships.len(), self.ship_cache.list_cached_ships().len());
         
+        // Track wear so repairs can be scheduled before a component fails
+        let condition_history = get_condition_history();
+        for ship in &ships {
+            condition_history.record_ship(ship);
+        }
+        self.locate_repair_shipyards(&ships).await;
+        
         // Analyze fleet performance and get prioritized metrics
         self.fleet_metrics = self.prioritizer.analyze_fleet_performance(&ships, contract).await?;
         
@@ -349,6 +546,13 @@ impl FleetCoordinator {
             }
         }
         
+        // Miners hand ore to haulers on station once the fleet has both
+        let station_busy = self.ships_busy_outside_station(&ships, contract, &needed_materials);
+        if let Some(station) = &mut self.mining_station {
+            station.max_haulers = self.config.fleet.mining_station_haulers;
+            station.refresh(&ships, &station_busy);
+        }
+        
         // Get idle ships in priority order
         let idle_ships = self.prioritizer.get_idle_ships(&self.fleet_metrics);
         
@@ -362,6 +566,11 @@ impl FleetCoordinator {
             o_info!("🎯 Assigning tasks to {} idle ships in priority order", idle_ships.len());
             
             for ship_symbol in idle_ships {
+                // Paused ships finish what they are doing but get nothing new
+                if get_control().is_paused(&ship_symbol) {
+                    o_debug!("⏸️ {} is paused - skipping assignment", ship_symbol);
+                    continue;
+                }
                 if let Some(ship) = ships.iter().find(|s| s.symbol == ship_symbol) {
                     if let Some(metrics) = self.fleet_metrics.iter().find(|m| m.ship_symbol == ship_symbol) {
                         let recommended_task = self.prioritizer.recommend_optimal_task(metrics, contract);
@@ -379,15 +588,30 @@ impl FleetCoordinator {
                                 o_info!("🔭 {} is a probe - assigning exploration", ship_symbol);
                                 self.assign_exploration_task(&ship).await?;
                             }
+                        } else if let Some(visit) = self.plan_repair_visit(ship) {
+                            o_info!("🔧 {} is wearing down - repairing at {} ({:.0} units off route)", ship_symbol, visit.shipyard, visit.detour);
+                            self.send_action_to_ship(&ship.symbol, ShipAction::Repair { shipyard: visit.shipyard }).await?;
                         } else if self.needs_refuel(&ship) {
                             o_info!("⛽ {} needs fuel ({}/{})", ship_symbol, ship.fuel.current, ship.fuel.capacity);
                             self.assign_refuel_task(&ship).await?;
+                        } else if let Some(role) = get_control().forced_role(&ship_symbol) {
+                            self.assign_forced_role(ship, role, contract, &needed_materials).await?;
+                        } else if let Some(task) = self.mining_station.as_ref().and_then(|station| station.task_for(ship)) {
+                            self.assign_station_task(ship, task, contract, &needed_materials).await?;
                         } else if self.should_deliver_cargo(&ship, contract) {
                             o_info!("📦 {} ready for delivery - assigning cargo delivery", ship_symbol);
                             self.assign_delivery_task(&ship, contract).await?;
                         } else if self.is_cargo_full(&ship) {
                             o_info!("🗃️ {} cargo full - need to manage inventory", ship_symbol);
                             self.assign_cargo_management(&ship, contract).await?;
+                        } else if metrics.capabilities.can_siphon {
+                            if self.should_refuel_before_mining(ship) {
+                                o_info!("⛽ {} needs fuel before siphoning ({}/{})", ship_symbol, ship.fuel.current, ship.fuel.capacity);
+                                self.assign_refuel_task(ship).await?;
+                            } else {
+                                o_info!("🪐 {} assigned to siphoning (priority: {:.2})", ship_symbol, metrics.priority_weight);
+                                self.assign_siphon_task(ship, &needed_materials, &contract.id).await?;
+                            }
                         } else if metrics.capabilities.can_mine && (metrics.contract_contribution >= 0.01 || ship.registration.role.contains("MINER") || ship.registration.role.contains("EXCAVATOR")) {
                             // Check if ship needs fuel before mining (more proactive than general refuel check)
                             if self.should_refuel_before_mining(&ship) {
@@ -395,7 +619,15 @@ impl FleetCoordinator {
                                 self.assign_refuel_task(&ship).await?;
                             } else {
                                 o_info!("⛏️ {} assigned to mining (priority: {:.2})", ship_symbol, metrics.priority_weight);
-                                self.assign_mining_task(&ship, &needed_materials, &contract.id).await?;
+                                let target = self.assign_mining_task(&ship, &needed_materials, &contract.id).await?;
+                                if let Some(asteroid) = target
+                                    && self.mining_station.is_none()
+                                    && MiningStation::fleet_supports_station(&ships) {
+                                    o_info!("🏗️ Establishing mining station at {} - haulers will ferry cargo", asteroid);
+                                    let mut station = MiningStation::new(&asteroid, self.config.fleet.mining_station_haulers);
+                                    station.refresh(&ships, &station_busy);
+                                    self.mining_station = Some(station);
+                                }
                             }
                         } else if metrics.capabilities.can_trade {
                             o_info!("🏪 {} assigned to support operations (trading ready)", ship_symbol);
@@ -408,6 +640,138 @@ impl FleetCoordinator {
         Ok(())
     }
 
+    /// Work for a ship the operator pinned to a role; repairs and refuelling still come first
+    async fn assign_forced_role(&mut self, ship: &Ship, role: ForcedRole, contract: &Contract, needed_materials: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+        match role {
+            ForcedRole::Miner | ForcedRole::Siphoner if self.is_cargo_full(ship) => {
+                o_info!("🗃️ {} cargo full - need to manage inventory", ship.symbol);
+                self.assign_cargo_management(ship, contract).await
+            }
+            ForcedRole::Miner | ForcedRole::Siphoner if self.should_refuel_before_mining(ship) => {
+                o_info!("⛽ {} needs fuel before extracting ({}/{})", ship.symbol, ship.fuel.current, ship.fuel.capacity);
+                self.assign_refuel_task(ship).await
+            }
+            ForcedRole::Miner => {
+                o_info!("⛏️ {} forced into mining", ship.symbol);
+                self.assign_mining_task(ship, needed_materials, &contract.id).await.map(|_| ())
+            }
+            ForcedRole::Siphoner => {
+                o_info!("🪐 {} forced into siphoning", ship.symbol);
+                self.assign_siphon_task(ship, needed_materials, &contract.id).await
+            }
+            ForcedRole::Hauler if self.should_deliver_cargo(ship, contract) => {
+                o_info!("📦 {} hauling contract cargo", ship.symbol);
+                self.assign_delivery_task(ship, contract).await
+            }
+            ForcedRole::Hauler if ship.cargo.units > 0 => {
+                o_info!("🚛 {} hauling cargo to market", ship.symbol);
+                self.assign_cargo_management(ship, contract).await
+            }
+            ForcedRole::Hauler => {
+                o_debug!("🚛 {} is a hauler with an empty hold - waiting", ship.symbol);
+                Ok(())
+            }
+            ForcedRole::Explorer => {
+                o_info!("🔭 {} forced into exploration", ship.symbol);
+                self.assign_exploration_task(ship).await
+            }
+        }
+    }
+
+    /// Look up shipyards in every system where a ship is due for repairs
+    async fn locate_repair_shipyards(&mut self, ships: &[Ship]) {
+        let mut systems: Vec<String> = ships.iter()
+            .filter(|ship| self.maintenance.repair_due(ship, get_condition_history()))
+            .map(|ship| ship.nav.system_symbol.clone())
+            .filter(|system| !self.maintenance.knows_system(system))
+            .collect();
+        systems.sort();
+        systems.dedup();
+        for system_symbol in systems {
+            match self.client.get_system_waypoints_with_traits(&system_symbol, "SHIPYARD").await {
+                Ok(shipyards) => self.maintenance.record_shipyards(&system_symbol, &shipyards),
+                Err(e) => o_error!("⚠️ Could not find shipyards in {} for repairs: {}", system_symbol, e),
+            }
+        }
+    }
+
+    /// A shipyard visit for ships with a component at or nearing the repair threshold
+    fn plan_repair_visit(&self, ship: &Ship) -> Option<RepairVisit> {
+        if !self.maintenance.repair_due(ship, get_condition_history()) {
+            return None;
+        }
+        let visit = self.maintenance.plan_visit(ship);
+        if visit.is_none() {
+            o_debug!("🔧 {} needs repairs but no shipyard is known in {}", ship.symbol, ship.nav.system_symbol);
+        }
+        visit
+    }
+
+    /// Ships a mining station must not recruit: pinned to a role, delivering, trading or holding contract goods
+    fn ships_busy_outside_station(&self, ships: &[Ship], contract: &Contract, needed_materials: &[String]) -> Vec<String> {
+        ships.iter()
+            .filter(|ship| {
+                let action = self.ship_states.get(&ship.symbol).and_then(|state| state.current_action.as_ref());
+                get_control().forced_role(&ship.symbol).is_some()
+                    || matches!(action, Some(ShipAction::DeliverCargo { .. } | ShipAction::Trade { .. }))
+                    || (contract.accepted && self.has_contract_materials(ship, needed_materials))
+            })
+            .map(|ship| ship.symbol.clone())
+            .collect()
+    }
+
+    /// Carry out a mining station task; miners stay on the asteroid while haulers ferry cargo
+    async fn assign_station_task(&mut self, ship: &Ship, task: StationTask, contract: &Contract, needed_materials: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(asteroid) = self.mining_station.as_ref().map(|station| station.asteroid.clone()) else {
+            return Ok(());
+        };
+        
+        match task {
+            StationTask::Mine => {
+                if self.should_refuel_before_mining(ship) {
+                    o_info!("⛽ {} needs fuel before mining ({}/{})", ship.symbol, ship.fuel.current, ship.fuel.capacity);
+                    return self.assign_refuel_task(ship).await;
+                }
+                o_info!("⛏️ {} mining on station at {}", ship.symbol, asteroid);
+                let action = ShipAction::Mine {
+                    target: asteroid,
+                    needed_materials: needed_materials.to_vec(),
+                    contract_id: contract.id.clone(),
+                };
+                self.send_action_to_ship(&ship.symbol, action).await
+            }
+            StationTask::Offload { hauler } => {
+                o_info!("🔄 {} offloading {}/{} units to {}", ship.symbol, ship.cargo.units, ship.cargo.capacity, hauler);
+                // The hauler's hold changes without it acting, so its cached state is stale
+                if let Err(e) = self.ship_cache.mark_ship_action(&hauler, &format!("Receiving cargo from {}", ship.symbol)) {
+                    o_error!("⚠️ Failed to mark {} as stale: {}", hauler, e);
+                }
+                self.send_action_to_ship(&ship.symbol, ShipAction::TransferCargo { to_ship: hauler }).await
+            }
+            StationTask::SelfHaul => {
+                o_info!("🗃️ {} cargo full with no hauler on station - managing its own cargo", ship.symbol);
+                self.assign_cargo_management(ship, contract).await
+            }
+            StationTask::Load => {
+                if ship.nav.status == "DOCKED" {
+                    // Miners transfer from orbit
+                    self.send_action_to_ship(&ship.symbol, ShipAction::Orbit).await
+                } else {
+                    o_debug!("🚛 {} parked at {} ({}/{} loaded)", ship.symbol, asteroid, ship.cargo.units, ship.cargo.capacity);
+                    Ok(())
+                }
+            }
+            StationTask::Depart => {
+                o_info!("🚛 {} leaving station with {}/{} units", ship.symbol, ship.cargo.units, ship.cargo.capacity);
+                self.assign_cargo_management(ship, contract).await
+            }
+            StationTask::Return => {
+                o_info!("🚛 {} returning to mining station {}", ship.symbol, asteroid);
+                self.send_action_to_ship(&ship.symbol, ShipAction::Navigate { destination: asteroid }).await
+            }
+        }
+    }
+
     fn _can_mine(&self, ship: &Ship) -> bool {
         ship.mounts.iter().any(|mount| {
             mount.symbol.contains("MINING") || mount.symbol.contains("EXTRACTOR")
@@ -473,7 +837,8 @@ impl FleetCoordinator {
         }
     }
 
-    async fn assign_mining_task(&mut self, ship: &Ship, needed_materials: &[String], contract_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+    /// Send a ship to mine at the best asteroid for the materials, returning the asteroid chosen
+    async fn assign_mining_task(&mut self, ship: &Ship, needed_materials: &[String], contract_id: &str) -> Result<Option<String>, Box<dyn std::error::Error>> {
         // Find suitable mining location using cached waypoints
         let system_symbol = ship.nav.waypoint_symbol.split('-').take(2).collect::<Vec<&str>>().join("-");
         let client_for_nav = self.client.clone(); // Clone before mutable borrow
@@ -547,11 +912,8 @@ impl FleetCoordinator {
         }
         
         // Sort by preference: Engineered asteroids with marketplaces first, then others
-        fuel_safe_asteroids.sort_by(|a, b| {
-            let a_score = Self::calculate_mining_preference_score(a);
-            let b_score = Self::calculate_mining_preference_score(b);
-            b_score.cmp(&a_score) // Descending order (higher score first)
-        });
+        // Descending order (higher score first)
+        fuel_safe_asteroids.sort_by_cached_key(|asteroid| std::cmp::Reverse(Self::calculate_mining_preference_score(asteroid)));
         
         if let Some(target) = fuel_safe_asteroids.first() {
             let deposit_types: Vec<String> = target.traits.iter()
@@ -562,6 +924,7 @@ impl FleetCoordinator {
             let score = Self::calculate_mining_preference_score(target);
             let has_marketplace = target.traits.iter().any(|t| t.symbol == "MARKETPLACE");
             let has_fuel = target.traits.iter().any(|t| t.symbol == "FUEL_STATION");
+            let proven_rate = get_extraction_log().proven_yield_rate(&target.symbol);
             
             o_info!("⛏️ Assigning {} to mine at {} ({})", ship.symbol, target.symbol, target.waypoint_type);
             o_debug!("   🎯 REASON: Need {:?} → requires {} → this asteroid has it", needed_materials, needed_deposit_trait);
@@ -569,7 +932,10 @@ impl FleetCoordinator {
             o_debug!("   📊 Selection score: {} {}{}", score, 
                     if has_marketplace { "🏪" } else { "" },
                     if has_fuel { "⛽" } else { "" });
-            if score > 0 {
+            if let Some(rate) = proven_rate {
+                o_debug!("   📈 Proven yield: {:.3} units per cooldown-second", rate);
+            }
+            if score > 0 && proven_rate.is_none() {
                 o_debug!("   ⭐ PRIORITY: {}", 
                     if score >= 1100 { "Engineered asteroid with marketplace - optimal!" }
                     else if score >= 200 { "Has fuel station - convenient refueling" }
@@ -583,7 +949,9 @@ impl FleetCoordinator {
                 contract_id: contract_id.to_string(),
             };
             
+            let target_symbol = target.symbol.clone();
             self.send_action_to_ship(&ship.symbol, mining_action).await?;
+            return Ok(Some(target_symbol));
         } else {
             let total_fuel_safe = fuel_safe_asteroids.len();
             o_error!("⚠️ No fuel-safe mining locations found for {} (need deposit type: {})", ship.symbol, needed_deposit_trait);
@@ -600,7 +968,25 @@ impl FleetCoordinator {
             }
         }
         
-        Ok(())
+        Ok(None)
+    }
+    
+    /// Send a siphon ship to the nearest gas giant in its system
+    async fn assign_siphon_task(&mut self, ship: &Ship, needed_materials: &[String], contract_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let waypoints = self.get_system_waypoints_cached(&ship.nav.system_symbol).await?;
+        let Some(gas_giant) = SiphonOperations::nearest_gas_giant(waypoints, ship.nav.route.destination.x, ship.nav.route.destination.y) else {
+            o_error!("⚠️ No gas giant found in {} for {}", ship.nav.system_symbol, ship.symbol);
+            return Ok(());
+        };
+        let target = gas_giant.symbol.clone();
+        
+        o_info!("🪐 Assigning {} to siphon at {}", ship.symbol, target);
+        let siphon_action = ShipAction::Siphon {
+            target,
+            needed_materials: needed_materials.to_vec(),
+            contract_id: contract_id.to_string(),
+        };
+        self.send_action_to_ship(&ship.symbol, siphon_action).await
     }
     
     /// Determine what deposit type is needed based on the materials we're looking for
@@ -642,6 +1028,14 @@ impl FleetCoordinator {
     fn calculate_mining_preference_score(waypoint: &Waypoint) -> i32 {
         let mut score = 0;
         
+        // Fields that have proven productive outrank anything traits can suggest;
+        // the best field seen so far earns the full bonus
+        let extraction_log = get_extraction_log();
+        if let (Some(rate), Some(best_rate)) = (extraction_log.proven_yield_rate(&waypoint.symbol), extraction_log.best_proven_yield_rate())
+            && best_rate > 0.0 {
+            score += (rate / best_rate * 2000.0) as i32;
+        }
+        
         // Prefer engineered asteroids (usually better yields)
         if waypoint.waypoint_type == "ENGINEERED_ASTEROID" {
             score += 100;
@@ -708,12 +1102,19 @@ impl FleetCoordinator {
     }
 
     async fn send_action_to_ship(&mut self, ship_symbol: &str, action: ShipAction) -> Result<(), Box<dyn std::error::Error>> {
+        self.queue_action(ship_symbol, action, false).await.map(|_| ())
+    }
+
+    /// Queue an action behind the ship's pending work, or with `urgent` stop whatever it is doing and run this next
+    pub async fn queue_action(&mut self, ship_symbol: &str, action: ShipAction, urgent: bool) -> Result<ActionId, Box<dyn std::error::Error>> {
         // Create a task plan for the action
+        let mut plan_steps = Vec::new();
         if let Some(ship_state) = self.ship_states.get(ship_symbol) {
             match self.task_planner.create_plan(&action, &ship_state.ship).await {
                 Ok(plan) => {
                     o_debug!("📋 {} task plan: {} steps, {} fuel needed", 
                             ship_symbol, plan.steps.len(), plan.estimated_fuel_required);
+                    plan_steps = plan.steps.clone();
                     
                     // Update ship state with the plan
                     if let Some(state) = self.ship_states.get_mut(ship_symbol) {
@@ -732,18 +1133,59 @@ impl FleetCoordinator {
             }
         }
         
-        if let Some(sender) = self.ship_queues.get(ship_symbol) {
-            // Mark ship state as stale since we're sending it an action
-            let action_description = format!("{:?}", action);
-            if let Err(e) = self.ship_cache.mark_ship_action(ship_symbol, &action_description) {
-                o_error!("⚠️ Failed to mark ship as stale: {}", e);
-            }
-            
-            sender.send(action)
-                .map_err(|e| format!("Failed to send action to {}: {}", ship_symbol, e))?;
-        } else {
+        self.dispatch_mission(ship_symbol, ShipMission::new(0, action, plan_steps), urgent)
+    }
+
+    /// Send a mission's action to the ship under a new action ID and store the mission with it. Resumed
+    /// missions keep their plan and the steps already done.
+    fn dispatch_mission(&mut self, ship_symbol: &str, mut mission: ShipMission, urgent: bool) -> Result<ActionId, Box<dyn std::error::Error>> {
+        if !self.ship_queues.contains_key(ship_symbol) {
             return Err(format!("No action queue for ship {}", ship_symbol).into());
         }
+        let action = mission.action.clone();
+        if let Some(state) = self.ship_states.get_mut(ship_symbol)
+            && state.current_action.is_none() {
+            state.current_action = Some(action.clone());
+        }
+        
+        // Mark ship state as stale since we're sending it an action
+        let action_description = format!("{:?}", action);
+        if let Err(e) = self.ship_cache.mark_ship_action(ship_symbol, &action_description) {
+            o_error!("⚠️ Failed to mark ship as stale: {}", e);
+        }
+        
+        let action_id = self.next_action_id;
+        self.next_action_id += 1;
+        let command = if urgent {
+            o_info!("⚡ {} preempted with #{}: {:?}", ship_symbol, action_id, action);
+            ShipCommand::Preempt { id: action_id, action: action.clone() }
+        } else {
+            ShipCommand::Enqueue { id: action_id, action: action.clone() }
+        };
+        self.send_command(ship_symbol, command)?;
+        mission.action_id = action_id;
+        if let Err(e) = self.ship_cache.add_mission(ship_symbol, mission, urgent) {
+            o_error!("⚠️ Failed to save mission for {}: {}", ship_symbol, e);
+        }
+        self.in_flight.insert(action_id, (ship_symbol.to_string(), action));
+        Ok(action_id)
+    }
+
+    /// Stop the action a ship is running; its queued actions carry on
+    pub fn cancel_current_action(&self, ship_symbol: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self.send_command(ship_symbol, ShipCommand::CancelCurrent)
+    }
+
+    /// Drop every action waiting in a ship's queue
+    pub fn clear_action_queue(&self, ship_symbol: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self.send_command(ship_symbol, ShipCommand::ClearQueue)
+    }
+
+    fn send_command(&self, ship_symbol: &str, command: ShipCommand) -> Result<(), Box<dyn std::error::Error>> {
+        let sender = self.ship_queues.get(ship_symbol)
+            .ok_or_else(|| format!("No action queue for ship {}", ship_symbol))?;
+        sender.send(command)
+            .map_err(|e| format!("Failed to send command to {}: {}", ship_symbol, e))?;
         Ok(())
     }
 
@@ -934,6 +1376,10 @@ impl FleetCoordinator {
             .iter()
             .map(|d| d.trade_symbol.clone())
             .collect();
+        // Goods a stored mission is holding on to are never sold
+        let reserved_goods = self.ship_cache.reserved_goods(&ship.symbol);
+        let mut kept_goods = contract_materials.clone();
+        kept_goods.extend(reserved_goods.iter().cloned());
 
         // Categorize cargo
         let mut contract_items = Vec::new();
@@ -943,6 +1389,8 @@ impl FleetCoordinator {
             if contract_materials.contains(&item.symbol) {
                 contract_items.push(item);
                 o_debug!("   🎯 Contract: {} x{}", item.symbol, item.units);
+            } else if reserved_goods.contains(&item.symbol) {
+                o_debug!("   🔒 Reserved: {} x{}", item.symbol, item.units);
             } else {
                 sellable_items.push(item);
                 o_debug!("   💰 Sellable: {} x{}", item.symbol, item.units);
@@ -961,7 +1409,7 @@ impl FleetCoordinator {
         if !sellable_items.is_empty() {
             // Try to sell non-contract items to make room
             o_info!("💰 {} attempting to sell non-contract cargo", ship.symbol);
-            self.assign_smart_sell_or_jettison(ship, &sellable_items, &contract_materials).await
+            self.assign_smart_sell_or_jettison(ship, &sellable_items, &kept_goods).await
         } else if !contract_items.is_empty() {
             // Only contract items - deliver them
             o_info!("📦 {} only has contract items - delivering", ship.symbol);
@@ -973,7 +1421,7 @@ impl FleetCoordinator {
                 .iter()
                 .map(|d| d.trade_symbol.clone())
                 .collect();
-            self.assign_mining_task(ship, &needed_materials, &contract.id).await
+            self.assign_mining_task(ship, &needed_materials, &contract.id).await.map(|_| ())
         }
     }
     
@@ -1275,8 +1723,8 @@ impl FleetCoordinator {
         Ok(())
     }
     
-    /// Check if we should purchase additional ships for fleet expansion
-    async fn check_ship_expansion(&mut self, contract: &Contract) -> Result<(), Box<dyn std::error::Error>> {
+    /// Buy the ship the fleet planner expects to pay for itself soonest, within the credit reserve
+    async fn check_ship_expansion(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         // Only check for expansion periodically to avoid spam
         use std::sync::Mutex;
         use std::time::{Instant, Duration};
@@ -1293,130 +1741,88 @@ impl FleetCoordinator {
             *last_check = Some(Instant::now());
         }
         
+        if !self.config.fleet.auto_purchase_ships {
+            return Ok(());
+        }
+        
         o_info!("🏗️ Checking fleet expansion opportunities...");
         
-        // Get current agent info to check credits
         let agent = self.client.get_agent().await?;
+        if agent.credits < self.config.fleet.min_credits_for_ship_purchase {
+            o_debug!("💸 Need {} credits before buying ships (have {})", self.config.fleet.min_credits_for_ship_purchase, agent.credits);
+            return Ok(());
+        }
         let current_ships = self.client.get_ships().await?;
         
-        // Find our mining ships
-        let mining_ships: Vec<_> = current_ships.iter()
-            .filter(|s| s.mounts.iter().any(|m| m.symbol.contains("MINING")))
-            .collect();
-        
-        o_debug!("📊 Current fleet: {} ships ({} miners)", current_ships.len(), mining_ships.len());
-        o_debug!("💰 Available credits: {}", agent.credits);
-        
-        // Check if we should buy another mining ship
-        let should_expand = self.should_expand_fleet(&agent, &current_ships, contract).await;
-        
-        if should_expand && agent.credits >= self.config.fleet.min_credits_for_ship_purchase {
-            o_info!("🎯 Fleet expansion recommended - searching for shipyards...");
-            
-            // Try to find and purchase a ship
-            match self.attempt_ship_purchase(&agent, &mining_ships).await {
-                Ok(new_ship) => {
-                    o_summary!("🎉 Successfully purchased new ship: {}", new_ship.symbol);
-                    o_info!("   🚢 Type: {} Frame: {}", new_ship.registration.role, new_ship.frame.symbol);
-                    o_info!("   ⛏️ Ready for mining operations!");
-                    
-                    // CRITICAL: Add the new ship to the fleet by spawning its actor
-                    if let Err(e) = self.spawn_ship_actor(new_ship.clone()).await {
-                        o_error!("⚠️ Failed to add new ship to fleet: {}", e);
-                        o_info!("   💡 Ship purchased but won't be active until next restart");
-                    } else {
-                        o_summary!("✅ New ship {} added to active fleet management", new_ship.symbol);
-                        // Cache the new ship state
-                        if let Err(e) = self.ship_cache.cache_ship(new_ship) {
-                            o_error!("⚠️ Failed to cache new ship state: {}", e);
-                        }
-                    }
-                }
-                Err(e) => {
-                    o_error!("⚠️ Ship purchase failed: {}", e);
+        let mut systems: Vec<String> = current_ships.iter().map(|ship| ship.nav.system_symbol.clone()).collect();
+        systems.sort();
+        systems.dedup();
+        
+        let mut shipyards = Vec::new();
+        let mut marketplaces = 0;
+        for system in &systems {
+            marketplaces += self.client.get_system_waypoints_with_traits(system, "MARKETPLACE").await
+                .map(|waypoints| waypoints.len())
+                .unwrap_or(0);
+            // Ships can only be bought where one of ours is docked
+            let shipyard_waypoints = self.client.get_system_waypoints_with_traits(system, "SHIPYARD").await.unwrap_or_default();
+            for waypoint in shipyard_waypoints.iter().filter(|waypoint| current_ships.iter()
+                .any(|ship| ship.nav.waypoint_symbol == waypoint.symbol && ship.nav.status == "DOCKED")) {
+                match self.client.get_shipyard(system, &waypoint.symbol).await {
+                    Ok(shipyard) => shipyards.push(shipyard),
+                    Err(e) => o_debug!("⚠️ Could not read shipyard {}: {}", waypoint.symbol, e),
                 }
             }
-        } else if should_expand {
-            let needed = self.config.fleet.min_credits_for_ship_purchase - agent.credits;
-            o_info!("💸 Want to expand fleet but need {} more credits", needed);
         }
         
-        Ok(())
-    }
-    
-    /// Determine if we should expand the fleet
-    async fn should_expand_fleet(&self, agent: &crate::models::Agent, ships: &[crate::models::Ship], contract: &Contract) -> bool {
-        // Expansion criteria:
-        // 1. Have enough credits (150k+)
-        // 2. Contract is large enough to benefit from more ships
-        // 3. Don't have too many ships already
-        
-        let mining_ships = ships.iter().filter(|s| s.mounts.iter().any(|m| m.symbol.contains("MINING"))).count();
-        
-        // Calculate contract workload
-        let total_contract_units: i32 = contract.terms.deliver.iter().map(|d| d.units_required).sum();
-        let contract_value = contract.terms.payment.on_fulfilled;
-        
-        // Expansion logic (using configurable thresholds)
-        let has_credits = agent.credits >= self.config.fleet.min_credits_for_ship_purchase;
-        let contract_is_large = total_contract_units >= self.config.contracts.large_contract_units || contract_value >= self.config.credits.large_contract_threshold;
-        let not_too_many_ships = mining_ships < self.config.fleet.max_mining_ships;
-        let profitable = contract_value > self.config.credits.min_profitable_contract;
-        
-        if has_credits && contract_is_large && not_too_many_ships && profitable {
-            o_info!("✅ Fleet expansion criteria met:");
-            o_info!("   💰 Credits: {} >= {}", agent.credits, self.config.fleet.min_credits_for_ship_purchase);
-            o_info!("   📦 Contract size: {} units", total_contract_units);
-            o_info!("   💎 Contract value: {} credits", contract_value);
-            o_info!("   🚢 Current miners: {}/{}", mining_ships, self.config.fleet.max_mining_ships);
-            true
-        } else {
-            o_debug!("❌ Fleet expansion not recommended:");
-            if !has_credits { o_debug!("   💸 Need more credits ({} < {})", agent.credits, self.config.fleet.min_credits_for_ship_purchase); }
-            if !contract_is_large { o_debug!("   📦 Contract too small ({} units, {} value)", total_contract_units, contract_value); }
-            if !not_too_many_ships { o_debug!("   🚢 Already have enough miners ({})", mining_ships); }
-            if !profitable { o_debug!("   💎 Contract not profitable enough ({} < {})", contract_value, self.config.credits.min_profitable_contract); }
-            false
-        }
-    }
-    
-    /// Attempt to purchase a mining ship
-    async fn attempt_ship_purchase(&self, _agent: &crate::models::Agent, reference_ships: &[&crate::models::Ship]) -> Result<crate::models::Ship, Box<dyn std::error::Error>> {
-        // Use our shipyard operations system
-        let shipyard_ops = crate::operations::ShipyardOperations::new(self.client.clone());
+        let survey_pool = get_survey_pool();
+        let bottleneck = detect_bottleneck(&current_ships, survey_pool.known_markets(), marketplaces);
+        let planner = FleetPlanner::measure(&current_ships, get_income_ledger(), get_extraction_log(),
+            |good| survey_pool.good_value(good), bottleneck, self.config.fleet.max_mining_ships);
+        let budget = purchase_budget(agent.credits, self.config.credits.min_reserve_credits);
         
-        // Find shipyards
-        let shipyards = shipyard_ops.find_shipyards().await?;
+        o_debug!("📊 Current fleet: {} ships, bottleneck {:?}", current_ships.len(), bottleneck);
+        o_debug!("💰 Available credits: {} ({} above reserve)", agent.credits, budget);
         
-        if shipyards.is_empty() {
-            return Err("No shipyards found - need to explore more systems".into());
+        for shipyard in &mut shipyards {
+            let symbol = shipyard.symbol.clone();
+            if let Some(listings) = shipyard.ships.as_mut() {
+                listings.retain(|listing| !self.failed_purchases.contains(&(listing.ship_type.clone(), symbol.clone())));
+            }
         }
+        let Some(option) = planner.recommend(&shipyards, budget) else {
+            o_info!("💡 No ship at {} shipyards with a docked ship pays back within a {} credit budget", shipyards.len(), budget);
+            return Ok(());
+        };
         
-        // Get reference mining ship configuration
-        let reference_ship = reference_ships.first()
-            .ok_or("No reference mining ship available")?;
+        o_info!("🎯 Buying {} ({:?}) at {} for {} credits - ~{:.0} credits/hour, pays back in {:.1}h",
+               option.ship_type, option.class, option.shipyard, option.price, option.income_per_hour, option.payback_hours);
         
-        // Try each shipyard until we find one with suitable ships
-        for shipyard in shipyards {
-            o_info!("🏭 Checking shipyard at {}", shipyard.waypoint_symbol);
-            
-            match shipyard_ops.purchase_mining_ship(&shipyard, reference_ship).await {
-                Ok(new_ship) => {
-                    // Attempt to outfit the ship (may not have all required APIs yet)
-                    if let Err(e) = shipyard_ops.outfit_mining_ship(&new_ship, reference_ship).await {
-                        o_info!("⚠️ Ship outfitting incomplete: {}", e);
-                        o_info!("   💡 Ship can still be used for basic mining");
+        match self.client.purchase_ship(&option.ship_type, &option.shipyard).await {
+            Ok(purchase) => {
+                let new_ship = purchase.ship;
+                o_summary!("🎉 Successfully purchased new ship: {}", new_ship.symbol);
+                o_info!("   💰 Remaining credits: {}", purchase.agent.credits);
+                
+                // CRITICAL: Add the new ship to the fleet by spawning its actor
+                if let Err(e) = self.spawn_ship_actor(new_ship.clone()).await {
+                    o_error!("⚠️ Failed to add new ship to fleet: {}", e);
+                    o_info!("   💡 Ship purchased but won't be active until next restart");
+                } else {
+                    o_summary!("✅ New ship {} added to active fleet management", new_ship.symbol);
+                    // Cache the new ship state
+                    if let Err(e) = self.ship_cache.cache_ship(new_ship) {
+                        o_error!("⚠️ Failed to cache new ship state: {}", e);
                     }
-                    
-                    return Ok(new_ship);
-                }
-                Err(e) => {
-                    o_error!("   ❌ Purchase failed at {}: {}", shipyard.waypoint_symbol, e);
                 }
             }
+            Err(e) => {
+                o_error!("⚠️ Ship purchase failed: {} - not retrying {} at {} this run", e, option.ship_type, option.shipyard);
+                self.failed_purchases.push((option.ship_type, option.shipyard));
+            }
         }
         
-        Err("No suitable ships found at any available shipyard".into())
+        Ok(())
     }
     
 }
\ No newline at end of file
@@ -0,0 +1,224 @@
+// Fleet composition planner - ranks purchasable ships by payback time from measured fleet income
+use std::collections::HashMap;
+use chrono::Duration;
+use crate::models::{Ship, Shipyard, ShipyardShip};
+use crate::operations::{has_refinery, has_siphon_capability};
+use crate::storage::{ExtractionLog, IncomeLedger};
+
+/// How far back sales and extractions count towards a ship's income
+const INCOME_WINDOW_HOURS: i64 = 24;
+/// Miners this full are waiting on a hauler rather than on their lasers
+const FULL_HOLD_RATIO: f64 = 0.9;
+/// Below this share of marketplaces with known prices, selling is guesswork
+const MIN_MARKET_COVERAGE: f64 = 0.5;
+/// Ships that relieve the current bottleneck are worth this much more
+const BOTTLENECK_BONUS: f64 = 1.5;
+/// Classes with no ship to measure are assumed to earn this share of the fleet average
+const UNMEASURED_DISCOUNT: f64 = 0.75;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShipClass {
+    Miner,
+    Hauler,
+    Probe,
+    Refinery,
+    Siphoner,
+}
+
+impl ShipClass {
+    pub fn of_ship(ship: &Ship) -> Self {
+        if has_refinery(ship) {
+            ShipClass::Refinery
+        } else if has_siphon_capability(ship) {
+            ShipClass::Siphoner
+        } else if ship.mounts.iter().any(|mount| mount.symbol.contains("MINING") || mount.symbol.contains("EXTRACTOR")) {
+            ShipClass::Miner
+        } else if ship.registration.role == "SATELLITE" || ship.frame.symbol.contains("PROBE") || ship.cargo.capacity == 0 {
+            ShipClass::Probe
+        } else {
+            ShipClass::Hauler
+        }
+    }
+
+    /// Class of a ship as listed for sale, from its fitted parts and then its type name
+    pub fn of_listing(listing: &ShipyardShip) -> Self {
+        if listing.modules.iter().any(|module| module.symbol.contains("REFINERY")) || listing.ship_type.contains("REFINING") {
+            ShipClass::Refinery
+        } else if listing.mounts.iter().any(|mount| mount.symbol.contains("GAS_SIPHON")) || listing.ship_type.contains("SIPHON") {
+            ShipClass::Siphoner
+        } else if listing.mounts.iter().any(|mount| mount.symbol.contains("MINING")) || listing.ship_type.contains("MINING") {
+            ShipClass::Miner
+        } else if listing.ship_type.contains("PROBE") || listing.frame.symbol.contains("PROBE") {
+            ShipClass::Probe
+        } else {
+            ShipClass::Hauler
+        }
+    }
+
+    /// Extracting ships count against `max_mining_ships`
+    pub fn is_extractor(&self) -> bool {
+        matches!(self, ShipClass::Miner | ShipClass::Siphoner)
+    }
+}
+
+/// What is currently limiting fleet income
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bottleneck {
+    Extraction,
+    Hauling,
+    MarketCoverage,
+}
+
+impl Bottleneck {
+    pub fn relieved_by(&self) -> &'static [ShipClass] {
+        match self {
+            Bottleneck::Extraction => &[ShipClass::Miner, ShipClass::Siphoner],
+            Bottleneck::Hauling => &[ShipClass::Hauler, ShipClass::Refinery],
+            Bottleneck::MarketCoverage => &[ShipClass::Probe],
+        }
+    }
+}
+
+/// Too few markets priced beats everything else; otherwise miners sitting on full holds
+/// point at hauling, and anything else means more extraction pays
+pub fn detect_bottleneck(ships: &[Ship], known_markets: usize, marketplaces: usize) -> Bottleneck {
+    if marketplaces > 0 && (known_markets as f64 / marketplaces as f64) < MIN_MARKET_COVERAGE {
+        return Bottleneck::MarketCoverage;
+    }
+
+    let extractors: Vec<&Ship> = ships.iter().filter(|ship| ShipClass::of_ship(ship).is_extractor()).collect();
+    let full = extractors.iter()
+        .filter(|ship| ship.cargo.capacity > 0 && ship.cargo.units as f64 >= ship.cargo.capacity as f64 * FULL_HOLD_RATIO)
+        .count();
+    if !extractors.is_empty() && full * 2 >= extractors.len() {
+        Bottleneck::Hauling
+    } else {
+        Bottleneck::Extraction
+    }
+}
+
+/// Credits that can go on ships once the reserve is kept back
+pub fn purchase_budget(credits: i64, reserve: i64) -> i64 {
+    (credits - reserve).max(0)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShipOption {
+    pub shipyard: String,
+    pub ship_type: String,
+    pub class: ShipClass,
+    pub price: i64,
+    pub income_per_hour: f64,
+    pub payback_hours: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct FleetPlanner {
+    bottleneck: Bottleneck,
+    class_income: HashMap<ShipClass, f64>,
+    fleet_average: Option<f64>,
+    class_counts: HashMap<ShipClass, usize>,
+    max_extractors: usize,
+}
+
+impl FleetPlanner {
+    /// Measure each ship's income as the better of its sales and the market value of what it
+    /// extracted, then average per class
+    pub fn measure(ships: &[Ship], ledger: &IncomeLedger, log: &ExtractionLog,
+                   good_value: impl Fn(&str) -> Option<f64>, bottleneck: Bottleneck, max_extractors: usize) -> Self {
+        let window = Duration::hours(INCOME_WINDOW_HOURS);
+        let since = chrono::Utc::now() - window;
+        let records = log.records();
+
+        let mut measured: HashMap<ShipClass, Vec<f64>> = HashMap::new();
+        let mut class_counts: HashMap<ShipClass, usize> = HashMap::new();
+        for ship in ships {
+            let class = ShipClass::of_ship(ship);
+            *class_counts.entry(class).or_insert(0) += 1;
+
+            let sales = ledger.credits_per_hour(&ship.symbol, window);
+            let (value, seconds) = records.iter()
+                .filter(|record| record.ship_symbol == ship.symbol && record.timestamp >= since)
+                .filter_map(|record| good_value(&record.yield_symbol)
+                    .map(|price| (record.units as f64 * price, record.cooldown_seconds)))
+                .fold((0.0, 0.0), |(value, seconds), (v, s)| (value + v, seconds + s));
+            let extracted = (seconds > 0.0).then(|| value / seconds * 3600.0);
+
+            let income = match (sales, extracted) {
+                (Some(sales), Some(extracted)) => Some(sales.max(extracted)),
+                (sales, extracted) => sales.or(extracted),
+            };
+            if let Some(income) = income {
+                measured.entry(class).or_default().push(income);
+            }
+        }
+
+        let all: Vec<f64> = measured.values().flatten().copied().collect();
+        let fleet_average = (!all.is_empty()).then(|| all.iter().sum::<f64>() / all.len() as f64);
+        let class_income = measured.into_iter()
+            .map(|(class, incomes)| (class, incomes.iter().sum::<f64>() / incomes.len() as f64))
+            .collect();
+
+        Self { bottleneck, class_income, fleet_average, class_counts, max_extractors }
+    }
+
+    pub fn bottleneck(&self) -> Bottleneck {
+        self.bottleneck
+    }
+
+    /// Expected credits per hour from one more ship of a class
+    pub fn expected_income(&self, class: ShipClass) -> Option<f64> {
+        let fleet_average = self.fleet_average?;
+        let base = match class {
+            // A probe earns nothing itself; while markets are unpriced it lets the fleet sell well
+            ShipClass::Probe if self.bottleneck == Bottleneck::MarketCoverage => fleet_average,
+            ShipClass::Probe => return None,
+            _ => self.class_income.get(&class).copied().unwrap_or(fleet_average * UNMEASURED_DISCOUNT),
+        };
+        let bonus = if self.bottleneck.relieved_by().contains(&class) { BOTTLENECK_BONUS } else { 1.0 };
+        Some(base * bonus)
+    }
+
+    fn at_capacity(&self, class: ShipClass) -> bool {
+        if !class.is_extractor() {
+            return false;
+        }
+        let extractors: usize = self.class_counts.iter()
+            .filter(|(class, _)| class.is_extractor())
+            .map(|(_, count)| count)
+            .sum();
+        extractors >= self.max_extractors
+    }
+
+    /// Every listing at a shipyard that would earn its price back, quickest payback first
+    pub fn evaluate(&self, shipyard: &Shipyard) -> Vec<ShipOption> {
+        let mut options: Vec<ShipOption> = shipyard.ships.iter().flatten()
+            .filter_map(|listing| {
+                let class = ShipClass::of_listing(listing);
+                if self.at_capacity(class) {
+                    return None;
+                }
+                let income = self.expected_income(class).filter(|income| *income > 0.0)?;
+                let price = listing.purchase_price as i64;
+                Some(ShipOption {
+                    shipyard: shipyard.symbol.clone(),
+                    ship_type: listing.ship_type.clone(),
+                    class,
+                    price,
+                    income_per_hour: income,
+                    payback_hours: price as f64 / income,
+                })
+            })
+            .collect();
+        options.sort_by(|a, b| a.payback_hours.partial_cmp(&b.payback_hours).unwrap_or(std::cmp::Ordering::Equal));
+        options
+    }
+
+    /// The affordable ship across all shipyards with the quickest payback
+    pub fn recommend(&self, shipyards: &[Shipyard], budget: i64) -> Option<ShipOption> {
+        shipyards.iter()
+            .flat_map(|shipyard| self.evaluate(shipyard))
+            .filter(|option| option.price <= budget)
+            .min_by(|a, b| a.payback_hours.partial_cmp(&b.payback_hours).unwrap_or(std::cmp::Ordering::Equal))
+    }
+}
@@ -0,0 +1,226 @@
+// Ship maintenance - forecasts wear from condition history and repairs ships at shipyards on their route
+use std::collections::HashMap;
+use crate::client::priority_client::PriorityApiClient;
+use crate::models::{Ship, Waypoint};
+use crate::operations::outfitting::travel_and_dock;
+use crate::storage::{get_condition_history, ConditionHistory, ConditionSample, ShipComponent};
+use crate::{o_info, o_summary};
+
+/// Ships are sent for repair when a component will cross the threshold within this many hours
+pub const MAINTENANCE_HORIZON_HOURS: f64 = 2.0;
+
+/// Where a component is heading, from its samples since the last repair
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConditionForecast {
+    pub component: ShipComponent,
+    pub condition: f64,
+    /// Percentage points lost per hour, None until there are two samples to compare
+    pub wear_per_hour: Option<f64>,
+    /// Hours until the repair threshold, Some(0.0) once below it, None if not wearing
+    pub hours_to_threshold: Option<f64>,
+}
+
+impl ConditionForecast {
+    /// Fit a straight line through the samples since the last repair (the last time condition rose)
+    pub fn from_samples(component: ShipComponent, samples: &[ConditionSample], threshold: f64) -> Option<Self> {
+        let repaired_at = samples.windows(2).rposition(|pair| pair[1].condition > pair[0].condition)
+            .map(|index| index + 1)
+            .unwrap_or(0);
+        let since_repair = &samples[repaired_at..];
+        let current = since_repair.last()?;
+
+        let start = since_repair[0].timestamp;
+        let points: Vec<(f64, f64)> = since_repair.iter()
+            .map(|sample| ((sample.timestamp - start).num_seconds() as f64 / 3600.0, sample.condition))
+            .collect();
+        let n = points.len() as f64;
+        let mean_t = points.iter().map(|(t, _)| t).sum::<f64>() / n;
+        let mean_c = points.iter().map(|(_, c)| c).sum::<f64>() / n;
+        let spread: f64 = points.iter().map(|(t, _)| (t - mean_t).powi(2)).sum();
+        let wear_per_hour = (spread > 0.0).then(|| {
+            let slope = points.iter().map(|(t, c)| (t - mean_t) * (c - mean_c)).sum::<f64>() / spread;
+            (-slope).max(0.0)
+        });
+
+        let hours_to_threshold = if current.condition <= threshold {
+            Some(0.0)
+        } else {
+            wear_per_hour.filter(|wear| *wear > 0.0).map(|wear| (current.condition - threshold) / wear)
+        };
+
+        Some(Self { component, condition: current.condition, wear_per_hour, hours_to_threshold })
+    }
+
+    pub fn due_within(&self, hours: f64) -> bool {
+        self.hours_to_threshold.is_some_and(|remaining| remaining <= hours)
+    }
+}
+
+/// A shipyard stop for repairs, picked for the smallest detour from the ship's route
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepairVisit {
+    pub ship_symbol: String,
+    pub shipyard: String,
+    pub detour: f64,
+}
+
+/// A ship whose repair would cost more than the ship is still worth
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScrapAdvice {
+    pub ship_symbol: String,
+    pub repair_cost: i64,
+    pub scrap_value: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MaintenanceOutcome {
+    AlreadySound,
+    Repaired { cost: i64 },
+    ScrapRecommended(ScrapAdvice),
+}
+
+#[derive(Debug, Clone)]
+pub struct MaintenanceManager {
+    repair_threshold: f64,
+    /// Shipyard positions by system
+    shipyards: HashMap<String, Vec<(String, i32, i32)>>,
+    /// Repairs priced above scrap value, with the lowest condition the ship had when priced
+    declined: HashMap<String, (ScrapAdvice, f64)>,
+}
+
+impl MaintenanceManager {
+    /// `repair_threshold` is the condition percentage ships are kept above
+    pub fn new(repair_threshold: f64) -> Self {
+        Self {
+            repair_threshold,
+            shipyards: HashMap::new(),
+            declined: HashMap::new(),
+        }
+    }
+
+    /// A new threshold is the operator's call to price declined repairs again
+    pub fn set_repair_threshold(&mut self, repair_threshold: f64) {
+        if repair_threshold != self.repair_threshold {
+            self.declined.clear();
+        }
+        self.repair_threshold = repair_threshold;
+    }
+
+    /// Stop sending a ship for repairs it is not worth, until its condition rises above where it was priced
+    pub fn decline_repair(&mut self, advice: ScrapAdvice, history: &ConditionHistory) {
+        let condition = self.lowest_condition(&advice.ship_symbol, history).unwrap_or(0.0);
+        self.declined.insert(advice.ship_symbol.clone(), (advice, condition));
+    }
+
+    pub fn declined_repair(&self, ship_symbol: &str) -> Option<&ScrapAdvice> {
+        self.declined.get(ship_symbol).map(|(advice, _)| advice)
+    }
+
+    fn lowest_condition(&self, ship_symbol: &str, history: &ConditionHistory) -> Option<f64> {
+        ShipComponent::ALL.iter()
+            .filter_map(|component| history.samples(ship_symbol, *component).last().map(|sample| sample.condition))
+            .min_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+    }
+
+    pub fn record_shipyards(&mut self, system_symbol: &str, waypoints: &[Waypoint]) {
+        let shipyards = waypoints.iter().map(|waypoint| (waypoint.symbol.clone(), waypoint.x, waypoint.y)).collect();
+        self.shipyards.insert(system_symbol.to_string(), shipyards);
+    }
+
+    pub fn knows_system(&self, system_symbol: &str) -> bool {
+        self.shipyards.contains_key(system_symbol)
+    }
+
+    pub fn forecast(&self, ship: &Ship, history: &ConditionHistory) -> Vec<ConditionForecast> {
+        ShipComponent::ALL.iter()
+            .filter_map(|component| {
+                ConditionForecast::from_samples(*component, &history.samples(&ship.symbol, *component), self.repair_threshold)
+            })
+            .collect()
+    }
+
+    pub fn repair_due(&self, ship: &Ship, history: &ConditionHistory) -> bool {
+        if let Some((_, priced_at)) = self.declined.get(&ship.symbol)
+            && self.lowest_condition(&ship.symbol, history).is_none_or(|condition| condition <= *priced_at) {
+            return false;
+        }
+        self.forecast(ship, history).iter().any(|forecast| forecast.due_within(MAINTENANCE_HORIZON_HOURS))
+    }
+
+    /// The shipyard in the ship's system closest to either end of its current route
+    pub fn plan_visit(&self, ship: &Ship) -> Option<RepairVisit> {
+        let route = &ship.nav.route;
+        let distance = |x: i32, y: i32, to_x: i32, to_y: i32| (((x - to_x).pow(2) + (y - to_y).pow(2)) as f64).sqrt();
+        self.shipyards.get(&ship.nav.system_symbol)?.iter()
+            .map(|(symbol, x, y)| {
+                let detour = distance(*x, *y, route.origin.x, route.origin.y)
+                    .min(distance(*x, *y, route.destination.x, route.destination.y));
+                RepairVisit { ship_symbol: ship.symbol.clone(), shipyard: symbol.clone(), detour }
+            })
+            .min_by(|a, b| a.detour.partial_cmp(&b.detour).unwrap_or(std::cmp::Ordering::Equal))
+    }
+
+    pub fn assess_scrap(ship_symbol: &str, repair_cost: i64, scrap_value: i64) -> Option<ScrapAdvice> {
+        (repair_cost > scrap_value).then(|| ScrapAdvice { ship_symbol: ship_symbol.to_string(), repair_cost, scrap_value })
+    }
+
+    /// Fly to the shipyard, price the repair against the ship's scrap value and repair if it is worth it.
+    /// Ships are never scrapped automatically; the advice is returned instead.
+    pub async fn repair_at(client: &PriorityApiClient, ship_symbol: &str, shipyard: &str) -> Result<MaintenanceOutcome, String> {
+        travel_and_dock(client, ship_symbol, shipyard).await?;
+
+        let repair_cost = client.get_repair_cost(ship_symbol).await.map_err(|e| e.to_string())?
+            .transaction.total_price as i64;
+        if repair_cost == 0 {
+            return Ok(MaintenanceOutcome::AlreadySound);
+        }
+        let scrap_value = client.get_scrap_value(ship_symbol).await.map_err(|e| e.to_string())?
+            .transaction.total_price as i64;
+        if let Some(advice) = Self::assess_scrap(ship_symbol, repair_cost, scrap_value) {
+            o_summary!("🗑️ {} costs {} to repair but is only worth {} - consider scrapping it", ship_symbol, repair_cost, scrap_value);
+            return Ok(MaintenanceOutcome::ScrapRecommended(advice));
+        }
+
+        let repair = client.repair_ship(ship_symbol).await.map_err(|e| e.to_string())?;
+        get_condition_history().record_ship(&repair.ship);
+        o_info!("🔧 {} repaired at {} for {} credits", ship_symbol, shipyard, repair.transaction.total_price);
+        Ok(MaintenanceOutcome::Repaired { cost: repair.transaction.total_price as i64 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    fn sample(hours_ago: i64, condition: f64) -> ConditionSample {
+        ConditionSample {
+            timestamp: Utc::now() - Duration::hours(hours_ago),
+            ship_symbol: "MINER-1".to_string(),
+            component: ShipComponent::Frame,
+            condition,
+            integrity: None,
+        }
+    }
+
+    #[test]
+    fn test_forecast_uses_wear_since_last_repair() {
+        // Worn to 60%, repaired to 100%, then losing 5 points an hour
+        let samples = vec![sample(6, 70.0), sample(5, 60.0), sample(4, 100.0), sample(2, 90.0), sample(0, 80.0)];
+        let forecast = ConditionForecast::from_samples(ShipComponent::Frame, &samples, 70.0).expect("forecast");
+        assert_eq!(forecast.condition, 80.0);
+        assert!((forecast.wear_per_hour.unwrap() - 5.0).abs() < 1e-9);
+        assert!((forecast.hours_to_threshold.unwrap() - 2.0).abs() < 1e-9);
+        assert!(forecast.due_within(2.0));
+
+        let single = ConditionForecast::from_samples(ShipComponent::Frame, &[sample(0, 65.0)], 70.0).expect("forecast");
+        assert_eq!((single.wear_per_hour, single.hours_to_threshold), (None, Some(0.0)));
+    }
+
+    #[test]
+    fn test_scrap_advice_when_repairs_cost_more_than_scrap() {
+        assert_eq!(MaintenanceManager::assess_scrap("MINER-1", 12000, 8000),
+                   Some(ScrapAdvice { ship_symbol: "MINER-1".to_string(), repair_cost: 12000, scrap_value: 8000 }));
+        assert_eq!(MaintenanceManager::assess_scrap("MINER-1", 3000, 8000), None);
+    }
+}
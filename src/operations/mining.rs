@@ -2,8 +2,8 @@
 use crate::client::SpaceTradersClient;
 use crate::{o_info};
 use crate::models::*;
+use crate::storage::{get_extraction_log, get_survey_pool};
 use tokio::time::{sleep, Duration};
-use std::collections::HashMap;
 
 pub struct MiningOperations<'a> {
     client: &'a SpaceTradersClient,
@@ -28,10 +28,8 @@ impl<'a> MiningOperations<'a> {
     }
 
     // Advanced mining operations
-    pub fn select_best_survey(&self, surveys: &[Survey], needed_materials: &[String]) -> Option<Survey> {
-        surveys.iter().find(|survey| {
-            survey.deposits.iter().any(|deposit| needed_materials.contains(&deposit.symbol))
-        }).cloned()
+    pub fn select_best_survey(&self, waypoint_symbol: &str, needed_materials: &[String]) -> Option<Survey> {
+        get_survey_pool().choose_survey(waypoint_symbol, needed_materials)
     }
 
     pub async fn find_asteroid_fields(&self, system_symbol: &str, needed_materials: &[String]) -> Result<Vec<Waypoint>, Box<dyn std::error::Error>> {
@@ -137,7 +135,7 @@ impl<'a> MiningOperations<'a> {
                     .len());
         
         let mut mining_cycles = 0;
-        let mut fleet_surveys: HashMap<String, Vec<Survey>> = HashMap::new();
+        let survey_pool = get_survey_pool();
         let mut _max_cooldown_seconds: f64 = 0.0;
         
         while mining_cycles < max_cycles {
@@ -149,14 +147,8 @@ impl<'a> MiningOperations<'a> {
             o_info!("🔍 Creating surveys for all mining ships...");
             
             for (ship, asteroid) in ready_miners {
-                let asteroid_surveys = fleet_surveys.entry(asteroid.symbol.clone()).or_insert(Vec::new());
-                
-                // Check if we need surveys for this asteroid
-                let needs_survey = asteroid_surveys.iter().all(|survey| {
-                    !survey.deposits.iter().any(|deposit| needed_materials.contains(&deposit.symbol))
-                });
-                
-                if needs_survey || asteroid_surveys.is_empty() {
+                // Check if the fleet pool needs surveys for this asteroid
+                if !survey_pool.has_survey_for(&asteroid.symbol, needed_materials) {
                     o_info!("  🔍 {} surveying {}...", ship.symbol, asteroid.symbol);
                     match self.create_survey(&ship.symbol).await {
                         Ok(survey_data) => {
@@ -174,7 +166,7 @@ impl<'a> MiningOperations<'a> {
                                 }
                             }
                             
-                            asteroid_surveys.extend(survey_data.surveys);
+                            survey_pool.add_surveys(&ship.symbol, &survey_data.surveys);
                             
                             // Small delay for survey cooldown
                             if survey_data.cooldown.remaining_seconds > 0.0 {
@@ -196,14 +188,10 @@ impl<'a> MiningOperations<'a> {
                 o_info!("  ⛏️ {} extracting at {}...", ship.symbol, asteroid.symbol);
                 
                 // Find best survey for this asteroid
-                let empty_surveys = Vec::new();
-                let asteroid_surveys = fleet_surveys.get(&asteroid.symbol).unwrap_or(&empty_surveys);
-                let target_survey = asteroid_surveys.iter().find(|survey| {
-                    survey.deposits.iter().any(|deposit| needed_materials.contains(&deposit.symbol))
-                });
+                let target_survey = self.select_best_survey(&asteroid.symbol, needed_materials);
                 
                 // Execute extraction (targeted or random)
-                let extraction_result = if let Some(survey) = target_survey {
+                let mut extraction_result = if let Some(survey) = &target_survey {
                     o_info!("    🎯 Using targeted survey {} for {}", survey.signature, ship.symbol);
                     self.extract_with_survey(&ship.symbol, survey).await
                 } else {
@@ -211,8 +199,22 @@ impl<'a> MiningOperations<'a> {
                     self.extract_resources(&ship.symbol).await
                 };
                 
+                // Exhausted or expired surveys leave the pool; mine without one instead
+                let dead_survey = match (&target_survey, &extraction_result) {
+                    (Some(survey), Err(e)) => survey_pool.handle_extraction_error(&survey.signature, &e.to_string()),
+                    _ => false,
+                };
+                let used_survey = if dead_survey { None } else { target_survey.as_ref() };
+                if dead_survey {
+                    extraction_result = self.extract_resources(&ship.symbol).await;
+                } else if let (Some(survey), Ok(_)) = (&target_survey, &extraction_result) {
+                    survey_pool.record_extraction(&survey.signature);
+                }
+                
                 match extraction_result {
                     Ok(extraction_data) => {
+                        get_extraction_log().record_extraction(ship, &asteroid.symbol,
+                            used_survey.map(|survey| survey.signature.as_str()), &extraction_data);
                         let yield_info = &extraction_data.extraction.extraction_yield;
                         let cooldown_seconds = extraction_data.cooldown.remaining_seconds;
                         
@@ -0,0 +1,150 @@
+// Mining station - miners stay on one asteroid and hand their ore to a parked hauler
+use crate::models::Ship;
+use crate::o_info;
+
+/// A loading hauler leaves to sell or deliver once its hold is this full
+pub const HAULER_DEPART_FILL: f64 = 0.9;
+/// Miners offload to the loading hauler once their hold is this full
+pub const MINER_OFFLOAD_FILL: f64 = 0.75;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StationTask {
+    /// Miner: keep extracting at the station asteroid
+    Mine,
+    /// Miner: transfer cargo to the hauler loading on station
+    Offload { hauler: String },
+    /// Miner: hold is full and no hauler is on station, so manage its own cargo
+    SelfHaul,
+    /// Hauler: stay parked at the station and receive cargo
+    Load,
+    /// Hauler: leave to sell or deliver what it carries
+    Depart,
+    /// Hauler: empty and away, head back to the station
+    Return,
+}
+
+/// Which ships work the station and which hauler miners currently load into
+#[derive(Debug, Clone)]
+pub struct MiningStation {
+    pub asteroid: String,
+    pub miners: Vec<String>,
+    pub haulers: Vec<String>,
+    /// Haulers the station may recruit; the rest of the fleet's haulers are left alone
+    pub max_haulers: usize,
+    loading_hauler: Option<String>,
+}
+
+impl MiningStation {
+    pub fn new(asteroid: &str, max_haulers: usize) -> Self {
+        Self {
+            asteroid: asteroid.to_string(),
+            miners: Vec::new(),
+            haulers: Vec::new(),
+            max_haulers,
+            loading_hauler: None,
+        }
+    }
+
+    pub fn is_miner(ship: &Ship) -> bool {
+        ship.mounts.iter().any(|mount| mount.symbol.contains("MINING") || mount.symbol.contains("EXTRACTOR"))
+    }
+
+    /// Ships with a hold and nothing to extract with can ferry cargo for the miners
+    pub fn is_hauler(ship: &Ship) -> bool {
+        let is_probe = ship.registration.role == "SATELLITE" || ship.frame.symbol.contains("PROBE");
+        let can_extract = ship.mounts.iter().any(|mount| {
+            mount.symbol.contains("MINING") || mount.symbol.contains("EXTRACTOR") || mount.symbol.contains("SIPHON")
+        });
+        !is_probe && !can_extract && ship.cargo.capacity > 0
+    }
+
+    /// Whether the fleet has both ships that mine and ships that can haul for them
+    pub fn fleet_supports_station(ships: &[Ship]) -> bool {
+        ships.iter().any(Self::is_miner) && ships.iter().any(Self::is_hauler)
+    }
+
+    fn fill(ship: &Ship) -> f64 {
+        if ship.cargo.capacity <= 0 {
+            return 1.0;
+        }
+        ship.cargo.units as f64 / ship.cargo.capacity as f64
+    }
+
+    fn is_on_station(&self, ship: &Ship) -> bool {
+        ship.nav.waypoint_symbol == self.asteroid && ship.nav.status != "IN_TRANSIT"
+    }
+
+    pub fn loading_hauler(&self) -> Option<&str> {
+        self.loading_hauler.as_deref()
+    }
+
+    /// Re-read membership from the latest ship states and make sure the loading hauler
+    /// is still on station with room; otherwise the emptiest parked hauler takes over.
+    /// Haulers keep their place once recruited; new ones are only taken up to `max_haulers`
+    /// and never from `busy`, the ships already committed to other work.
+    pub fn refresh(&mut self, ships: &[Ship], busy: &[String]) {
+        self.miners = ships.iter().filter(|ship| Self::is_miner(ship)).map(|ship| ship.symbol.clone()).collect();
+        self.haulers.retain(|symbol| ships.iter().any(|ship| &ship.symbol == symbol && Self::is_hauler(ship)));
+        self.haulers.truncate(self.max_haulers);
+
+        let mut recruits: Vec<&Ship> = ships.iter()
+            .filter(|ship| Self::is_hauler(ship) && !self.haulers.contains(&ship.symbol) && !busy.contains(&ship.symbol))
+            .collect();
+        // Haulers already parked on station join first, then the ones with the most room
+        recruits.sort_by_key(|ship| (!self.is_on_station(ship), ship.cargo.units - ship.cargo.capacity, ship.symbol.clone()));
+        let open_places = self.max_haulers.saturating_sub(self.haulers.len());
+        for ship in recruits.into_iter().take(open_places) {
+            o_info!("🚛 {} joins mining station {}", ship.symbol, self.asteroid);
+            self.haulers.push(ship.symbol.clone());
+        }
+
+        let still_loading = self.loading_hauler.as_ref()
+            .and_then(|symbol| ships.iter().find(|ship| &ship.symbol == symbol))
+            .is_some_and(|ship| self.is_on_station(ship) && Self::fill(ship) < HAULER_DEPART_FILL);
+        if still_loading {
+            return;
+        }
+
+        let next = ships.iter()
+            .filter(|ship| self.haulers.contains(&ship.symbol))
+            .filter(|ship| self.is_on_station(ship) && Self::fill(ship) < HAULER_DEPART_FILL)
+            .max_by_key(|ship| ship.cargo.capacity - ship.cargo.units)
+            .map(|ship| ship.symbol.clone());
+        if next != self.loading_hauler {
+            match &next {
+                Some(hauler) => o_info!("🚛 {} is now loading at mining station {}", hauler, self.asteroid),
+                None => o_info!("🚛 No hauler loading at mining station {}", self.asteroid),
+            }
+        }
+        self.loading_hauler = next;
+    }
+
+    /// What a station ship should do next, or None if the ship is not part of the station
+    pub fn task_for(&self, ship: &Ship) -> Option<StationTask> {
+        if self.haulers.contains(&ship.symbol) {
+            return Some(if Self::fill(ship) >= HAULER_DEPART_FILL {
+                StationTask::Depart
+            } else if self.is_on_station(ship) {
+                StationTask::Load
+            } else if ship.cargo.units > 0 {
+                // Leftovers from the last run get sold before returning
+                StationTask::Depart
+            } else {
+                StationTask::Return
+            });
+        }
+
+        if !self.miners.contains(&ship.symbol) {
+            return None;
+        }
+        let fill = Self::fill(ship);
+        Some(match &self.loading_hauler {
+            Some(hauler) if fill >= MINER_OFFLOAD_FILL && self.is_on_station(ship) => {
+                StationTask::Offload { hauler: hauler.clone() }
+            }
+            None if fill >= 1.0 => StationTask::SelfHaul,
+            _ if fill >= 1.0 && !self.is_on_station(ship) => StationTask::SelfHaul,
+            _ => StationTask::Mine,
+        })
+    }
+}
@@ -0,0 +1,118 @@
+// Ship missions - what each ship was doing, kept on disk so work resumes at the right step after a restart
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::models::Ship;
+use crate::operations::ship_actor::{ActionId, ShipAction};
+use crate::operations::task_planner::{TaskStep, TaskStepType};
+use crate::operations::trading::TradeSide;
+
+/// An action a ship was given, with its plan and how far through it the ship got
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShipMission {
+    pub action_id: ActionId,
+    pub action: ShipAction,
+    pub steps: Vec<TaskStep>,
+    pub completed_steps: usize,
+    /// Contract the mission works towards, if any
+    pub contract_id: Option<String>,
+    /// Goods the mission needs in the hold; cargo management must not sell these
+    pub reserved_goods: Vec<String>,
+    pub started_at: DateTime<Utc>,
+}
+
+/// What to do with a stored mission once the ship's live state is known
+#[derive(Debug, Clone, PartialEq)]
+pub enum MissionResume {
+    /// Re-issue the action; the steps before `from_step` are already done
+    Resume { from_step: usize },
+    /// The ship finished before the mission could be cleared
+    Completed,
+    Abandoned(String),
+}
+
+impl ShipMission {
+    pub fn new(action_id: ActionId, action: ShipAction, steps: Vec<TaskStep>) -> Self {
+        let (contract_id, reserved_goods) = match &action {
+            ShipAction::Mine { contract_id, needed_materials, .. }
+            | ShipAction::Siphon { contract_id, needed_materials, .. } => (Some(contract_id.clone()), needed_materials.clone()),
+            ShipAction::DeliverCargo { contract_id, trade_symbol, .. } => (Some(contract_id.clone()), vec![trade_symbol.clone()]),
+            ShipAction::Trade { orders, .. } => (None, orders.iter()
+                .filter(|order| order.side == TradeSide::Buy)
+                .map(|order| order.good.clone())
+                .collect()),
+            _ => (None, Vec::new()),
+        };
+
+        Self {
+            action_id,
+            action,
+            steps,
+            completed_steps: 0,
+            contract_id,
+            reserved_goods,
+            started_at: Utc::now(),
+        }
+    }
+
+    /// Whether the ship's state shows a plan step has been carried out
+    pub fn step_done(&self, step: &TaskStep, ship: &Ship) -> bool {
+        let at_location = ship.nav.waypoint_symbol == step.location;
+        let held = |good: &str| ship.cargo.inventory.iter()
+            .filter(|item| item.symbol == good)
+            .map(|item| item.units)
+            .sum::<i32>();
+
+        match step.step_type {
+            TaskStepType::Navigate => at_location && ship.nav.status != "IN_TRANSIT",
+            TaskStepType::Dock => at_location && ship.nav.status == "DOCKED",
+            TaskStepType::Orbit => at_location && ship.nav.status == "IN_ORBIT",
+            TaskStepType::Refuel => at_location && ship.fuel.current >= ship.fuel.capacity,
+            TaskStepType::SellCargo => at_location && ship.cargo.units == 0,
+            TaskStepType::DeliverCargo => match &self.action {
+                ShipAction::DeliverCargo { trade_symbol, .. } => held(trade_symbol) == 0,
+                _ => false,
+            },
+            TaskStepType::Trade => match &self.action {
+                ShipAction::Trade { orders, .. } => orders.iter().all(|order| match order.side {
+                    TradeSide::Buy => held(&order.good) >= order.units,
+                    TradeSide::Sell => held(&order.good) == 0,
+                }),
+                _ => false,
+            },
+            // Extraction and jettisons leave nothing to tell a finished step from an unstarted one
+            TaskStepType::Mine | TaskStepType::Siphon | TaskStepType::Survey | TaskStepType::JettisonCargo => false,
+        }
+    }
+
+    /// Steps done so far, counted up to the furthest step the ship's state shows as done
+    pub fn progress(&self, ship: &Ship) -> usize {
+        self.steps.iter()
+            .rposition(|step| self.step_done(step, ship))
+            .map(|index| index + 1)
+            .unwrap_or(0)
+    }
+
+    /// Compare the stored mission with the ship as the API reports it now
+    pub fn reconcile(&self, ship: &Ship, active_contracts: &[String]) -> MissionResume {
+        if let Some(contract_id) = &self.contract_id
+            && !active_contracts.contains(contract_id) {
+            return MissionResume::Abandoned(format!("contract {} is no longer active", contract_id));
+        }
+        if let ShipAction::DeliverCargo { trade_symbol, .. } = &self.action
+            && !ship.cargo.inventory.iter().any(|item| &item.symbol == trade_symbol)
+            && self.completed_steps == 0 && self.steps.is_empty() {
+            return MissionResume::Abandoned(format!("no {} left in the hold to deliver", trade_symbol));
+        }
+
+        let from_step = self.progress(ship).max(self.completed_steps.min(self.steps.len()));
+        if !self.steps.is_empty() && from_step >= self.steps.len() {
+            MissionResume::Completed
+        } else {
+            MissionResume::Resume { from_step }
+        }
+    }
+
+    pub fn step_description(&self, step: usize) -> &str {
+        self.steps.get(step).map(|step| step.description.as_str()).unwrap_or("start")
+    }
+}
@@ -2,6 +2,7 @@
 
 pub mod ship;
 pub mod mining;
+pub mod siphoning;
 pub mod trading;
 pub mod contracts;
 pub mod fleet;
@@ -15,10 +16,17 @@ pub mod task_planner;
 pub mod product_knowledge;
 pub mod ship_role_manager;
 pub mod contract_analyzer;
-pub mod iron_ore_miner;
+pub mod mining_station;
+pub mod refinery_planner;
+pub mod resource_campaign;
+pub mod outfitting;
+pub mod fleet_planner;
+pub mod maintenance;
+pub mod missions;
 
 pub use ship::*;
 pub use mining::*;
+pub use siphoning::*;
 pub use trading::*;
 pub use contracts::*;
 pub use fleet::*;
@@ -32,4 +40,10 @@ pub use task_planner::*;
 pub use product_knowledge::*;
 pub use ship_role_manager::*;
 pub use contract_analyzer::*;
-pub use iron_ore_miner::*;
\ No newline at end of file
+pub use mining_station::*;
+pub use refinery_planner::*;
+pub use resource_campaign::*;
+pub use outfitting::*;
+pub use fleet_planner::*;
+pub use maintenance::*;
+pub use missions::*;
\ No newline at end of file
@@ -2,8 +2,10 @@
 // Provides intelligent wayfinding with fuel safety checks
 
 use crate::models::*;
-use crate::client::SpaceTradersClient;
+use crate::client::{ApiPriority, PriorityApiClient, SpaceTradersClient};
 use crate::config::SpaceTradersConfig;
+use crate::o_info;
+use crate::operations::outfitting::travel_and_dock;
 
 pub struct NavigationPlanner {
     client: SpaceTradersClient,
@@ -150,6 +152,25 @@ impl NavigationPlanner {
             nearest_fuel_source: Some(nearest_marketplace.symbol.clone()),
         })
     }
+
+    /// Fly a ship to a waypoint and dock, stopping to refuel first when it cannot make the trip directly
+    pub async fn travel_with_refuel(&self, client: &PriorityApiClient, ship: &Ship, destination: &str) -> Result<(), Box<dyn std::error::Error>> {
+        // Probes and satellites fly without fuel
+        if ship.fuel.capacity > 0 {
+            let safety_check = self.can_navigate_safely(ship, destination).await?;
+            if !safety_check.is_safe {
+                let Some(fuel_station) = safety_check.nearest_fuel_source else {
+                    return Err(format!("{} cannot reach {}: {}", ship.symbol, destination, safety_check.reason).into());
+                };
+                o_info!("    ⛽ {} refuelling at {} on the way to {}", ship.symbol, fuel_station, destination);
+                travel_and_dock(client, &ship.symbol, &fuel_station).await?;
+                client.refuel_ship_with_priority(&ship.symbol, None, ApiPriority::Normal).await?;
+            }
+        }
+        
+        travel_and_dock(client, &ship.symbol, destination).await?;
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
@@ -0,0 +1,411 @@
+// Ship outfitting - desired mount/module loadouts per role, checked against ship limits and bought where sold
+use std::collections::HashMap;
+use crate::client::priority_client::{ApiPriority, PriorityApiClient};
+use crate::models::{Market, Ship, ShipRequirements, Shipyard};
+use crate::operations::ShipRole;
+use crate::{o_debug, o_info};
+
+/// The mounts and modules a ship in a role should carry. Mounts are the exact set
+/// (anything else is removed); modules are required, other modules stay installed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Loadout {
+    pub mounts: Vec<String>,
+    pub modules: Vec<String>,
+}
+
+impl Loadout {
+    pub fn new(mounts: &[&str], modules: &[&str]) -> Self {
+        Self {
+            mounts: mounts.iter().map(|mount| mount.to_string()).collect(),
+            modules: modules.iter().map(|module| module.to_string()).collect(),
+        }
+    }
+}
+
+/// Loadouts used until overridden with `OutfittingManager::set_loadout`
+pub fn default_loadout(role: &ShipRole) -> Option<Loadout> {
+    match role {
+        ShipRole::Miner => Some(Loadout::new(&["MOUNT_MINING_LASER_II", "MOUNT_MINING_LASER_II", "MOUNT_SURVEYOR_I"], &[])),
+        ShipRole::Siphoner => Some(Loadout::new(&["MOUNT_GAS_SIPHON_II", "MOUNT_GAS_SIPHON_II"], &[])),
+        ShipRole::Refiner => Some(Loadout::new(&[], &["MODULE_ORE_REFINERY_I"])),
+        ShipRole::Hauler | ShipRole::Scout | ShipRole::Utility => None,
+    }
+}
+
+/// A part to buy and the cheapest known place to buy it
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartPurchase {
+    pub part: String,
+    pub waypoint: Option<String>,
+    pub price: Option<i32>,
+}
+
+/// What has to change for a ship to match its loadout
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutfitPlan {
+    pub ship_symbol: String,
+    pub remove_mounts: Vec<String>,
+    pub install_mounts: Vec<String>,
+    pub install_modules: Vec<String>,
+    /// Parts not already in the ship's hold
+    pub purchases: Vec<PartPurchase>,
+    /// Power, crew or slot limits the finished loadout would break
+    pub violations: Vec<String>,
+    /// Parts whose requirements have not been seen yet, so limits could not be fully checked
+    pub unknown_requirements: Vec<String>,
+}
+
+impl OutfitPlan {
+    pub fn is_empty(&self) -> bool {
+        self.remove_mounts.is_empty() && self.install_mounts.is_empty() && self.install_modules.is_empty()
+    }
+
+    /// Installs and removals, each charged the shipyard's modifications fee
+    pub fn changes(&self) -> usize {
+        self.remove_mounts.len() + self.install_mounts.len() + self.install_modules.len()
+    }
+
+    pub fn is_feasible(&self) -> bool {
+        self.violations.is_empty() && self.purchases.iter().all(|purchase| purchase.waypoint.is_some())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CostEstimate {
+    pub parts: i64,
+    pub fees: i64,
+    pub unpriced_parts: Vec<String>,
+}
+
+impl CostEstimate {
+    pub fn total(&self) -> i64 {
+        self.parts + self.fees
+    }
+}
+
+/// Power, crew and slots a ship's parts use against what its frame, reactor and quarters provide
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShipBudget {
+    pub power_used: i32,
+    pub power_available: Option<i32>,
+    pub crew_required: i32,
+    pub crew_capacity: i32,
+    pub slots_used: i32,
+    pub slots_available: i32,
+    pub mounts_used: i32,
+    pub mounting_points: i32,
+}
+
+impl ShipBudget {
+    fn add(&mut self, requirements: &ShipRequirements) {
+        self.power_used += requirements.power.unwrap_or(0);
+        self.crew_required += requirements.crew.unwrap_or(0);
+    }
+
+    pub fn violations(&self) -> Vec<String> {
+        let mut violations = Vec::new();
+        if let Some(power) = self.power_available
+            && self.power_used > power {
+            violations.push(format!("needs {} power, reactor provides {}", self.power_used, power));
+        }
+        if self.crew_required > self.crew_capacity {
+            violations.push(format!("needs {} crew, quarters hold {}", self.crew_required, self.crew_capacity));
+        }
+        if self.slots_used > self.slots_available {
+            violations.push(format!("needs {} module slots, frame has {}", self.slots_used, self.slots_available));
+        }
+        if self.mounts_used > self.mounting_points {
+            violations.push(format!("needs {} mounting points, frame has {}", self.mounts_used, self.mounting_points));
+        }
+        violations
+    }
+}
+
+pub struct OutfittingManager {
+    loadouts: HashMap<ShipRole, Loadout>,
+    requirements: HashMap<String, ShipRequirements>, // part symbol -> requirements
+    sources: HashMap<String, HashMap<String, Option<i32>>>, // part symbol -> waypoint -> purchase price
+    modification_fees: HashMap<String, i32>, // shipyard waypoint -> fee per install/removal
+}
+
+impl Default for OutfittingManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OutfittingManager {
+    pub fn new() -> Self {
+        let loadouts = [ShipRole::Miner, ShipRole::Siphoner, ShipRole::Refiner].into_iter()
+            .filter_map(|role| default_loadout(&role).map(|loadout| (role, loadout)))
+            .collect();
+        Self {
+            loadouts,
+            requirements: HashMap::new(),
+            sources: HashMap::new(),
+            modification_fees: HashMap::new(),
+        }
+    }
+
+    pub fn set_loadout(&mut self, role: ShipRole, loadout: Loadout) {
+        self.loadouts.insert(role, loadout);
+    }
+
+    pub fn loadout(&self, role: &ShipRole) -> Option<&Loadout> {
+        self.loadouts.get(role)
+    }
+
+    pub fn record_requirements(&mut self, part: &str, requirements: &ShipRequirements) {
+        self.requirements.insert(part.to_string(), requirements.clone());
+    }
+
+    /// Learn part requirements from every mount and module installed across the fleet
+    pub fn learn_from_ships(&mut self, ships: &[Ship]) {
+        for ship in ships {
+            for mount in &ship.mounts {
+                self.record_requirements(&mount.symbol, &mount.requirements);
+            }
+            for module in &ship.modules {
+                self.record_requirements(&module.symbol, &module.requirements);
+            }
+        }
+    }
+
+    /// Learn part requirements from the ships a shipyard sells, and its modification fee
+    pub fn record_shipyard(&mut self, shipyard: &Shipyard) {
+        self.modification_fees.insert(shipyard.symbol.clone(), shipyard.modifications_fee);
+        for ship in shipyard.ships.iter().flatten() {
+            for mount in &ship.mounts {
+                self.record_requirements(&mount.symbol, &mount.requirements);
+            }
+            for module in &ship.modules {
+                self.record_requirements(&module.symbol, &module.requirements);
+            }
+        }
+    }
+
+    /// Remember where mounts and modules are sold and at what price
+    pub fn record_market(&mut self, market: &Market) {
+        let is_part = |symbol: &str| symbol.starts_with("MOUNT_") || symbol.starts_with("MODULE_");
+        for good in market.exports.iter().chain(&market.exchange).filter(|good| is_part(&good.symbol)) {
+            self.sources.entry(good.symbol.clone()).or_default()
+                .entry(market.symbol.clone()).or_insert(None);
+        }
+        for good in market.trade_goods.iter().flatten().filter(|good| is_part(&good.symbol)) {
+            self.sources.entry(good.symbol.clone()).or_default()
+                .insert(market.symbol.clone(), Some(good.purchase_price));
+        }
+    }
+
+    /// Cheapest known seller of a part; priced sellers beat ones only known to stock it
+    pub fn cheapest_source(&self, part: &str) -> Option<(String, Option<i32>)> {
+        self.sources.get(part)?.iter()
+            .min_by_key(|(waypoint, price)| (price.unwrap_or(i32::MAX), waypoint.to_string()))
+            .map(|(waypoint, price)| (waypoint.clone(), *price))
+    }
+
+    /// Power, crew and slot use of a ship with the given mounts and modules fitted
+    pub fn budget(&self, ship: &Ship, mounts: &[String], modules: &[String]) -> (ShipBudget, Vec<String>) {
+        let mut budget = ShipBudget {
+            power_available: ship.reactor.power_output,
+            crew_capacity: ship.crew.capacity,
+            slots_available: ship.frame.module_slots,
+            mounting_points: ship.frame.mounting_points,
+            mounts_used: mounts.len() as i32,
+            ..Default::default()
+        };
+        budget.add(&ship.frame.requirements);
+        budget.add(&ship.reactor.requirements);
+        budget.add(&ship.engine.requirements);
+
+        let mut unknown = Vec::new();
+        for part in mounts.iter().chain(modules) {
+            let installed = ship.mounts.iter().find(|mount| &mount.symbol == part).map(|mount| &mount.requirements)
+                .or_else(|| ship.modules.iter().find(|module| &module.symbol == part).map(|module| &module.requirements));
+            match installed.or_else(|| self.requirements.get(part)) {
+                Some(requirements) => {
+                    budget.add(requirements);
+                    if part.starts_with("MODULE_") {
+                        budget.slots_used += requirements.slots.unwrap_or(1);
+                    }
+                }
+                None => {
+                    if part.starts_with("MODULE_") {
+                        budget.slots_used += 1;
+                    }
+                    if !unknown.contains(part) {
+                        unknown.push(part.clone());
+                    }
+                }
+            }
+        }
+        (budget, unknown)
+    }
+
+    /// Diff a ship against its role's loadout, or None if the role has no loadout
+    pub fn plan(&self, ship: &Ship, role: &ShipRole) -> Option<OutfitPlan> {
+        let loadout = self.loadouts.get(role)?;
+
+        // Match wanted mounts against installed ones one for one so duplicates count
+        let mut remaining: Vec<String> = ship.mounts.iter().map(|mount| mount.symbol.clone()).collect();
+        let mut install_mounts = Vec::new();
+        for wanted in &loadout.mounts {
+            match remaining.iter().position(|mount| mount == wanted) {
+                Some(index) => { remaining.remove(index); }
+                None => install_mounts.push(wanted.clone()),
+            }
+        }
+        let remove_mounts = remaining;
+
+        let mut installed_modules: Vec<String> = ship.modules.iter().map(|module| module.symbol.clone()).collect();
+        let mut install_modules = Vec::new();
+        for wanted in &loadout.modules {
+            match installed_modules.iter().position(|module| module == wanted) {
+                Some(index) => { installed_modules.remove(index); }
+                None => install_modules.push(wanted.clone()),
+            }
+        }
+
+        let final_modules: Vec<String> = ship.modules.iter().map(|module| module.symbol.clone())
+            .chain(install_modules.iter().cloned())
+            .collect();
+        let (budget, unknown_requirements) = self.budget(ship, &loadout.mounts, &final_modules);
+
+        // Parts already in the hold don't need buying
+        let mut in_hold: HashMap<&str, i32> = ship.cargo.inventory.iter()
+            .map(|item| (item.symbol.as_str(), item.units))
+            .collect();
+        let mut purchases = Vec::new();
+        for part in install_mounts.iter().chain(&install_modules) {
+            if let Some(units) = in_hold.get_mut(part.as_str())
+                && *units > 0 {
+                *units -= 1;
+                continue;
+            }
+            let (waypoint, price) = self.cheapest_source(part).unzip();
+            purchases.push(PartPurchase { part: part.clone(), waypoint, price: price.flatten() });
+        }
+
+        Some(OutfitPlan {
+            ship_symbol: ship.symbol.clone(),
+            remove_mounts,
+            install_mounts,
+            install_modules,
+            purchases,
+            violations: budget.violations(),
+            unknown_requirements,
+        })
+    }
+
+    /// Cost of carrying out a plan at a shipyard, before anything is bought
+    pub fn estimate_cost(&self, plan: &OutfitPlan, shipyard_waypoint: &str) -> CostEstimate {
+        let fee = self.modification_fees.get(shipyard_waypoint).copied().unwrap_or(0) as i64;
+        CostEstimate {
+            parts: plan.purchases.iter().filter_map(|purchase| purchase.price).map(|price| price as i64).sum(),
+            fees: fee * plan.changes() as i64,
+            unpriced_parts: plan.purchases.iter()
+                .filter(|purchase| purchase.price.is_none())
+                .map(|purchase| purchase.part.clone())
+                .collect(),
+        }
+    }
+
+    /// Look up every marketplace in a system so parts can be found
+    pub async fn scan_markets(&mut self, client: &PriorityApiClient, system_symbol: &str) -> Result<usize, String> {
+        let marketplaces = client.get_system_waypoints_with_traits(system_symbol, "MARKETPLACE").await
+            .map_err(|e| e.to_string())?;
+        for waypoint in &marketplaces {
+            match client.get_market(system_symbol, &waypoint.symbol).await.map_err(|e| e.to_string()) {
+                Ok(market) => self.record_market(&market),
+                Err(e) => o_debug!("⚠️ Could not read market at {}: {}", waypoint.symbol, e),
+            }
+        }
+        Ok(marketplaces.len())
+    }
+
+    /// Buy the plan's parts, then fit them at a shipyard. Returns credits spent.
+    pub async fn execute(&self, client: &PriorityApiClient, plan: &OutfitPlan, shipyard_waypoint: &str) -> Result<i64, String> {
+        if plan.is_empty() {
+            return Ok(0);
+        }
+        if !plan.is_feasible() {
+            return Err(format!("{} cannot be outfitted: {}", plan.ship_symbol,
+                if plan.violations.is_empty() { "some parts are not sold anywhere known".to_string() } else { plan.violations.join(", ") }));
+        }
+
+        let estimate = self.estimate_cost(plan, shipyard_waypoint);
+        let credits = client.get_agent().await.map_err(|e| e.to_string())?.credits;
+        if estimate.total() > credits {
+            return Err(format!("Outfitting {} costs about {} credits, only {} available", plan.ship_symbol, estimate.total(), credits));
+        }
+        let ship = client.get_ship(&plan.ship_symbol).await.map_err(|e| e.to_string())?;
+        if ship.cargo.capacity - ship.cargo.units < plan.purchases.len() as i32 {
+            return Err(format!("{} needs {} free cargo units to carry its new parts", plan.ship_symbol, plan.purchases.len()));
+        }
+        o_info!("🛠️ Outfitting {}: +{:?} mounts, -{:?} mounts, +{:?} modules (~{} credits)",
+               plan.ship_symbol, plan.install_mounts, plan.remove_mounts, plan.install_modules, estimate.total());
+
+        let mut spent = 0i64;
+        let mut purchases = plan.purchases.clone();
+        purchases.sort_by(|a, b| a.waypoint.cmp(&b.waypoint));
+        for purchase in &purchases {
+            let waypoint = purchase.waypoint.as_deref().unwrap_or(shipyard_waypoint);
+            travel_and_dock(client, &plan.ship_symbol, waypoint).await?;
+            let bought = client.purchase_cargo_with_priority(&plan.ship_symbol, &purchase.part, 1, ApiPriority::Normal).await
+                .map_err(|e| format!("Buying {} at {} failed: {}", purchase.part, waypoint, e))?;
+            spent += bought.transaction.total_price as i64;
+            o_info!("🛒 {} bought {} at {} for {}", plan.ship_symbol, purchase.part, waypoint, bought.transaction.total_price);
+        }
+
+        travel_and_dock(client, &plan.ship_symbol, shipyard_waypoint).await?;
+        for mount in &plan.remove_mounts {
+            let removed = client.remove_ship_mount_with_priority(&plan.ship_symbol, mount, ApiPriority::Normal).await
+                .map_err(|e| format!("Removing {} failed: {}", mount, e))?;
+            spent += removed.transaction.total_price as i64;
+            o_info!("🗑️ Removed {} from {}", mount, plan.ship_symbol);
+        }
+        for mount in &plan.install_mounts {
+            let installed = client.install_ship_mount_with_priority(&plan.ship_symbol, mount, ApiPriority::Normal).await
+                .map_err(|e| format!("Installing {} failed: {}", mount, e))?;
+            spent += installed.transaction.total_price as i64;
+            o_info!("🔧 Installed {} on {}", mount, plan.ship_symbol);
+        }
+        for module in &plan.install_modules {
+            let installed = client.install_ship_module_with_priority(&plan.ship_symbol, module, ApiPriority::Normal).await
+                .map_err(|e| format!("Installing {} failed: {}", module, e))?;
+            spent += installed.transaction.total_price as i64;
+            o_info!("🔧 Installed {} on {}", module, plan.ship_symbol);
+        }
+
+        o_info!("✅ {} outfitted for {} credits", plan.ship_symbol, spent);
+        Ok(spent)
+    }
+}
+
+/// Fly a ship to a waypoint, wait for it to arrive and dock
+pub(crate) async fn travel_and_dock(client: &PriorityApiClient, ship_symbol: &str, waypoint: &str) -> Result<(), String> {
+    let ship = client.get_ship(ship_symbol).await.map_err(|e| e.to_string())?;
+    if ship.nav.waypoint_symbol != waypoint || ship.nav.status == "IN_TRANSIT" {
+        let arrival = if ship.nav.status == "IN_TRANSIT" {
+            ship.nav.route.arrival.clone()
+        } else {
+            if ship.nav.status == "DOCKED" {
+                client.orbit_ship(ship_symbol).await.map_err(|e| e.to_string())?;
+            }
+            client.navigate_ship(ship_symbol, waypoint).await.map_err(|e| e.to_string())?.nav.route.arrival
+        };
+        let wait_seconds = chrono::DateTime::parse_from_rfc3339(&arrival)
+            .map(|arrival| (arrival.with_timezone(&chrono::Utc) - chrono::Utc::now()).num_seconds().max(0) as u64 + 3)
+            .unwrap_or(30);
+        o_debug!("⏳ {} arriving at {} in {}s", ship_symbol, waypoint, wait_seconds);
+        tokio::time::sleep(tokio::time::Duration::from_secs(wait_seconds)).await;
+        // A ship that was already in transit may have been heading somewhere else
+        if ship.nav.status == "IN_TRANSIT" && ship.nav.route.destination.symbol != waypoint {
+            return Box::pin(travel_and_dock(client, ship_symbol, waypoint)).await;
+        }
+    }
+    if ship.nav.status != "DOCKED" || ship.nav.waypoint_symbol != waypoint {
+        client.dock_ship(ship_symbol).await.map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
@@ -0,0 +1,261 @@
+// Refinery production planner - recipes for refine_cargo and scheduling around refine cooldowns
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use crate::models::{RefineData, Ship, ShipCargo, ShipCooldown};
+use crate::{o_debug, o_info};
+
+/// Input consumed and output produced by a single refine before any have been observed
+const DEFAULT_INPUT_UNITS: i32 = 30;
+const DEFAULT_OUTPUT_UNITS: i32 = 10;
+
+/// Produce types accepted by refine_cargo and the raw good each consumes
+const RECIPE_INPUTS: [(&str, &str); 9] = [
+    ("IRON", "IRON_ORE"),
+    ("COPPER", "COPPER_ORE"),
+    ("SILVER", "SILVER_ORE"),
+    ("GOLD", "GOLD_ORE"),
+    ("ALUMINUM", "ALUMINUM_ORE"),
+    ("PLATINUM", "PLATINUM_ORE"),
+    ("URANITE", "URANITE_ORE"),
+    ("MERITIUM", "MERITIUM_ORE"),
+    ("FUEL", "HYDROCARBON"),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefineRecipe {
+    pub produce: String,
+    pub input: String,
+    pub input_units: i32,  // consumed per refine
+    pub output_units: i32, // produced per refine
+}
+
+impl RefineRecipe {
+    /// Refines needed to produce a number of output units
+    pub fn refines_for_output(&self, output_units: i32) -> i32 {
+        if output_units <= 0 {
+            return 0;
+        }
+        (output_units + self.output_units - 1) / self.output_units
+    }
+
+    /// Raw input needed to produce a number of output units
+    pub fn input_for_output(&self, output_units: i32) -> i32 {
+        self.refines_for_output(output_units) * self.input_units
+    }
+
+    /// Refines the input already in a hold allows
+    pub fn refines_possible(&self, cargo: &ShipCargo) -> i32 {
+        let stock: i32 = cargo.inventory.iter()
+            .filter(|item| item.symbol == self.input)
+            .map(|item| item.units)
+            .sum();
+        stock / self.input_units
+    }
+}
+
+/// Look up the recipe that produces a good, using the default ratios
+pub fn recipe_for(produce: &str) -> Option<RefineRecipe> {
+    RECIPE_INPUTS.iter()
+        .find(|(product, _)| *product == produce)
+        .map(|(product, input)| RefineRecipe {
+            produce: product.to_string(),
+            input: input.to_string(),
+            input_units: DEFAULT_INPUT_UNITS,
+            output_units: DEFAULT_OUTPUT_UNITS,
+        })
+}
+
+/// Look up the recipe that consumes a raw good
+pub fn recipe_for_input(input: &str) -> Option<RefineRecipe> {
+    RECIPE_INPUTS.iter()
+        .find(|(_, raw)| *raw == input)
+        .and_then(|(product, _)| recipe_for(product))
+}
+
+/// Whether refining a batch is worth more than selling the raw input it consumes
+#[derive(Debug, Clone, PartialEq)]
+pub enum RefineDecision {
+    Refine { gain_per_refine: f64 },
+    SellRaw { loss_per_refine: f64 },
+    /// One of the prices is unknown
+    Unknown,
+}
+
+/// What the refinery ship should do next
+#[derive(Debug, Clone, PartialEq)]
+pub enum RefineryStep {
+    Refine,
+    WaitForCooldown { seconds: f64 },
+    NeedInput { units: i32 },
+}
+
+/// A stock top-up: move input from a co-located ship into the refinery
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputTransfer {
+    pub from_ship: String,
+    pub good: String,
+    pub units: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct RefineryPlanner {
+    recipes: HashMap<String, RefineRecipe>, // produce -> recipe
+    ready_at: Option<DateTime<Utc>>,
+    /// Refines worth of input to keep on hand so a refine can start as soon as the cooldown ends
+    pub target_refines_in_stock: i32,
+}
+
+impl Default for RefineryPlanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RefineryPlanner {
+    pub fn new() -> Self {
+        let recipes = RECIPE_INPUTS.iter()
+            .filter_map(|(product, _)| recipe_for(product))
+            .map(|recipe| (recipe.produce.clone(), recipe))
+            .collect();
+        Self {
+            recipes,
+            ready_at: None,
+            target_refines_in_stock: 2,
+        }
+    }
+
+    pub fn recipe(&self, produce: &str) -> Option<&RefineRecipe> {
+        self.recipes.get(produce)
+    }
+
+    pub fn recipes(&self) -> impl Iterator<Item = &RefineRecipe> {
+        self.recipes.values()
+    }
+
+    /// Learn the real ratios from a refine response and start its cooldown
+    pub fn observe_refine(&mut self, refine: &RefineData) {
+        for produced in &refine.produced {
+            let Some(recipe) = self.recipes.get_mut(&produced.trade_symbol) else {
+                continue;
+            };
+            if let Some(consumed) = refine.consumed.iter().find(|consumed| consumed.trade_symbol == recipe.input)
+                && consumed.units > 0 && produced.units > 0
+                && (consumed.units != recipe.input_units || produced.units != recipe.output_units) {
+                o_info!("📖 Refine recipe for {}: {} {} → {} (was {} → {})",
+                        recipe.produce, consumed.units, recipe.input, produced.units, recipe.input_units, recipe.output_units);
+                recipe.input_units = consumed.units;
+                recipe.output_units = produced.units;
+            }
+        }
+        self.record_cooldown(&refine.cooldown);
+    }
+
+    pub fn record_cooldown(&mut self, cooldown: &ShipCooldown) {
+        self.ready_at = if cooldown.remaining_seconds > 0.0 {
+            Some(Utc::now() + chrono::Duration::milliseconds((cooldown.remaining_seconds * 1000.0) as i64))
+        } else {
+            None
+        };
+    }
+
+    pub fn seconds_until_ready(&self) -> f64 {
+        self.ready_at
+            .map(|ready_at| (ready_at - Utc::now()).num_milliseconds().max(0) as f64 / 1000.0)
+            .unwrap_or(0.0)
+    }
+
+    /// Next step for a refinery ship producing a good
+    pub fn next_step(&self, produce: &str, cargo: &ShipCargo) -> Option<RefineryStep> {
+        let recipe = self.recipe(produce)?;
+        if recipe.refines_possible(cargo) == 0 {
+            return Some(RefineryStep::NeedInput { units: self.input_shortfall(recipe, cargo).max(recipe.input_units) });
+        }
+        let wait = self.seconds_until_ready();
+        if wait > 0.0 {
+            return Some(RefineryStep::WaitForCooldown { seconds: wait });
+        }
+        Some(RefineryStep::Refine)
+    }
+
+    /// Input units missing from the stock target, limited by free hold space
+    pub fn input_shortfall(&self, recipe: &RefineRecipe, cargo: &ShipCargo) -> i32 {
+        let stock: i32 = cargo.inventory.iter()
+            .filter(|item| item.symbol == recipe.input)
+            .map(|item| item.units)
+            .sum();
+        let wanted = recipe.input_units * self.target_refines_in_stock - stock;
+        wanted.min(cargo.capacity - cargo.units).max(0)
+    }
+
+    /// Transfers from ships at the refinery's waypoint that keep its input stock topped up
+    pub fn plan_top_up(&self, produce: &str, refinery: &Ship, fleet: &[Ship]) -> Vec<InputTransfer> {
+        let Some(recipe) = self.recipe(produce) else {
+            return Vec::new();
+        };
+        let mut remaining = self.input_shortfall(recipe, &refinery.cargo);
+        let mut transfers = Vec::new();
+        for ship in fleet {
+            if remaining <= 0 {
+                break;
+            }
+            if ship.symbol == refinery.symbol
+                || ship.nav.waypoint_symbol != refinery.nav.waypoint_symbol
+                || ship.nav.status == "IN_TRANSIT" {
+                continue;
+            }
+            let available: i32 = ship.cargo.inventory.iter()
+                .filter(|item| item.symbol == recipe.input)
+                .map(|item| item.units)
+                .sum();
+            let units = available.min(remaining);
+            if units > 0 {
+                transfers.push(InputTransfer { from_ship: ship.symbol.clone(), good: recipe.input.clone(), units });
+                remaining -= units;
+            }
+        }
+        transfers
+    }
+
+    /// Compare the refined output against selling the raw input at the given sell prices
+    pub fn compare(&self, produce: &str, input_price: Option<f64>, output_price: Option<f64>) -> RefineDecision {
+        let (Some(recipe), Some(input_price), Some(output_price)) = (self.recipe(produce), input_price, output_price) else {
+            return RefineDecision::Unknown;
+        };
+        let gain = recipe.output_units as f64 * output_price - recipe.input_units as f64 * input_price;
+        o_debug!("⚖️ Refining {} {} → {} {}: {:.0} vs {:.0} raw",
+                recipe.input_units, recipe.input, recipe.output_units, recipe.produce,
+                recipe.output_units as f64 * output_price, recipe.input_units as f64 * input_price);
+        if gain > 0.0 {
+            RefineDecision::Refine { gain_per_refine: gain }
+        } else {
+            RefineDecision::SellRaw { loss_per_refine: -gain }
+        }
+    }
+}
+
+/// Whether a ship carries a refinery module
+pub fn has_refinery(ship: &Ship) -> bool {
+    ship.modules.iter().any(|module| module.symbol.contains("REFINERY"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recipe_math() {
+        let recipe = recipe_for("IRON").expect("iron recipe");
+        assert_eq!(recipe.input, "IRON_ORE");
+        assert_eq!(recipe_for_input("HYDROCARBON").map(|recipe| recipe.produce), Some("FUEL".to_string()));
+        assert_eq!(recipe.refines_for_output(25), 3);
+        assert_eq!(recipe.input_for_output(25), 90);
+    }
+
+    #[tokio::test]
+    async fn test_compare_refined_and_raw_prices() {
+        let planner = RefineryPlanner::new();
+        assert_eq!(planner.compare("IRON", Some(10.0), Some(50.0)), RefineDecision::Refine { gain_per_refine: 200.0 });
+        assert_eq!(planner.compare("IRON", Some(20.0), Some(50.0)), RefineDecision::SellRaw { loss_per_refine: 100.0 });
+        assert_eq!(planner.compare("IRON", None, Some(50.0)), RefineDecision::Unknown);
+    }
+}
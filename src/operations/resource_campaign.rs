@@ -0,0 +1,374 @@
+// Resource campaign engine - gather any trade good with a set of ships across candidate waypoints
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use chrono::{DateTime, Utc};
+use tokio::sync::mpsc;
+use crate::client::priority_client::{ApiPriority, PriorityApiClient};
+use crate::models::{Ship, Survey, Waypoint};
+use crate::operations::{has_siphon_capability, is_gas_giant, is_siphonable, MiningStation};
+use crate::storage::{get_extraction_log, get_survey_pool, is_surveyor, ExtractionLog, SurveyCache, SURVEY_CACHE_PATH};
+use crate::{o_debug, o_info};
+use crate::events::GameEvent;
+
+/// A ship moves on to the next candidate after this many extractions in a row without the good
+pub const MAX_OFF_TARGET_STREAK: u32 = 5;
+/// Hours a cached system scan stays fresh when looking up candidate waypoints
+const WAYPOINT_CACHE_HOURS: i64 = 12;
+
+/// Waypoint deposit traits and the ores found there
+const DEPOSIT_TRAITS: [(&str, &[&str]); 3] = [
+    ("COMMON_METAL_DEPOSITS", &["IRON_ORE", "COPPER_ORE", "ALUMINUM_ORE"]),
+    ("PRECIOUS_METAL_DEPOSITS", &["SILVER_ORE", "GOLD_ORE", "PLATINUM_ORE"]),
+    ("RARE_METAL_DEPOSITS", &["URANITE_ORE", "MERITIUM_ORE"]),
+];
+
+/// Progress reported while a campaign runs
+#[derive(Debug, Clone, PartialEq)]
+pub enum CampaignEvent {
+    Started { good: String, target: i32, ships: Vec<String>, waypoints: Vec<String> },
+    Moving { ship: String, waypoint: String },
+    Extracted { ship: String, waypoint: String, units: i32, total: i32 },
+    OffTarget { ship: String, waypoint: String, good: String, units: i32 },
+    ShipStopped { ship: String, reason: String },
+    Finished { collected: i32, target: i32, success: bool },
+}
+
+#[derive(Debug, Clone)]
+pub struct CampaignReport {
+    pub good: String,
+    pub target_quantity: i32,
+    pub collected: i32,
+    pub extractions: u32,
+    pub ships_used: Vec<String>,
+    pub deadline_missed: bool,
+}
+
+impl CampaignReport {
+    pub fn success(&self) -> bool {
+        self.collected >= self.target_quantity
+    }
+}
+
+/// One ship's place in the campaign
+#[derive(Debug, Clone, Default)]
+struct ShipProgress {
+    site: usize, // index into the candidate waypoints
+    off_target_streak: u32,
+    ready_at: Option<Instant>,
+}
+
+pub struct ResourceCampaign {
+    pub good: String,
+    pub target_quantity: i32,
+    /// Ships to use; empty means every ship able to extract the good
+    pub ships: Vec<String>,
+    /// Waypoints to work, best first; empty means look them up in the waypoint store
+    pub candidate_waypoints: Vec<String>,
+    /// System searched for candidates; defaults to the first ship's system
+    pub system_symbol: Option<String>,
+    /// Units gathered before this run, e.g. by a goal that was restarted
+    pub already_collected: i32,
+    pub deadline: Option<DateTime<Utc>>,
+    pub use_surveys: bool,
+    events: Option<mpsc::UnboundedSender<CampaignEvent>>,
+}
+
+impl ResourceCampaign {
+    pub fn new(good: &str, target_quantity: i32) -> Self {
+        Self {
+            good: good.to_string(),
+            target_quantity,
+            ships: Vec::new(),
+            candidate_waypoints: Vec::new(),
+            system_symbol: None,
+            already_collected: 0,
+            deadline: None,
+            use_surveys: true,
+            events: None,
+        }
+    }
+
+    /// Receive progress events; the channel closes when the campaign finishes
+    pub fn subscribe(&mut self) -> mpsc::UnboundedReceiver<CampaignEvent> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        self.events = Some(sender);
+        receiver
+    }
+
+    fn emit(&self, event: CampaignEvent) {
+        if let Some(sender) = &self.events {
+            let _ = sender.send(event);
+        }
+    }
+
+    /// Whether a ship has the mounts to gather this campaign's good
+    pub fn can_extract(&self, ship: &Ship) -> bool {
+        if is_siphonable(&self.good) {
+            has_siphon_capability(ship)
+        } else {
+            MiningStation::is_miner(ship)
+        }
+    }
+
+    /// Waypoints where a good can be gathered, best first: sites with a matching deposit
+    /// trait ahead of the rest, then by proven yield per cooldown-second
+    pub fn rank_candidates(good: &str, waypoints: &[Waypoint], log: &ExtractionLog) -> Vec<String> {
+        let has_deposit = |waypoint: &Waypoint| {
+            DEPOSIT_TRAITS.iter()
+                .filter(|(_, goods)| goods.contains(&good))
+                .any(|(deposit, _)| waypoint.traits.iter().any(|t| t.symbol == *deposit))
+        };
+        let mut candidates: Vec<(&Waypoint, bool, f64)> = waypoints.iter()
+            .filter(|waypoint| if is_siphonable(good) {
+                is_gas_giant(waypoint)
+            } else {
+                waypoint.waypoint_type.contains("ASTEROID")
+            })
+            .map(|waypoint| (waypoint, has_deposit(waypoint), log.proven_yield_rate(&waypoint.symbol).unwrap_or(0.0)))
+            .collect();
+        candidates.sort_by(|a, b| b.1.cmp(&a.1)
+            .then(b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal)));
+        candidates.into_iter().map(|(waypoint, _, _)| waypoint.symbol.clone()).collect()
+    }
+
+    /// Candidate waypoints for the good in a system, scanning it only if the waypoint store is stale
+    pub async fn find_candidates(&self, client: &PriorityApiClient, system_symbol: &str) -> Result<Vec<String>, String> {
+        let mut store = SurveyCache::new(SURVEY_CACHE_PATH, WAYPOINT_CACHE_HOURS);
+        if store.should_scan_system(system_symbol) {
+            o_info!("📡 Scanning system {} for {} sites", system_symbol, self.good);
+            let waypoints = client.get_system_waypoints(system_symbol, None).await.map_err(|e| e.to_string())?;
+            store.cache_system_waypoints(system_symbol, waypoints).map_err(|e| e.to_string())?;
+        }
+        let waypoints = store.get_cached_waypoints(system_symbol).cloned().unwrap_or_default();
+        Ok(Self::rank_candidates(&self.good, &waypoints, get_extraction_log()))
+    }
+
+    /// Run until the target is gathered, the deadline passes or no ship can continue
+    pub async fn run(mut self, client: &PriorityApiClient) -> Result<CampaignReport, String> {
+        let fleet = client.get_ships().await.map_err(|e| e.to_string())?;
+        let ships: Vec<String> = fleet.iter()
+            .filter(|ship| self.ships.is_empty() || self.ships.contains(&ship.symbol))
+            .filter(|ship| self.can_extract(ship))
+            .map(|ship| ship.symbol.clone())
+            .collect();
+        if ships.is_empty() {
+            return Err(format!("No ships able to gather {}", self.good));
+        }
+
+        if self.candidate_waypoints.is_empty() {
+            let system_symbol = match &self.system_symbol {
+                Some(system) => system.clone(),
+                None => fleet.iter()
+                    .find(|ship| ships.contains(&ship.symbol))
+                    .map(|ship| ship.nav.system_symbol.clone())
+                    .unwrap_or_default(),
+            };
+            self.candidate_waypoints = self.find_candidates(client, &system_symbol).await?;
+        }
+        if self.candidate_waypoints.is_empty() {
+            return Err(format!("No candidate waypoints for {}", self.good));
+        }
+
+        o_info!("🎯 Campaign: {} {} with {} ship(s) across {} site(s)",
+               self.target_quantity, self.good, ships.len(), self.candidate_waypoints.len());
+        self.emit(CampaignEvent::Started {
+            good: self.good.clone(),
+            target: self.target_quantity,
+            ships: ships.clone(),
+            waypoints: self.candidate_waypoints.clone(),
+        });
+
+        let mut collected = self.already_collected;
+        let mut extractions = 0;
+        let mut deadline_missed = false;
+        let mut active = ships.clone();
+        let mut progress: HashMap<String, ShipProgress> = HashMap::new();
+
+        while collected < self.target_quantity && !active.is_empty() {
+            if self.deadline.is_some_and(|deadline| Utc::now() >= deadline) {
+                o_info!("⏰ Campaign deadline reached with {}/{} {}", collected, self.target_quantity, self.good);
+                deadline_missed = true;
+                break;
+            }
+
+            let mut stopped = Vec::new();
+            for ship_symbol in &active {
+                let state = progress.entry(ship_symbol.clone()).or_default();
+                if state.ready_at.is_some_and(|ready| ready > Instant::now()) {
+                    continue;
+                }
+                let waypoint = self.candidate_waypoints[state.site].clone();
+
+                let ship = match client.get_ship(ship_symbol).await.map_err(|e| e.to_string()) {
+                    Ok(ship) => ship,
+                    Err(e) => {
+                        stopped.push((ship_symbol.clone(), e));
+                        continue;
+                    }
+                };
+
+                // Travel is started without waiting so ships move and extract in parallel
+                if ship.nav.status == "IN_TRANSIT" || ship.nav.waypoint_symbol != waypoint {
+                    match self.move_to(client, &ship, &waypoint).await {
+                        Ok(ready_at) => state.ready_at = Some(ready_at),
+                        Err(e) => stopped.push((ship_symbol.clone(), e)),
+                    }
+                    continue;
+                }
+                if ship.nav.status == "DOCKED"
+                    && let Err(e) = client.orbit_ship_with_priority(ship_symbol, ApiPriority::ActiveGoal).await.map_err(|e| e.to_string()) {
+                    stopped.push((ship_symbol.clone(), e));
+                    continue;
+                }
+
+                let (symbol, units, cooldown, hold_full) = match self.extract(client, &ship, &waypoint, &fleet).await {
+                    Ok(outcome) => outcome,
+                    Err(e) if e.contains("cooldown") => {
+                        o_debug!("⏳ {} still on cooldown", ship_symbol);
+                        state.ready_at = Some(Instant::now() + Duration::from_secs_f64(ship.cooldown.remaining_seconds.max(5.0)));
+                        continue;
+                    }
+                    Err(e) => {
+                        stopped.push((ship_symbol.clone(), e));
+                        continue;
+                    }
+                };
+                extractions += 1;
+                state.ready_at = Some(Instant::now() + Duration::from_secs_f64(cooldown.max(0.0)));
+
+                if symbol == self.good {
+                    collected += units;
+                    state.off_target_streak = 0;
+                    o_info!("💎 {} gathered {} {} at {} (total: {}/{})",
+                           ship_symbol, units, symbol, waypoint, collected, self.target_quantity);
+                    self.emit(CampaignEvent::Extracted { ship: ship_symbol.clone(), waypoint: waypoint.clone(), units, total: collected });
+                } else {
+                    state.off_target_streak += 1;
+                    o_debug!("⚪ {} got {} {} at {} (not {})", ship_symbol, units, symbol, waypoint, self.good);
+                    self.emit(CampaignEvent::OffTarget { ship: ship_symbol.clone(), waypoint: waypoint.clone(), good: symbol, units });
+                    if state.off_target_streak >= MAX_OFF_TARGET_STREAK {
+                        state.off_target_streak = 0;
+                        state.site += 1;
+                        match self.candidate_waypoints.get(state.site) {
+                            Some(next) => o_info!("🔀 {} moving on from {} to {}", ship_symbol, waypoint, next),
+                            None => stopped.push((ship_symbol.clone(), format!("no candidate site yields {}", self.good))),
+                        }
+                    }
+                }
+
+                if hold_full {
+                    stopped.push((ship_symbol.clone(), "cargo full".to_string()));
+                }
+                if collected >= self.target_quantity {
+                    break;
+                }
+            }
+
+            for (ship_symbol, reason) in stopped {
+                o_info!("🛑 {} leaves the {} campaign: {}", ship_symbol, self.good, reason);
+                active.retain(|symbol| *symbol != ship_symbol);
+                self.emit(CampaignEvent::ShipStopped { ship: ship_symbol, reason });
+            }
+
+            // Wait for the next ship to arrive or come off cooldown
+            if collected < self.target_quantity && !active.is_empty() {
+                let now = Instant::now();
+                let next_ready = active.iter()
+                    .filter_map(|symbol| progress.get(symbol).and_then(|state| state.ready_at))
+                    .map(|ready| ready.saturating_duration_since(now))
+                    .min()
+                    .unwrap_or_default();
+                tokio::time::sleep(next_ready.max(Duration::from_secs(2))).await;
+            }
+        }
+
+        let report = CampaignReport {
+            good: self.good.clone(),
+            target_quantity: self.target_quantity,
+            collected,
+            extractions,
+            ships_used: ships,
+            deadline_missed,
+        };
+        o_info!("📊 Campaign finished: {}/{} {} in {} extractions", collected, self.target_quantity, self.good, extractions);
+        self.emit(CampaignEvent::Finished { collected, target: self.target_quantity, success: report.success() });
+        Ok(report)
+    }
+
+    /// Start a ship toward a waypoint and return when it will arrive
+    async fn move_to(&self, client: &PriorityApiClient, ship: &Ship, waypoint: &str) -> Result<Instant, String> {
+        let arrival = if ship.nav.status == "IN_TRANSIT" {
+            ship.nav.route.arrival.clone()
+        } else {
+            if ship.nav.status == "DOCKED" {
+                client.orbit_ship_with_priority(&ship.symbol, ApiPriority::ActiveGoal).await.map_err(|e| e.to_string())?;
+            }
+            o_info!("🚀 {} heading to {} for {}", ship.symbol, waypoint, self.good);
+            self.emit(CampaignEvent::Moving { ship: ship.symbol.clone(), waypoint: waypoint.to_string() });
+            client.navigate_ship_with_priority(&ship.symbol, waypoint, ApiPriority::ActiveGoal).await
+                .map_err(|e| e.to_string())?
+                .nav.route.arrival
+        };
+        let remaining = chrono::DateTime::parse_from_rfc3339(&arrival)
+            .map(|arrival| (arrival.with_timezone(&Utc) - Utc::now()).num_seconds().max(0) as u64 + 3)
+            .unwrap_or(30);
+        Ok(Instant::now() + Duration::from_secs(remaining))
+    }
+
+    /// Extract or siphon once; returns the yielded good, units, cooldown seconds and whether the hold is full
+    async fn extract(&self, client: &PriorityApiClient, ship: &Ship, waypoint: &str, fleet: &[Ship]) -> Result<(String, i32, f64, bool), String> {
+        if is_siphonable(&self.good) {
+            let data = client.siphon_resources_with_priority(&ship.symbol, ApiPriority::ActiveGoal).await
+                .map_err(|e| e.to_string())?;
+            let yield_item = &data.siphon.extraction_yield;
+            crate::events::publish(GameEvent::extraction(&ship.symbol, waypoint, yield_item));
+            return Ok((yield_item.symbol.clone(), yield_item.units, data.cooldown.remaining_seconds,
+                       data.cargo.units >= data.cargo.capacity));
+        }
+
+        let pool = get_survey_pool();
+        let survey = if self.use_surveys { self.pooled_survey(client, ship, waypoint, fleet).await } else { None };
+        let data = match client.extract_resources_with_priority(&ship.symbol, survey.as_ref(), ApiPriority::ActiveGoal).await
+            .map_err(|e| e.to_string()) {
+            Ok(data) => data,
+            Err(e) => {
+                if let Some(survey) = &survey {
+                    pool.handle_extraction_error(&survey.signature, &e);
+                }
+                return Err(e);
+            }
+        };
+        if let Some(survey) = &survey {
+            pool.record_extraction(&survey.signature);
+        }
+        get_extraction_log().record_extraction(ship, waypoint, survey.as_ref().map(|survey| survey.signature.as_str()), &data);
+        let yield_item = &data.extraction.extraction_yield;
+        Ok((yield_item.symbol.clone(), yield_item.units, data.cooldown.remaining_seconds,
+            data.cargo.units >= data.cargo.capacity))
+    }
+
+    /// Most valuable pooled survey for the good, refilling the pool from a surveyor on
+    /// site when it has none. None means extract unsurveyed.
+    async fn pooled_survey(&self, client: &PriorityApiClient, ship: &Ship, waypoint: &str, fleet: &[Ship]) -> Option<Survey> {
+        let pool = get_survey_pool();
+        let wanted = vec![self.good.clone()];
+        if pool.has_survey_for(waypoint, &wanted) {
+            return pool.choose_survey(waypoint, &wanted);
+        }
+
+        // Prefer the extracting ship itself, then any other surveyor already on site
+        let surveyor = if is_surveyor(ship) {
+            ship.symbol.clone()
+        } else {
+            fleet.iter()
+                .find(|other| is_surveyor(other) && other.nav.waypoint_symbol == waypoint && other.nav.status != "IN_TRANSIT")
+                .map(|other| other.symbol.clone())?
+        };
+        o_debug!("🔍 {} surveying {} for {}", surveyor, waypoint, self.good);
+        if let Err(e) = pool.replenish(client, &surveyor).await.map_err(|e| e.to_string()) {
+            o_debug!("⚠️ Survey failed: {} - extracting unsurveyed", e);
+            return None;
+        }
+        pool.choose_survey(waypoint, &wanted)
+    }
+}
@@ -33,7 +33,11 @@ impl<'a> ShipOperations<'a> {
         })
     }
 
+    pub fn has_siphon_capability(&self, ship: &Ship) -> bool {
+        crate::operations::has_siphon_capability(ship)
+    }
+
     pub fn is_hauler(&self, ship: &Ship) -> bool {
-        ship.cargo.capacity >= 20 && !self.has_mining_capability(ship)
+        ship.cargo.capacity >= 20 && !self.has_mining_capability(ship) && !self.has_siphon_capability(ship)
     }
 }
\ No newline at end of file
@@ -3,11 +3,15 @@ use crate::client::SpaceTradersClient;
 use crate::{o_error, o_summary, o_info, o_debug};
 use crate::models::*;
 use crate::operations::NavigationPlanner;
-use crate::storage::CooldownStore;
+use crate::storage::{CooldownStore, get_extraction_log, get_survey_pool, is_surveyor};
 use crate::config::SpaceTradersConfig;
+use crate::control::get_control;
+use crate::events::{self, GameEvent};
+use std::collections::VecDeque;
 use tokio::sync::mpsc;
 use tokio::time::{Duration, Instant, sleep};
 use chrono;
+use serde::{Deserialize, Serialize};
 #[derive(Debug)]
 pub struct ShipActorError(pub String);
 
@@ -34,13 +38,18 @@ impl From<Box<dyn std::error::Error + Send + Sync>> for ShipActorError {
 unsafe impl Send for ShipActorError {}
 unsafe impl Sync for ShipActorError {}
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ShipAction {
     Mine { 
         target: String,
         needed_materials: Vec<String>,
         contract_id: String,
     },
+    Siphon {
+        target: String,
+        needed_materials: Vec<String>,
+        contract_id: String,
+    },
     Navigate { 
         destination: String 
     },
@@ -48,7 +57,7 @@ pub enum ShipAction {
         systems: Vec<String> 
     },
     Trade { 
-        items: Vec<String>,
+        orders: Vec<crate::operations::TradeOrder>,
         marketplace: String,
     },
     Refuel { 
@@ -73,6 +82,12 @@ pub enum ShipAction {
     JettisonCargo {
         contract_materials: Vec<String>,
     },
+    TransferCargo {
+        to_ship: String,
+    },
+    Repair {
+        shipyard: String,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -93,10 +108,127 @@ pub enum ShipActorStatus {
     Error(String),
 }
 
+impl ShipActorStatus {
+    /// Short name without the error text, for metrics labels
+    pub fn label(&self) -> &'static str {
+        match self {
+            ShipActorStatus::Idle => "idle",
+            ShipActorStatus::Working => "working",
+            ShipActorStatus::OnCooldown => "on_cooldown",
+            ShipActorStatus::Navigating => "navigating",
+            ShipActorStatus::Error(_) => "error",
+        }
+    }
+}
+
+/// Identifies an action from the moment the coordinator sends it until its outcome is reported
+pub type ActionId = u64;
+
+/// Messages the coordinator sends to a ship actor
+#[derive(Debug, Clone)]
+pub enum ShipCommand {
+    /// Run after everything already queued
+    Enqueue { id: ActionId, action: ShipAction },
+    /// Stop the action in progress; queued actions still run
+    CancelCurrent,
+    /// Drop every queued action; the one in progress carries on
+    ClearQueue,
+    /// Stop the action in progress and run this one next
+    Preempt { id: ActionId, action: ShipAction },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActionOutcome {
+    Completed,
+    Failed(String),
+    Cancelled,
+    Preempted { by: ActionId },
+    /// Stopped by a shutdown; the mission is kept so the next run resumes it
+    Interrupted,
+}
+
+/// What happened to an action, sent back to the coordinator once it is finished with
+#[derive(Debug, Clone)]
+pub struct ActionReport {
+    pub ship_symbol: String,
+    pub action_id: ActionId,
+    pub action: ShipAction,
+    pub outcome: ActionOutcome,
+    /// Market transactions made while carrying out the action
+    pub transactions: Vec<MarketTransaction>,
+    /// Set when a repair was priced above the ship's scrap value and declined
+    pub scrap_advice: Option<crate::operations::ScrapAdvice>,
+}
+
+/// What a control message means for the action in progress
+#[derive(Debug, Clone, PartialEq)]
+pub enum ControlEffect {
+    None,
+    CancelCurrent,
+    Preempt { by: ActionId },
+    /// Queued actions that were dropped and need reporting as cancelled
+    Cleared(Vec<(ActionId, ShipAction)>),
+}
+
+/// Actions waiting for a ship, in the order they will run
+#[derive(Debug, Default)]
+pub struct ActionQueue {
+    pending: VecDeque<(ActionId, ShipAction)>,
+}
+
+impl ActionQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn apply(&mut self, command: ShipCommand) -> ControlEffect {
+        match command {
+            ShipCommand::Enqueue { id, action } => {
+                self.pending.push_back((id, action));
+                ControlEffect::None
+            }
+            ShipCommand::CancelCurrent => ControlEffect::CancelCurrent,
+            ShipCommand::ClearQueue => ControlEffect::Cleared(self.pending.drain(..).collect()),
+            ShipCommand::Preempt { id, action } => {
+                self.pending.push_front((id, action));
+                ControlEffect::Preempt { by: id }
+            }
+        }
+    }
+
+    pub fn pop_next(&mut self) -> Option<(ActionId, ShipAction)> {
+        self.pending.pop_front()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    pub fn ids(&self) -> Vec<ActionId> {
+        self.pending.iter().map(|(id, _)| *id).collect()
+    }
+}
+
+/// How the action in progress came to an end
+enum Finished {
+    Ran(Result<(), ShipActorError>),
+    Stopped(ActionOutcome),
+    ChannelClosed,
+    Shutdown,
+}
+
 pub struct ShipActor {
     ship_symbol: String,
-    action_receiver: mpsc::UnboundedReceiver<ShipAction>,
+    command_receiver: Option<mpsc::UnboundedReceiver<ShipCommand>>,
     status_sender: mpsc::UnboundedSender<(String, ShipState)>,
+    report_sender: mpsc::UnboundedSender<ActionReport>,
+    current_action: Option<ShipAction>,
+    transactions: Vec<MarketTransaction>,
+    scrap_advice: Option<crate::operations::ScrapAdvice>,
     client: SpaceTradersClient,
     navigation_planner: NavigationPlanner,
     cooldown_until: Option<Instant>,
@@ -106,8 +238,9 @@ pub struct ShipActor {
 impl ShipActor {
     pub fn new(
         ship_symbol: String,
-        action_receiver: mpsc::UnboundedReceiver<ShipAction>,
+        command_receiver: mpsc::UnboundedReceiver<ShipCommand>,
         status_sender: mpsc::UnboundedSender<(String, ShipState)>,
+        report_sender: mpsc::UnboundedSender<ActionReport>,
         client: SpaceTradersClient,
         config: SpaceTradersConfig,
     ) -> Self {        
@@ -117,8 +250,12 @@ impl ShipActor {
         
         Self {
             ship_symbol,
-            action_receiver,
+            command_receiver: Some(command_receiver),
             status_sender,
+            report_sender,
+            current_action: None,
+            transactions: Vec::new(),
+            scrap_advice: None,
             client,
             navigation_planner,
             cooldown_until: None,
@@ -129,6 +266,12 @@ impl ShipActor {
     pub async fn run(&mut self) {
         o_debug!("🤖 {} actor started", self.ship_symbol);
         
+        let Some(mut commands) = self.command_receiver.take() else {
+            o_error!("⚠️ {} actor already ran", self.ship_symbol);
+            return;
+        };
+        let mut queue = ActionQueue::new();
+        
         // Check for persisted cooldowns
         if let Some(remaining) = self.cooldown_store.get_remaining_cooldown(&self.ship_symbol) {
             self.cooldown_until = Some(Instant::now() + Duration::from_secs_f64(remaining));
@@ -147,63 +290,131 @@ impl ShipActor {
                     // Update status to on cooldown
                     self.send_status(ShipActorStatus::OnCooldown).await;
                     
-                    // Wait for cooldown or new action (whichever comes first)
+                    // Wait for cooldown or new command (whichever comes first)
                     tokio::select! {
                         _ = sleep(remaining) => {
                             self.cooldown_until = None;
                             o_debug!("✅ {} cooldown complete", self.ship_symbol);
+                            events::publish(GameEvent::CooldownEnded { ship_symbol: self.ship_symbol.clone() });
                             
                             // Clear persisted cooldown
                             if let Err(e) = self.cooldown_store.clear_cooldown(&self.ship_symbol) {
                                 o_error!("⚠️ Failed to clear cooldown for {}: {}", self.ship_symbol, e);
                             }
                             
-                            self.send_status(ShipActorStatus::Idle).await;
+                            if queue.is_empty() {
+                                self.send_status(ShipActorStatus::Idle).await;
+                            }
                         }
-                        action = self.action_receiver.recv() => {
-                            if let Some(action) = action {
-                                // Queue action for after cooldown
-                                o_debug!("📥 {} queued action during cooldown: {:?}", self.ship_symbol, action);
-                                continue;
-                            } else {
+                        command = commands.recv() => {
+                            let Some(command) = command else {
                                 break; // Channel closed
-                            }
+                            };
+                            // Queued actions run once the cooldown is over
+                            o_debug!("📥 {} received during cooldown: {:?}", self.ship_symbol, command);
+                            let effect = queue.apply(command);
+                            self.report_cleared(effect);
                         }
+                        _ = get_control().wait_for_shutdown() => break,
                     }
                     continue;
                 }
             }
 
-            // Wait for next action
-            match self.action_receiver.recv().await {
-                Some(action) => {
-                    o_debug!("🚀 {} executing: {:?}", self.ship_symbol, action);
-                    self.send_status(ShipActorStatus::Working).await;
-                    self.execute_action(action).await;
+            let Some((action_id, action)) = queue.pop_next() else {
+                // Wait for next command
+                let command = tokio::select! {
+                    command = commands.recv() => command,
+                    _ = get_control().wait_for_shutdown() => break,
+                };
+                match command {
+                    Some(command) => {
+                        let effect = queue.apply(command);
+                        self.report_cleared(effect);
+                    }
+                    None => {
+                        o_debug!("🛑 {} actor stopping - channel closed", self.ship_symbol);
+                        break;
+                    }
                 }
-                None => {
+                continue;
+            };
+            
+            o_debug!("🚀 {} executing #{}: {:?}", self.ship_symbol, action_id, action);
+            self.current_action = Some(action.clone());
+            self.send_status(ShipActorStatus::Working).await;
+            
+            // Control messages are handled while the action runs; stopping drops its future
+            let mut cleared = Vec::new();
+            let finished = {
+                let execution = self.execute_action(&action);
+                tokio::pin!(execution);
+                loop {
+                    tokio::select! {
+                        result = &mut execution => break Finished::Ran(result),
+                        command = commands.recv() => {
+                            let Some(command) = command else {
+                                break Finished::ChannelClosed;
+                            };
+                            match queue.apply(command) {
+                                ControlEffect::None => {}
+                                ControlEffect::Cleared(dropped) => cleared.extend(dropped),
+                                ControlEffect::CancelCurrent => break Finished::Stopped(ActionOutcome::Cancelled),
+                                ControlEffect::Preempt { by } => break Finished::Stopped(ActionOutcome::Preempted { by }),
+                            }
+                        }
+                        // API calls already sent finish in the broker; ones not yet sent are dropped with the future
+                        _ = get_control().wait_for_shutdown() => break Finished::Shutdown,
+                    }
+                }
+            };
+            self.current_action = None;
+            self.report_cleared(ControlEffect::Cleared(cleared));
+            
+            match finished {
+                Finished::Ran(result) => self.finish_action(action_id, action, result, queue.len()).await,
+                Finished::Stopped(outcome) => {
+                    o_info!("🛑 {} stopped: {:?} ({:?})", self.ship_symbol, action, outcome);
+                    self.report(action_id, action, outcome);
+                    let status = if queue.is_empty() { ShipActorStatus::Idle } else { ShipActorStatus::Working };
+                    self.send_status(status).await;
+                }
+                Finished::ChannelClosed => {
+                    self.report(action_id, action, ActionOutcome::Cancelled);
                     o_debug!("🛑 {} actor stopping - channel closed", self.ship_symbol);
                     break;
                 }
+                Finished::Shutdown => {
+                    o_info!("🛑 {} interrupted by shutdown: {:?}", self.ship_symbol, action);
+                    self.report(action_id, action, ActionOutcome::Interrupted);
+                    break;
+                }
             }
         }
+        
+        // Leave the cooldown on disk for the next run
+        if let Err(e) = self.cooldown_store.flush() {
+            o_error!("⚠️ Failed to save cooldown for {}: {}", self.ship_symbol, e);
+        }
+        o_debug!("🛑 {} actor stopped", self.ship_symbol);
     }
 
-    async fn execute_action(&mut self, action: ShipAction) {
-        let result = match &action {
+    async fn execute_action(&mut self, action: &ShipAction) -> Result<(), ShipActorError> {
+        match action {
             ShipAction::Mine { target, needed_materials, contract_id: _ } => {
                 self.execute_mining(target, needed_materials).await
             }
+            ShipAction::Siphon { target, needed_materials, contract_id: _ } => {
+                self.execute_siphoning(target, needed_materials).await
+            }
             ShipAction::Navigate { destination } => {
                 self.execute_navigation(destination).await
             }
             ShipAction::Explore { systems } => {
                 self.execute_exploration(systems).await
             }
-            ShipAction::Trade { items: _, marketplace: _ } => {
-                // TODO: Implement trading
-                o_debug!("🏪 {} trading not yet implemented", self.ship_symbol);
-                Ok(())
+            ShipAction::Trade { orders, marketplace } => {
+                self.execute_trade(marketplace, orders).await
             }
             ShipAction::Survey { target } => {
                 self.execute_survey(target).await
@@ -229,16 +440,25 @@ impl ShipActor {
             ShipAction::JettisonCargo { contract_materials } => {
                 self.execute_jettison_cargo(contract_materials).await
             }
-        };
+            ShipAction::TransferCargo { to_ship } => {
+                self.execute_transfer_cargo(to_ship).await
+            }
+            ShipAction::Repair { shipyard } => {
+                self.execute_repair(shipyard).await
+            }
+        }
+    }
 
-        let status = match result {
+    /// Record cooldowns, update status and report the outcome of a finished action
+    async fn finish_action(&mut self, action_id: ActionId, action: ShipAction, result: Result<(), ShipActorError>, queued: usize) {
+        let (status, outcome) = match result {
             Ok(()) => {
                 o_info!("✅ {} completed: {:?}", self.ship_symbol, action);
                 
                 // Pretty-print current ship status after action
                 self.print_ship_status().await;
                 
-                ShipActorStatus::Idle
+                (ShipActorStatus::Idle, ActionOutcome::Completed)
             }
             Err(e) => {
                 let error_message = e.to_string();
@@ -255,13 +475,48 @@ impl ShipActor {
                     }
                 }
                 
-                ShipActorStatus::Error(error_message)
+                (ShipActorStatus::Error(error_message.clone()), ActionOutcome::Failed(error_message))
             }
         };
         
+        self.report(action_id, action, outcome);
+        // A ship with more queued work is not free for new assignments
+        let status = if queued > 0 && matches!(status, ShipActorStatus::Idle) { ShipActorStatus::Working } else { status };
         self.send_status(status).await;
     }
 
+    fn report(&mut self, action_id: ActionId, action: ShipAction, outcome: ActionOutcome) {
+        let transactions = std::mem::take(&mut self.transactions);
+        let scrap_advice = self.scrap_advice.take();
+        let report = ActionReport { ship_symbol: self.ship_symbol.clone(), action_id, action, outcome, transactions, scrap_advice };
+        if self.report_sender.send(report).is_err() {
+            // Channel closed - coordinator is shutting down
+        }
+    }
+
+    /// Report an action that was dropped from the queue before it ran
+    fn report_unstarted(&self, action_id: ActionId, action: ShipAction) {
+        let report = ActionReport {
+            ship_symbol: self.ship_symbol.clone(),
+            action_id,
+            action,
+            outcome: ActionOutcome::Cancelled,
+            transactions: Vec::new(),
+            scrap_advice: None,
+        };
+        if self.report_sender.send(report).is_err() {
+            // Channel closed - coordinator is shutting down
+        }
+    }
+
+    fn report_cleared(&self, effect: ControlEffect) {
+        if let ControlEffect::Cleared(dropped) = effect {
+            for (action_id, action) in dropped {
+                self.report_unstarted(action_id, action);
+            }
+        }
+    }
+
     async fn execute_mining(&mut self, target: &str, needed_materials: &[String]) -> Result<(), ShipActorError> {
         // First, check if we're at the mining location
         let ship = match self.client.get_ship(&self.ship_symbol).await {
@@ -269,11 +524,28 @@ impl ShipActor {
             Err(e) => return Err(ShipActorError(format!("Could not get ship data: {}", e)))
         };
         
+        self.move_into_orbit_at(&ship, target, "mining").await?;
+        
+        // Surveyors refill the fleet pool when it has nothing for this asteroid
+        let pool = get_survey_pool();
+        if !pool.has_survey_for(target, needed_materials) && is_surveyor(&ship) {
+            o_info!("🔍 {} refilling survey pool at {} before mining", self.ship_symbol, target);
+            return self.execute_survey(target).await;
+        }
+        
+        // Only use a survey when it is worth more than an unsurveyed extraction
+        let survey = pool.choose_survey(target, needed_materials);
+        o_info!("⛏️ {} performing extraction at {}", self.ship_symbol, target);
+        self.attempt_extraction_with_retry(&ship, target, survey.as_ref(), needed_materials).await
+    }
+
+    /// Navigate to an extraction target if needed and make sure the ship is in orbit there
+    async fn move_into_orbit_at(&mut self, ship: &Ship, target: &str, activity: &str) -> Result<(), ShipActorError> {
         // Navigate to target if we're not already there
         if ship.nav.waypoint_symbol != target {
-            o_info!("🧭 {} navigating to mining target {}", self.ship_symbol, target);
+            o_info!("🧭 {} navigating to {} target {}", self.ship_symbol, activity, target);
             if let Err(e) = self.execute_navigation(target).await {
-                return Err(ShipActorError(format!("Failed to navigate to mining location {}: {}", target, e)));
+                return Err(ShipActorError(format!("Failed to navigate to {} location {}: {}", activity, target, e)));
             }
             
             // Wait for arrival if in transit
@@ -295,10 +567,11 @@ impl ShipActor {
                 }
                 break;
             }
+            self.announce_arrival(target);
             
-            // Need to orbit for mining
+            // Need to orbit for extraction
             match self.client.orbit_ship(&self.ship_symbol).await {
-                Ok(_) => o_info!("🛸 {} in orbit for mining", self.ship_symbol),
+                Ok(_) => o_info!("🛸 {} in orbit for {}", self.ship_symbol, activity),
                 Err(e) => {
                     if !e.to_string().contains("already in orbit") {
                         o_error!("⚠️ {} orbit failed: {}", self.ship_symbol, e);
@@ -306,7 +579,7 @@ impl ShipActor {
                 }
             }
         } else {
-            o_info!("✅ {} already at mining target {}", self.ship_symbol, target);
+            o_info!("✅ {} already at {} target {}", self.ship_symbol, activity, target);
             
             // Ensure we're in orbit even if already at location
             let current_ship = self.client.get_ship(&self.ship_symbol).await
@@ -314,22 +587,40 @@ impl ShipActor {
             
             if current_ship.nav.status == "DOCKED" {
                 match self.client.orbit_ship(&self.ship_symbol).await {
-                    Ok(_) => o_info!("🛸 {} now in orbit for mining", self.ship_symbol),
+                    Ok(_) => o_info!("🛸 {} now in orbit for {}", self.ship_symbol, activity),
                     Err(e) => {
                         if !e.to_string().contains("already in orbit") {
-                            return Err(ShipActorError(format!("Failed to orbit for mining: {}", e)));
+                            return Err(ShipActorError(format!("Failed to orbit for {}: {}", activity, e)));
                         }
                     }
                 }
             }
         }
         
-        o_info!("⛏️ {} performing extraction at {}", self.ship_symbol, target);
-        
         // Wait for any in-transit status before attempting extraction
-        self.wait_for_arrival().await?;
+        self.wait_for_arrival().await
+    }
+
+    async fn execute_siphoning(&mut self, target: &str, needed_materials: &[String]) -> Result<(), ShipActorError> {
+        let ship = self.client.get_ship(&self.ship_symbol).await
+            .map_err(|e| ShipActorError(format!("Could not get ship data: {}", e)))?;
         
-        self.attempt_extraction_with_retry(needed_materials).await
+        self.move_into_orbit_at(&ship, target, "siphoning").await?;
+        
+        o_info!("🪐 {} siphoning gas at {}", self.ship_symbol, target);
+        let siphon_data = self.client.siphon_resources(&self.ship_symbol).await
+            .map_err(|e| ShipActorError(e.to_string()))?;
+        
+        let yield_info = &siphon_data.siphon.extraction_yield;
+        o_info!("🪐 {} siphoned {} x{}", self.ship_symbol, yield_info.symbol, yield_info.units);
+        events::publish(GameEvent::extraction(&self.ship_symbol, target, yield_info));
+        
+        self.start_cooldown(siphon_data.cooldown.remaining_seconds);
+        
+        if needed_materials.contains(&yield_info.symbol) {
+            o_info!("🎯 {} found CONTRACT MATERIAL: {}! ✨", self.ship_symbol, yield_info.symbol);
+        }
+        Ok(())
     }
 
     async fn execute_exploration(&mut self, systems: &[String]) -> Result<(), ShipActorError> {
@@ -382,6 +673,7 @@ impl ShipActor {
         match self.client.create_survey(&self.ship_symbol).await {
             Ok(survey_data) => {
                 o_info!("🔍 {} surveyed {} - found {} deposits", self.ship_symbol, target, survey_data.surveys.len());
+                get_survey_pool().add_surveys(&self.ship_symbol, &survey_data.surveys);
                 
                 // Set cooldown
                 if survey_data.cooldown.remaining_seconds > 0.0 {
@@ -413,6 +705,122 @@ impl ShipActor {
         }
     }
 
+    /// Buy and sell at a marketplace in trade-volume sized lots, keeping to each order's price limit
+    async fn execute_trade(&mut self, marketplace: &str, orders: &[crate::operations::TradeOrder]) -> Result<(), ShipActorError> {
+        use crate::operations::{plan_trade_lots, quoted_price, TradeSide};
+        
+        o_info!("🏪 {} trading {} orders at {}", self.ship_symbol, orders.len(), marketplace);
+        self.send_status(ShipActorStatus::Navigating).await;
+        let client = crate::client::priority_client::PriorityApiClient::new(self.client.clone());
+        crate::operations::outfitting::travel_and_dock(&client, &self.ship_symbol, marketplace).await.map_err(ShipActorError)?;
+        self.send_status(ShipActorStatus::Working).await;
+        
+        let ship = self.client.get_ship(&self.ship_symbol).await
+            .map_err(|e| ShipActorError(format!("Failed to get ship status: {}", e)))?;
+        let market = self.client.get_market(&ship.nav.system_symbol, marketplace).await
+            .map_err(|e| ShipActorError(format!("Failed to get market {}: {}", marketplace, e)))?;
+        get_survey_pool().record_market_prices(&market);
+        
+        let (lots, skipped) = plan_trade_lots(orders, &market, &ship.cargo);
+        for reason in &skipped {
+            o_info!("   ⏭️ {} skipping: {}", self.ship_symbol, reason);
+        }
+        
+        // Prices move as we trade, so every lot after the first is re-quoted and an order
+        // stops before a lot its limit no longer covers
+        let mut stopped_orders = Vec::new();
+        let mut fills: Vec<MarketTransaction> = Vec::new();
+        for lot in lots {
+            if stopped_orders.contains(&lot.order) {
+                continue;
+            }
+            if !fills.is_empty() {
+                let market = self.client.get_market(&ship.nav.system_symbol, marketplace).await
+                    .map_err(|e| ShipActorError(format!("Failed to get market {}: {}", marketplace, e)))?;
+                get_survey_pool().record_market_prices(&market);
+                let quote = quoted_price(&market, lot.side, &lot.good);
+                if !quote.is_some_and(|price| orders[lot.order].accepts(price)) {
+                    o_info!("   🛑 {} {} price moved past the limit, stopping the order", self.ship_symbol, lot.good);
+                    stopped_orders.push(lot.order);
+                    continue;
+                }
+            }
+            let transaction: MarketTransaction = match lot.side {
+                TradeSide::Buy => self.client.purchase_cargo(&self.ship_symbol, &lot.good, lot.units).await
+                    .map(|purchase| purchase.transaction),
+                TradeSide::Sell => self.client.sell_cargo(&self.ship_symbol, &lot.good, lot.units).await
+                    .map(|sale| sale.transaction.into()),
+            }.map_err(|e| ShipActorError(format!("{:?} {} x{} failed: {}", lot.side, lot.good, lot.units, e)))?;
+            
+            o_info!("   💱 {} {:?} {} x{} @ {} = {}", self.ship_symbol, lot.side, transaction.trade_symbol,
+                   transaction.units, transaction.price_per_unit, transaction.total_price);
+            fills.push(transaction);
+        }
+        
+        let spent: i32 = fills.iter().filter(|t| t.transaction_type == "PURCHASE").map(|t| t.total_price).sum();
+        let earned: i32 = fills.iter().filter(|t| t.transaction_type == "SELL").map(|t| t.total_price).sum();
+        self.transactions.extend(fills);
+        o_info!("🏪 {} finished trading at {}: spent {}, earned {}", self.ship_symbol, marketplace, spent, earned);
+        Ok(())
+    }
+
+    /// Repair at a shipyard unless the repair costs more than the ship is worth
+    async fn execute_repair(&mut self, shipyard: &str) -> Result<(), ShipActorError> {
+        o_info!("🔧 {} heading to {} for repairs", self.ship_symbol, shipyard);
+        self.send_status(ShipActorStatus::Navigating).await;
+        
+        let client = crate::client::priority_client::PriorityApiClient::new(self.client.clone());
+        match crate::operations::MaintenanceManager::repair_at(&client, &self.ship_symbol, shipyard).await.map_err(ShipActorError)? {
+            crate::operations::MaintenanceOutcome::AlreadySound => {
+                o_info!("✅ {} needed no repairs", self.ship_symbol);
+            }
+            crate::operations::MaintenanceOutcome::Repaired { cost } => {
+                o_info!("✅ {} repaired for {} credits", self.ship_symbol, cost);
+            }
+            crate::operations::MaintenanceOutcome::ScrapRecommended(advice) => {
+                o_info!("⚠️ {} left unrepaired: repair {} > scrap value {}", self.ship_symbol, advice.repair_cost, advice.scrap_value);
+                self.scrap_advice = Some(advice);
+            }
+        }
+        Ok(())
+    }
+
+    /// Hand as much cargo as fits to another ship at the same waypoint
+    async fn execute_transfer_cargo(&mut self, to_ship: &str) -> Result<(), ShipActorError> {
+        let ship = self.client.get_ship(&self.ship_symbol).await
+            .map_err(|e| ShipActorError(format!("Failed to get ship status: {}", e)))?;
+        let receiver = self.client.get_ship(to_ship).await
+            .map_err(|e| ShipActorError(format!("Failed to get {} status: {}", to_ship, e)))?;
+        
+        if receiver.nav.waypoint_symbol != ship.nav.waypoint_symbol || receiver.nav.status == "IN_TRANSIT" {
+            return Err(ShipActorError(format!("{} is not at {}", to_ship, ship.nav.waypoint_symbol)));
+        }
+        
+        let mut free_space = receiver.cargo.capacity - receiver.cargo.units;
+        let mut transferred = 0;
+        for item in &ship.cargo.inventory {
+            let units = item.units.min(free_space);
+            if units <= 0 {
+                continue;
+            }
+            match self.client.transfer_cargo(&self.ship_symbol, &item.symbol, units, to_ship).await {
+                Ok(_) => {
+                    o_info!("🔄 {} transferred {} x{} to {}", self.ship_symbol, item.symbol, units, to_ship);
+                    free_space -= units;
+                    transferred += units;
+                }
+                Err(e) => {
+                    o_error!("⚠️ {} failed to transfer {} to {}: {}", self.ship_symbol, item.symbol, to_ship, e);
+                }
+            }
+        }
+        
+        if transferred == 0 {
+            return Err(ShipActorError(format!("Nothing transferred to {} ({} units free)", to_ship, free_space)));
+        }
+        Ok(())
+    }
+
     async fn execute_dock(&mut self) -> Result<(), ShipActorError> {
         match self.client.dock_ship(&self.ship_symbol).await {
             Ok(_) => {
@@ -462,6 +870,7 @@ impl ShipActor {
                 }
             }
             o_info!("✅ {} arrived at destination", self.ship_symbol);
+            self.announce_arrival(&ship.nav.waypoint_symbol);
         }
         
         // Check if ship is at the correct destination for delivery
@@ -588,7 +997,7 @@ impl ShipActor {
                 let ship_state = ShipState {
                     ship,
                     cooldown_until: self.cooldown_until,
-                    current_action: None, // TODO: Track current action properly
+                    current_action: self.current_action.clone(),
                     current_plan: None,   // TODO: Track current plan properly
                     status,
                 };
@@ -664,6 +1073,9 @@ impl ShipActor {
                     symbol: "UNKNOWN".to_string(),
                     capacity: None,
                     range: None,
+                    power_output: None,
+                    condition: None,
+                    integrity: None,
                     name: "Unknown".to_string(),
                     description: "Unknown".to_string(),
                     requirements: ShipRequirements {
@@ -676,6 +1088,9 @@ impl ShipActor {
                     symbol: "UNKNOWN".to_string(),
                     capacity: None,
                     range: None,
+                    power_output: None,
+                    condition: None,
+                    integrity: None,
                     name: "Unknown".to_string(),
                     description: "Unknown".to_string(),
                     requirements: ShipRequirements {
@@ -1113,12 +1528,23 @@ impl ShipActor {
     }
     
     /// Attempt resource extraction with automatic retry for transit errors
-    async fn attempt_extraction_with_retry(&mut self, needed_materials: &[String]) -> Result<(), ShipActorError> {
+    async fn attempt_extraction_with_retry(&mut self, ship: &Ship, target: &str, survey: Option<&Survey>, needed_materials: &[String]) -> Result<(), ShipActorError> {
         // Try extraction first
-        let extraction_result = self.try_extraction().await;
+        let mut survey = survey;
+        let mut extraction_result = self.try_extraction(survey).await;
+        
+        // Exhausted or expired surveys leave the pool; mine without one instead
+        if let (Some(dead_survey), Err(error_msg)) = (survey, &extraction_result)
+            && get_survey_pool().handle_extraction_error(&dead_survey.signature, error_msg) {
+            survey = None;
+            extraction_result = self.try_extraction(None).await;
+        } else if let (Some(survey), Ok(_)) = (survey, &extraction_result) {
+            get_survey_pool().record_extraction(&survey.signature);
+        }
         
         match extraction_result {
             Ok(extraction_data) => {
+                get_extraction_log().record_extraction(ship, target, survey.map(|s| s.signature.as_str()), &extraction_data);
                 self.process_extraction_success(&extraction_data, needed_materials).await
             }
             Err(error_msg) => {
@@ -1128,9 +1554,10 @@ impl ShipActor {
                     self.wait_for_arrival().await?;
                     
                     // Retry after arrival
-                    match self.try_extraction().await {
+                    match self.try_extraction(None).await {
                         Ok(extraction_data) => {
                             o_info!("⛏️ {} extraction successful after waiting for transit", self.ship_symbol);
+                            get_extraction_log().record_extraction(ship, target, None, &extraction_data);
                             self.process_extraction_success(&extraction_data, needed_materials).await
                         }
                         Err(retry_error_msg) => {
@@ -1147,10 +1574,24 @@ impl ShipActor {
     }
     
     /// Try extraction and return either success data or error message string
-    async fn try_extraction(&self) -> Result<crate::models::ExtractionData, String> {
-        match self.client.extract_resources(&self.ship_symbol).await {
-            Ok(extraction_data) => Ok(extraction_data),
-            Err(e) => Err(e.to_string())
+    async fn try_extraction(&self, survey: Option<&Survey>) -> Result<crate::models::ExtractionData, String> {
+        let result = match survey {
+            Some(survey) => self.client.extract_resources_with_survey(&self.ship_symbol, survey).await,
+            None => self.client.extract_resources(&self.ship_symbol).await,
+        };
+        result.map_err(|e| e.to_string())
+    }
+    
+    /// Start and persist a reactor cooldown after an extraction or siphon
+    fn start_cooldown(&mut self, remaining_seconds: f64) {
+        if remaining_seconds <= 0.0 {
+            return;
+        }
+        self.cooldown_until = Some(Instant::now() + Duration::from_secs_f64(remaining_seconds));
+        
+        // Persist cooldown
+        if let Err(e) = self.cooldown_store.set_cooldown(&self.ship_symbol, remaining_seconds) {
+            o_info!("⚠️ Failed to save cooldown for {}: {}", self.ship_symbol, e);
         }
     }
     
@@ -1160,14 +1601,7 @@ impl ShipActor {
         o_info!("⛏️ {} extracted {} x{}", self.ship_symbol, yield_info.symbol, yield_info.units);
         
         // Set cooldown from extraction
-        if extraction_data.cooldown.remaining_seconds > 0.0 {
-            self.cooldown_until = Some(Instant::now() + Duration::from_secs_f64(extraction_data.cooldown.remaining_seconds));
-            
-            // Persist cooldown
-            if let Err(e) = self.cooldown_store.set_cooldown(&self.ship_symbol, extraction_data.cooldown.remaining_seconds) {
-                o_info!("⚠️ Failed to save cooldown for {}: {}", self.ship_symbol, e);
-            }
-        }
+        self.start_cooldown(extraction_data.cooldown.remaining_seconds);
         
         // Check if it's contract material
         if needed_materials.contains(&yield_info.symbol) {
@@ -1179,13 +1613,18 @@ impl ShipActor {
 
     /// Wait for ship to arrive if it's currently in transit
     async fn wait_for_arrival(&self) -> Result<(), ShipActorError> {
+        let mut travelled = false;
         loop {
             let ship = self.client.get_ship(&self.ship_symbol).await
                 .map_err(|e| ShipActorError(format!("Failed to check ship status: {}", e)))?;
             
             if ship.nav.status != "IN_TRANSIT" {
+                if travelled {
+                    self.announce_arrival(&ship.nav.waypoint_symbol);
+                }
                 break; // Ship has arrived
             }
+            travelled = true;
             
             // Parse arrival time and calculate wait
             if let Ok(arrival_time) = chrono::DateTime::parse_from_rfc3339(&ship.nav.route.arrival) {
@@ -1210,6 +1649,13 @@ impl ShipActor {
         Ok(())
     }
 
+    fn announce_arrival(&self, waypoint_symbol: &str) {
+        events::publish(GameEvent::ShipArrived {
+            ship_symbol: self.ship_symbol.clone(),
+            waypoint_symbol: waypoint_symbol.to_string(),
+        });
+    }
+
     async fn execute_jettison_cargo(&mut self, contract_materials: &[String]) -> Result<(), ShipActorError> {
         o_info!("🗑️ {} jettisoning non-contract cargo", self.ship_symbol);
         
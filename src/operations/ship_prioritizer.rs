@@ -19,6 +19,7 @@ pub struct ShipPerformanceMetrics {
 #[derive(Debug, Clone)]
 pub struct ShipCapabilities {
     pub can_mine: bool,
+    pub can_siphon: bool,
     pub can_trade: bool,
     pub can_explore: bool,
     pub can_haul: bool,
@@ -101,7 +102,8 @@ impl ShipPrioritizer {
         });
         let is_mining_role = ship.registration.role.contains("EXCAVATOR") || 
                            ship.registration.role.contains("MINER");
-        let can_mine = !is_probe && (has_mining_mounts || is_mining_role);
+        let can_siphon = !is_probe && crate::operations::has_siphon_capability(ship);
+        let can_mine = !is_probe && (has_mining_mounts || (is_mining_role && !can_siphon));
         
         
         let mining_power = if is_probe {
@@ -114,11 +116,12 @@ impl ShipPrioritizer {
         };
         
         let can_trade = ship.cargo.capacity >= 10; // Minimum cargo for meaningful trading
-        let can_haul = ship.cargo.capacity >= 20 && !can_mine; // Large cargo, not primarily a miner
+        let can_haul = ship.cargo.capacity >= 20 && !can_mine && !can_siphon; // Large cargo, not primarily an extractor
         let can_explore = ship.registration.role == "SATELLITE" || ship.frame.symbol.contains("PROBE");
         
         ShipCapabilities {
             can_mine,
+            can_siphon,
             can_trade,
             can_explore,
             can_haul,
@@ -131,6 +134,16 @@ impl ShipPrioritizer {
     fn calculate_contract_contribution(&self, ship: &Ship, needed_materials: &[String], contract: &Contract) -> f64 {
         let capabilities = self.analyze_ship_capabilities(ship);
         
+        // Gas contracts are filled by siphoning gas giants
+        if needed_materials.iter().any(|material| crate::operations::is_siphonable(material)) {
+            if !capabilities.can_siphon {
+                return if capabilities.can_haul { (capabilities.cargo_capacity as f64 / 100.0).min(0.8) } else { 0.0 };
+            }
+            let total_needed: i32 = contract.terms.deliver.iter().map(|d| d.units_required).sum();
+            let cycles_per_ship = (total_needed as f64 / capabilities.cargo_capacity.max(1) as f64).ceil();
+            return (capabilities.cargo_capacity as f64 / (cycles_per_ship * 100.0)).clamp(0.05, 1.0);
+        }
+        
         // Determine if this contract requires mining or can be fulfilled through other means
         let requires_mining = self.contract_requires_mining(needed_materials);
         
@@ -172,7 +185,7 @@ impl ShipPrioritizer {
         let mineable_materials = [
             "IRON_ORE", "COPPER_ORE", "ALUMINUM_ORE", "GOLD_ORE", "PLATINUM_ORE",
             "SILVER_ORE", "URANIUM_ORE", "PRECIOUS_STONES", "QUARTZ_SAND",
-            "SILICON_CRYSTALS", "DIAMONDS", "HYDROCARBON", "LIQUID_HYDROGEN"
+            "SILICON_CRYSTALS", "DIAMONDS"
         ];
         
         // If any needed material is mineable, this is likely a mining contract
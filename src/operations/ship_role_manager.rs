@@ -1,12 +1,15 @@
 // Ship Role Manager - Handles ship role designation and module management
 use crate::models::Ship;
 use crate::client::priority_client::PriorityApiClient;
+use crate::operations::refinery_planner::{recipe_for, RefineDecision, RefineRecipe, RefineryPlanner, RefineryStep};
+use crate::storage::get_survey_pool;
 use crate::{o_debug, o_info};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ShipRole {
     Miner,
+    Siphoner,
     Hauler,
     Refiner,
     Scout,
@@ -96,6 +99,8 @@ impl ShipRoleManager {
             m.symbol.contains("MINING") || m.symbol.contains("LASER")
         );
         
+        let has_siphon_mounts = crate::operations::has_siphon_capability(ship);
+        
         // Check for refinery module
         let has_refinery = ship.modules.iter().any(|m| 
             m.symbol.contains("REFINERY")
@@ -103,6 +108,7 @@ impl ShipRoleManager {
         
         // Check ship registration role
         match ship.registration.role.as_str() {
+            "EXCAVATOR" | "MINER" if has_siphon_mounts && !has_mining_mounts => ShipRole::Siphoner,
             "EXCAVATOR" | "MINER" => ShipRole::Miner,
             "HAULER" | "TRANSPORT" => ShipRole::Hauler,
             "REFINERY" => ShipRole::Refiner,
@@ -113,6 +119,8 @@ impl ShipRoleManager {
                     ShipRole::Refiner
                 } else if has_mining_mounts {
                     ShipRole::Miner
+                } else if has_siphon_mounts {
+                    ShipRole::Siphoner
                 } else if ship.cargo.capacity >= 30 {
                     ShipRole::Hauler
                 } else {
@@ -329,6 +337,7 @@ impl ShipRoleManager {
         for (i, capability) in candidates.iter().enumerate().take(5) {
             let role_icon = match capability.current_role {
                 ShipRole::Miner => "⛏️",
+                ShipRole::Siphoner => "🪐",
                 ShipRole::Hauler => "🚛",
                 ShipRole::Refiner => "🏭",
                 ShipRole::Scout => "🔍",
@@ -935,10 +944,11 @@ impl ShipRoleManager {
         // Get current ship data
         let refiner_ship = client.get_ship(refiner_symbol).await.map_err(|e| e.to_string())?;
         
-        // Check cargo capacity - need at least 100 for refining
-        if refiner_ship.cargo.capacity < 100 {
-            o_info!("📦 Refiner {} has {} cargo capacity, need 100+ for refining", 
-                   refiner_symbol, refiner_ship.cargo.capacity);
+        // Check cargo capacity - need room for at least one refine's worth of ore
+        let recipe = iron_recipe();
+        if refiner_ship.cargo.capacity < recipe.input_units {
+            o_info!("📦 Refiner {} has {} cargo capacity, need {}+ for refining", 
+                   refiner_symbol, refiner_ship.cargo.capacity, recipe.input_units);
             
             // Try to expand cargo capacity
             match self.expand_refiner_cargo_capacity(client, refiner_symbol).await {
@@ -975,9 +985,9 @@ impl ShipRoleManager {
             .map(|item| item.units)
             .unwrap_or(0);
             
-        if iron_ore_units < 100 {
-            o_info!("📦 Refiner {} has {} units of IRON_ORE after transfers, need 100+ for refining", 
-                   refiner_symbol, iron_ore_units);
+        if iron_ore_units < recipe.input_units {
+            o_info!("📦 Refiner {} has {} units of IRON_ORE after transfers, need {}+ for refining", 
+                   refiner_symbol, iron_ore_units, recipe.input_units);
             return Ok(false);
         }
         
@@ -1081,11 +1091,12 @@ impl ShipRoleManager {
         
         // First, find the ship with the most cargo space
         let ships = client.get_ships().await.map_err(|e| e.to_string())?;
+        let recipe = iron_recipe();
         let mut best_receiver: Option<(String, i32)> = None; // (symbol, available_space)
         
         for ship in &ships {
             let available_space = ship.cargo.capacity - ship.cargo.units;
-            if available_space >= 100 {
+            if available_space >= recipe.input_units {
                 match &best_receiver {
                     None => best_receiver = Some((ship.symbol.clone(), available_space)),
                     Some((_, current_space)) => {
@@ -1100,7 +1111,7 @@ impl ShipRoleManager {
         let (receiver_ship, available_space) = match best_receiver {
             Some(ship) => ship,
             None => {
-                o_info!("❌ No ship has 100+ cargo space to receive consolidated ore");
+                o_info!("❌ No ship has {}+ cargo space to receive consolidated ore", recipe.input_units);
                 return Ok(false);
             }
         };
@@ -1110,13 +1121,13 @@ impl ShipRoleManager {
         // Coordinate ore transfers to the receiver ship
         match self.coordinate_ore_consolidation(client, &receiver_ship).await {
             Ok(total_ore) => {
-                if total_ore >= 100 {
+                if total_ore >= recipe.input_units {
                     o_info!("✅ Consolidated {} ore units to {}", total_ore, receiver_ship);
                     
                     // Now try refining on the consolidated ship
                     return self.refine_iron_ore(client, &receiver_ship, total_ore).await;
                 } else {
-                    o_info!("⚠️ Only consolidated {} ore units, need 100+", total_ore);
+                    o_info!("⚠️ Only consolidated {} ore units, need {}+", total_ore, recipe.input_units);
                     Ok(false)
                 }
             }
@@ -1167,15 +1178,16 @@ impl ShipRoleManager {
         Ok(final_ore)
     }
 
-    /// Refine iron ore into refined iron
+    /// Refine iron ore into refined iron, refining again as soon as each cooldown ends
     async fn refine_iron_ore(&self, client: &PriorityApiClient, ship_symbol: &str, available_units: i32) -> Result<bool, String> {
         o_info!("⚙️ Refining iron ore on {}", ship_symbol);
         
-        // Calculate how many refining cycles we can do (100 ore per cycle = 10 refined iron)
-        let possible_cycles = available_units / 100;
+        let mut planner = RefineryPlanner::new();
+        let recipe = iron_recipe();
+        let possible_cycles = available_units / recipe.input_units;
         
         if possible_cycles == 0 {
-            o_info!("⚠️ Not enough iron ore for refining (need 100+, have {})", available_units);
+            o_info!("⚠️ Not enough iron ore for refining (need {}+, have {})", recipe.input_units, available_units);
             return Ok(false);
         }
         
@@ -1183,49 +1195,58 @@ impl ShipRoleManager {
                possible_cycles, available_units);
         
         let mut successful_refines = 0;
+        let mut failed_refines = 0;
         let mut total_iron_produced = 0;
         let mut total_ore_consumed = 0;
         
-        for cycle in 1..=possible_cycles {
-            o_info!("⚙️ Starting refining cycle {}/{}", cycle, possible_cycles);
+        while failed_refines < 3 {
+            let ship = client.get_ship(ship_symbol).await.map_err(|e| e.to_string())?;
+            match planner.next_step(&recipe.produce, &ship.cargo) {
+                Some(RefineryStep::Refine) => {}
+                Some(RefineryStep::WaitForCooldown { seconds }) => {
+                    o_info!("⏳ Refining cooldown: {:.0} seconds", seconds);
+                    tokio::time::sleep(tokio::time::Duration::from_secs_f64(seconds + 0.5)).await;
+                    continue;
+                }
+                Some(RefineryStep::NeedInput { .. }) | None => break,
+            }
             
-            match client.refine_cargo_with_priority(
+            o_info!("⚙️ Starting refining cycle {}", successful_refines + 1);
+            let result = client.refine_cargo_with_priority(
                 ship_symbol, 
-                "IRON", 
+                &recipe.produce, 
                 crate::client::priority_client::ApiPriority::ActiveGoal
-            ).await {
+            ).await.map_err(|e| e.to_string());
+            match result {
                 Ok(refine_data) => {
                     successful_refines += 1;
+                    planner.observe_refine(&refine_data);
                     
                     // Log what was produced and consumed
                     for produced in &refine_data.produced {
                         o_info!("✨ Produced: {} x{}", produced.trade_symbol, produced.units);
-                        if produced.trade_symbol == "IRON" {
+                        if produced.trade_symbol == recipe.produce {
                             total_iron_produced += produced.units;
                         }
                     }
                     
                     for consumed in &refine_data.consumed {
                         o_info!("🔥 Consumed: {} x{}", consumed.trade_symbol, consumed.units);
-                        if consumed.trade_symbol == "IRON_ORE" {
+                        if consumed.trade_symbol == recipe.input {
                             total_ore_consumed += consumed.units;
                         }
                     }
-                    
-                    // Check cooldown
-                    let cooldown_seconds = refine_data.cooldown.total_seconds;
-                    if cooldown_seconds > 0.0 {
-                        o_info!("⏳ Refining cooldown: {:.0} seconds", cooldown_seconds);
-                        tokio::time::sleep(tokio::time::Duration::from_secs(cooldown_seconds as u64 + 1)).await;
-                    }
                 }
                 Err(e) => {
-                    o_info!("❌ Refining cycle {} failed: {}", cycle, e);
+                    failed_refines += 1;
+                    o_info!("❌ Refining cycle {} failed: {}", successful_refines + 1, e);
                     
-                    // Check if it's a cooldown issue and wait
-                    if e.to_string().contains("cooldown") {
-                        o_info!("⏳ Waiting for cooldown to expire...");
-                        tokio::time::sleep(tokio::time::Duration::from_secs(30)).await;
+                    // A cooldown we did not know about - learn it from the ship and wait it out
+                    if e.contains("cooldown") {
+                        planner.record_cooldown(&ship.cooldown);
+                        if planner.seconds_until_ready() <= 0.0 {
+                            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+                        }
                         continue;
                     }
                     
@@ -1282,9 +1303,22 @@ impl ShipRoleManager {
         
         o_info!("📋 Contract requires {} IRON units delivered to {}", required_iron, delivery_destination);
         
-        // Calculate ore needed: 100 ore → 10 iron, so we need required_iron * 10 ore
-        let ore_needed = required_iron * 10;
-        o_info!("⛏️ Need {} iron ore total ({}x refinement cycles)", ore_needed, required_iron / 10);
+        let recipe = iron_recipe();
+        let ore_needed = recipe.input_for_output(required_iron);
+        o_info!("⛏️ Need {} iron ore total ({}x refinement cycles)", ore_needed, recipe.refines_for_output(required_iron));
+        
+        // Refining only pays if the iron is worth more than the ore it consumes; the
+        // contract payment is already counted in the pool's value for IRON
+        let pool = get_survey_pool();
+        match RefineryPlanner::new().compare(&recipe.produce, pool.good_value(&recipe.input), pool.good_value(&recipe.produce)) {
+            RefineDecision::SellRaw { loss_per_refine } => {
+                o_info!("⚠️ Selling raw {} beats refining by {:.0} credits per refine", recipe.input, loss_per_refine);
+            }
+            RefineDecision::Refine { gain_per_refine } => {
+                o_info!("⚖️ Refining gains {:.0} credits per refine", gain_per_refine);
+            }
+            RefineDecision::Unknown => {}
+        }
         
         // Determine strategy based on refiner cargo capacity
         let refiner_ship_info = match self.find_best_refinery_candidate() {
@@ -1327,8 +1361,8 @@ impl ShipRoleManager {
             }
         }
         
-        // Step 2: Collect ore needed (required_iron * 10)
-        let ore_needed = required_iron * 10;
+        // Step 2: Collect ore needed
+        let ore_needed = iron_recipe().input_for_output(required_iron);
         o_info!("📦 Collecting {} iron ore units for batch processing", ore_needed);
         // TODO: Implement ore collection strategy (mining coordination)
         
@@ -1344,25 +1378,27 @@ impl ShipRoleManager {
         Ok(true)
     }
 
-    /// Execute incremental delivery strategy (refine 100→10, deliver, repeat)
+    /// Execute incremental delivery strategy (refine one batch, deliver, repeat)
     async fn execute_incremental_delivery_strategy(&self, client: &PriorityApiClient, _refiner_symbol: &str, required_iron: i32, destination: &str) -> Result<bool, String> {
         o_info!("🔄 Executing INCREMENTAL DELIVERY STRATEGY for {} iron units", required_iron);
         
-        let cycles_needed = (required_iron + 9) / 10; // Round up division
-        o_info!("📊 Strategy: {} cycles of (mine 100 ore → refine 10 iron → deliver 10 iron)", cycles_needed);
+        let recipe = iron_recipe();
+        let cycles_needed = recipe.refines_for_output(required_iron);
+        o_info!("📊 Strategy: {} cycles of (mine {} ore → refine {} iron → deliver {} iron)", 
+               cycles_needed, recipe.input_units, recipe.output_units, recipe.output_units);
         
         let mut delivered_iron = 0;
         
         for cycle in 1..=cycles_needed {
-            let delivery_amount = std::cmp::min(10, required_iron - delivered_iron);
+            let delivery_amount = std::cmp::min(recipe.output_units, required_iron - delivered_iron);
             o_info!("🔄 Cycle {}/{}: Processing {} iron units", cycle, cycles_needed, delivery_amount);
             
-            // Step 1: Ensure refiner has 100 ore
-            o_info!("⛏️ Collecting 100 iron ore for refining");
-            // TODO: Coordinate ore collection (100 units)
+            // Step 1: Ensure refiner has a refine's worth of ore
+            o_info!("⛏️ Collecting {} iron ore for refining", recipe.input_units);
+            // TODO: Coordinate ore collection
             
-            // Step 2: Refine 100 ore → 10 iron
-            o_info!("⚙️ Refining 100 ore → {} iron", delivery_amount);
+            // Step 2: Refine one batch of ore
+            o_info!("⚙️ Refining {} ore → {} iron", recipe.input_units, delivery_amount);
             match self.start_refinery_operations(client).await {
                 Ok(true) => o_info!("✅ Refining cycle {} completed", cycle),
                 Ok(false) => {
@@ -1392,6 +1428,10 @@ impl ShipRoleManager {
     }
 }
 
+fn iron_recipe() -> RefineRecipe {
+    recipe_for("IRON").expect("IRON has a refine recipe")
+}
+
 #[derive(Debug, Clone)]
 struct CargoCarrierInfo {
     ship_symbol: String,
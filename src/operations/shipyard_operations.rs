@@ -1,5 +1,7 @@
 // Shipyard operations for purchasing and outfitting ships
 use crate::client::SpaceTradersClient;
+use crate::client::priority_client::PriorityApiClient;
+use crate::operations::{OutfittingManager, ShipRole};
 use crate::{o_info};
 use crate::models::*;
 
@@ -148,25 +150,31 @@ impl ShipyardOperations {
     pub async fn outfit_mining_ship(&self, ship: &Ship, reference_ship: &Ship) -> Result<(), Box<dyn std::error::Error>> {
         o_info!("🛠️ Outfitting {} with mining equipment", ship.symbol);
         
-        // For now, this would require additional mount/module installation APIs
-        // which may not be implemented yet
-        o_info!("   🎯 Target configuration (based on {}):", reference_ship.symbol);
+        let client = PriorityApiClient::new(self.client.clone());
+        let mut outfitting = OutfittingManager::new();
+        outfitting.learn_from_ships(&[ship.clone(), reference_ship.clone()]);
         
-        for mount in &reference_ship.mounts {
-            if mount.symbol.contains("MINING") || mount.symbol.contains("SURVEYOR") {
-                o_info!("     • Install {} - {}", mount.symbol, mount.name);
-            }
+        // A freshly bought ship sits at the shipyard it came from
+        let shipyard_waypoint = &ship.nav.waypoint_symbol;
+        if let Ok(shipyard) = self.client.get_shipyard(&ship.nav.system_symbol, shipyard_waypoint).await {
+            outfitting.record_shipyard(&shipyard);
         }
+        outfitting.scan_markets(&client, &ship.nav.system_symbol).await?;
         
-        for module in &reference_ship.modules {
-            if module.symbol.contains("CARGO") || module.symbol.contains("PROCESSOR") {
-                o_info!("     • Install {} - {}", module.symbol, module.name);
-            }
+        let plan = outfitting.plan(ship, &ShipRole::Miner).ok_or("No miner loadout configured")?;
+        if plan.is_empty() {
+            o_info!("   ✅ {} already matches the miner loadout", ship.symbol);
+            return Ok(());
         }
         
-        o_info!("   ⚠️ Outfitting system needs ship modification APIs");
-        o_info!("   💡 Ship can be used as-is for basic mining operations");
+        let estimate = outfitting.estimate_cost(&plan, shipyard_waypoint);
+        o_info!("   🎯 Install {:?}, remove {:?} - estimated {} credits ({} parts + {} fees)",
+               plan.install_mounts, plan.remove_mounts, estimate.total(), estimate.parts, estimate.fees);
+        if !estimate.unpriced_parts.is_empty() {
+            o_info!("   ⚠️ No price known yet for {:?}", estimate.unpriced_parts);
+        }
         
+        outfitting.execute(&client, &plan, shipyard_waypoint).await?;
         Ok(())
     }
 }
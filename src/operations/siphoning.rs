@@ -0,0 +1,68 @@
+// Siphoning operations module - gas extraction at gas giants
+use crate::client::SpaceTradersClient;
+use crate::o_info;
+use crate::models::*;
+
+/// Goods that come from siphoning gas giants rather than mining asteroids
+pub const SIPHONABLE_GOODS: [&str; 3] = ["HYDROCARBON", "LIQUID_HYDROGEN", "LIQUID_NITROGEN"];
+
+pub fn is_siphonable(good: &str) -> bool {
+    SIPHONABLE_GOODS.contains(&good)
+}
+
+/// Whether a ship carries a gas siphon mount
+pub fn has_siphon_capability(ship: &Ship) -> bool {
+    ship.mounts.iter().any(|mount| mount.symbol.contains("GAS_SIPHON"))
+}
+
+pub fn is_gas_giant(waypoint: &Waypoint) -> bool {
+    waypoint.waypoint_type == "GAS_GIANT"
+}
+
+pub struct SiphonOperations<'a> {
+    client: &'a SpaceTradersClient,
+}
+
+impl<'a> SiphonOperations<'a> {
+    pub fn new(client: &'a SpaceTradersClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn siphon_resources(&self, ship_symbol: &str) -> Result<SiphonData, Box<dyn std::error::Error>> {
+        self.client.siphon_resources(ship_symbol).await
+    }
+
+    pub async fn find_gas_giants(&self, system_symbol: &str) -> Result<Vec<Waypoint>, Box<dyn std::error::Error>> {
+        o_info!("🔍 Searching for gas giants in system {}...", system_symbol);
+
+        let gas_giants: Vec<Waypoint> = self.client.get_system_waypoints(system_symbol, None).await?
+            .into_iter()
+            .filter(is_gas_giant)
+            .collect();
+
+        o_info!("🪐 Found {} gas giant(s)", gas_giants.len());
+        for gas_giant in &gas_giants {
+            o_info!("  - {} at ({}, {})", gas_giant.symbol, gas_giant.x, gas_giant.y);
+        }
+        Ok(gas_giants)
+    }
+
+    /// Nearest gas giant to a point in a list of system waypoints
+    pub fn nearest_gas_giant(waypoints: &[Waypoint], from_x: i32, from_y: i32) -> Option<&Waypoint> {
+        waypoints.iter()
+            .filter(|waypoint| is_gas_giant(waypoint))
+            .min_by_key(|waypoint| (waypoint.x - from_x).pow(2) + (waypoint.y - from_y).pow(2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gas_goods_are_siphonable() {
+        assert!(is_siphonable("HYDROCARBON"));
+        assert!(is_siphonable("LIQUID_NITROGEN"));
+        assert!(!is_siphonable("IRON_ORE"));
+    }
+}
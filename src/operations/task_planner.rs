@@ -5,6 +5,7 @@ use crate::models::*;
 use crate::operations::ship_actor::ShipAction;
 use crate::operations::navigation::NavigationPlanner;
 use crate::config::SpaceTradersConfig;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
@@ -15,7 +16,7 @@ pub struct TaskPlan {
     pub estimated_time_seconds: f64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaskStep {
     pub step_type: TaskStepType,
     pub location: String,
@@ -23,14 +24,16 @@ pub struct TaskStep {
     pub description: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TaskStepType {
     Navigate,
     Dock,
     Orbit,
     Mine,
+    Siphon,
     Refuel,
     SellCargo,
+    Trade,
     DeliverCargo,
     Survey,
     JettisonCargo,
@@ -70,8 +73,9 @@ impl TaskPlanner {
                 estimated_time += fuel_needed as f64 * 2.0; // Rough time estimate
             },
             
-            ShipAction::Mine { target, .. } => {
-                // Plan: Navigate to target -> Orbit -> Mine
+            ShipAction::Mine { target, .. } | ShipAction::Siphon { target, .. } => {
+                let siphoning = matches!(action, ShipAction::Siphon { .. });
+                // Plan: Navigate to target -> Orbit -> Mine (or siphon)
                 if ship.nav.waypoint_symbol != *target {
                     let (nav_steps, fuel_needed) = self.plan_navigation(ship, target).await?;
                     steps.extend(nav_steps);
@@ -84,17 +88,17 @@ impl TaskPlanner {
                         step_type: TaskStepType::Orbit,
                         location: target.clone(),
                         fuel_cost: 1,
-                        description: format!("Orbit at {} for mining", target),
+                        description: format!("Orbit at {} for {}", target, if siphoning { "siphoning" } else { "mining" }),
                     });
                     total_fuel += 1;
                 }
                 
                 // Add mining step
                 steps.push(TaskStep {
-                    step_type: TaskStepType::Mine,
+                    step_type: if siphoning { TaskStepType::Siphon } else { TaskStepType::Mine },
                     location: target.clone(),
                     fuel_cost: 0,
-                    description: format!("Extract resources at {}", target),
+                    description: format!("{} at {}", if siphoning { "Siphon gas" } else { "Extract resources" }, target),
                 });
                 
                 estimated_time += total_fuel as f64 * 2.0 + 30.0; // Navigation + mining time
@@ -158,6 +162,33 @@ impl TaskPlanner {
                 estimated_time += total_fuel as f64 * 2.0 + 10.0; // Navigation + trading time
             },
 
+            ShipAction::Trade { orders, marketplace } => {
+                // Plan: Navigate to marketplace -> Dock -> Buy and sell
+                if ship.nav.waypoint_symbol != *marketplace {
+                    let (nav_steps, fuel_needed) = self.plan_navigation(ship, marketplace).await?;
+                    steps.extend(nav_steps);
+                    total_fuel += fuel_needed;
+                }
+                
+                if ship.nav.status != "DOCKED" {
+                    steps.push(TaskStep {
+                        step_type: TaskStepType::Dock,
+                        location: marketplace.clone(),
+                        fuel_cost: 0,
+                        description: format!("Dock at {} for trading", marketplace),
+                    });
+                }
+                
+                steps.push(TaskStep {
+                    step_type: TaskStepType::Trade,
+                    location: marketplace.clone(),
+                    fuel_cost: 0,
+                    description: format!("Trade {} orders at {}", orders.len(), marketplace),
+                });
+                
+                estimated_time += total_fuel as f64 * 2.0 + 5.0 * orders.len() as f64; // Navigation + one call per lot
+            },
+
             ShipAction::DeliverCargo { destination, .. } => {
                 // Plan: Navigate to destination -> Dock -> Deliver
                 if ship.nav.waypoint_symbol != *destination {
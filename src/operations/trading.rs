@@ -3,6 +3,7 @@ use crate::client::SpaceTradersClient;
 use crate::{o_info};
 use crate::models::*;
 use crate::operations::ShipOperations;
+use serde::{Deserialize, Serialize};
 use tokio::time::{sleep, Duration};
 
 pub struct TradingOperations<'a> {
@@ -267,4 +268,108 @@ impl<'a> TradingOperations<'a> {
         let fuel_cost = distance_to_market * 2; // Rough fuel cost
         cargo_value - fuel_cost
     }
-}
\ No newline at end of file
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TradeSide {
+    Buy,
+    Sell,
+}
+
+/// One good to buy or sell. The limit is the highest price paid per unit when buying
+/// and the lowest accepted per unit when selling.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradeOrder {
+    pub side: TradeSide,
+    pub good: String,
+    pub units: i32,
+    pub price_limit: Option<i32>,
+}
+
+impl TradeOrder {
+    pub fn buy(good: &str, units: i32, max_price: Option<i32>) -> Self {
+        Self { side: TradeSide::Buy, good: good.to_string(), units, price_limit: max_price }
+    }
+
+    pub fn sell(good: &str, units: i32, min_price: Option<i32>) -> Self {
+        Self { side: TradeSide::Sell, good: good.to_string(), units, price_limit: min_price }
+    }
+
+    /// Whether a per-unit price is inside this order's limit
+    pub fn accepts(&self, price_per_unit: i32) -> bool {
+        match (self.side, self.price_limit) {
+            (_, None) => true,
+            (TradeSide::Buy, Some(limit)) => price_per_unit <= limit,
+            (TradeSide::Sell, Some(limit)) => price_per_unit >= limit,
+        }
+    }
+}
+
+/// A single market transaction, no larger than the good's trade volume
+#[derive(Debug, Clone, PartialEq)]
+pub struct TradeLot {
+    /// Index of the order this lot fills
+    pub order: usize,
+    pub side: TradeSide,
+    pub good: String,
+    pub units: i32,
+}
+
+/// The market's current per-unit price for one side of a trade, if it lists the good
+pub fn quoted_price(market: &Market, side: TradeSide, good: &str) -> Option<i32> {
+    let listing = market.trade_goods.as_deref().unwrap_or_default().iter().find(|listing| listing.symbol == good)?;
+    Some(match side {
+        TradeSide::Buy => listing.purchase_price,
+        TradeSide::Sell => listing.sell_price,
+    })
+}
+
+/// Split orders into lots the market will take. Sells go first so their space is free for buys;
+/// orders the market does not list, or lists outside the price limit, are skipped with a reason.
+pub fn plan_trade_lots(orders: &[TradeOrder], market: &Market, cargo: &ShipCargo) -> (Vec<TradeLot>, Vec<String>) {
+    let listings = market.trade_goods.as_deref().unwrap_or_default();
+    let mut lots = Vec::new();
+    let mut skipped = Vec::new();
+    let mut free_space = cargo.capacity - cargo.units;
+
+    let sells = orders.iter().enumerate().filter(|(_, order)| order.side == TradeSide::Sell);
+    let buys = orders.iter().enumerate().filter(|(_, order)| order.side == TradeSide::Buy);
+    for (index, order) in sells.chain(buys) {
+        let Some(listing) = listings.iter().find(|listing| listing.symbol == order.good) else {
+            skipped.push(format!("{} is not traded at {}", order.good, market.symbol));
+            continue;
+        };
+        let price = quoted_price(market, order.side, &order.good).unwrap_or_default();
+        if !order.accepts(price) {
+            skipped.push(format!("{} at {} is outside the limit of {}", order.good, price, order.price_limit.unwrap_or_default()));
+            continue;
+        }
+
+        let units = match order.side {
+            TradeSide::Sell => {
+                let held = cargo.inventory.iter().find(|item| item.symbol == order.good).map(|item| item.units).unwrap_or(0);
+                let units = order.units.min(held);
+                free_space += units;
+                units
+            }
+            TradeSide::Buy => {
+                let units = order.units.min(free_space);
+                free_space -= units;
+                units
+            }
+        };
+        if units <= 0 {
+            skipped.push(format!("no {} to {}", order.good, if order.side == TradeSide::Buy { "fit in the hold" } else { "sell" }));
+            continue;
+        }
+
+        let volume = listing.trade_volume.max(1);
+        let mut remaining = units;
+        while remaining > 0 {
+            let lot_units = remaining.min(volume);
+            lots.push(TradeLot { order: index, side: order.side, good: order.good.clone(), units: lot_units });
+            remaining -= lot_units;
+        }
+    }
+
+    (lots, skipped)
+}
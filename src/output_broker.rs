@@ -13,6 +13,8 @@ pub struct OutputBroker {
 /// Output request that gets queued through the broker
 pub struct OutputRequest {
     pub level: OutputLevel,
+    /// Module the message came from, used as the subsystem in the JSON log
+    pub subsystem: &'static str,
     pub message: String,
     pub timestamp: Instant,
     pub response_sender: Option<oneshot::Sender<()>>,
@@ -31,6 +33,8 @@ pub enum OutputLevel {
 /// Internal broker state
 struct BrokerState {
     verbosity_level: u8,
+    /// Off while the terminal dashboard owns the screen
+    console_enabled: bool,
     pending_summaries: VecDeque<String>,
     last_summary_flush: Instant,
     summary_interval: Duration,
@@ -49,8 +53,14 @@ impl OutputBroker {
     
     /// Submit an output request through the broker
     pub async fn output(&self, level: OutputLevel, message: String) {
+        self.output_from(level, module_path!(), message).await;
+    }
+    
+    /// Submit an output request tagged with the module it came from
+    pub async fn output_from(&self, level: OutputLevel, subsystem: &'static str, message: String) {
         let request = OutputRequest {
             level,
+            subsystem,
             message,
             timestamp: Instant::now(),
             response_sender: None,
@@ -66,6 +76,7 @@ impl OutputBroker {
         
         let request = OutputRequest {
             level,
+            subsystem: module_path!(),
             message,
             timestamp: Instant::now(),
             response_sender: Some(response_sender),
@@ -83,15 +94,28 @@ impl OutputBroker {
         self.output(OutputLevel::Summary, "FLUSH_SUMMARIES".to_string()).await;
     }
     
+    /// Wait until messages sent so far are printed, summaries included; used before the process exits
+    pub async fn flush(&self) {
+        // The output macros hand messages over from spawned tasks, so give those a moment to run first
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        self.output_and_wait(OutputLevel::Summary, "FLUSH_SUMMARIES".to_string()).await;
+    }
+    
     /// Update verbosity level
     pub async fn set_verbosity_level(&self, level: u8) {
         self.output(OutputLevel::Debug, format!("SET_VERBOSITY_{}", level)).await;
     }
     
+    /// Stop or resume printing to the console; the JSON log is unaffected
+    pub async fn set_console_enabled(&self, enabled: bool) {
+        self.output_and_wait(OutputLevel::Debug, format!("SET_CONSOLE_{}", enabled)).await;
+    }
+    
     /// Background worker that processes all output requests with filtering
     async fn broker_worker(mut receiver: mpsc::UnboundedReceiver<OutputRequest>) {
         let mut state = BrokerState {
             verbosity_level: crate::verbosity::get_verbosity_level(),
+            console_enabled: true,
             pending_summaries: VecDeque::new(),
             last_summary_flush: Instant::now(),
             summary_interval: Duration::from_secs(30), // Flush summaries every 30s
@@ -124,6 +148,14 @@ impl OutputBroker {
             return;
         }
         
+        if let Some(enabled) = request.message.strip_prefix("SET_CONSOLE_") {
+            state.console_enabled = enabled == "true";
+            if let Some(sender) = request.response_sender {
+                let _ = sender.send(());
+            }
+            return;
+        }
+        
         if request.message.starts_with("SET_VERBOSITY_") {
             if let Ok(level) = request.message[14..].parse::<u8>() {
                 state.verbosity_level = level;
@@ -135,6 +167,10 @@ impl OutputBroker {
             return;
         }
         
+        // The JSON log has its own per-subsystem levels, independent of console verbosity
+        crate::structured_log::log_record(&crate::structured_log::LogRecord::from_message(
+            &request.level, request.subsystem, &request.message));
+        
         // Apply verbosity filtering
         let should_show = match request.level {
             OutputLevel::Error => true,  // Always show errors
@@ -144,7 +180,7 @@ impl OutputBroker {
             OutputLevel::Trace => state.verbosity_level >= 2,
         };
         
-        if !should_show {
+        if !should_show || !state.console_enabled {
             if let Some(sender) = request.response_sender {
                 let _ = sender.send(());
             }
@@ -176,6 +212,10 @@ impl OutputBroker {
     
     /// Flush all pending summary messages
     async fn flush_pending_summaries(state: &mut BrokerState) {
+        if !state.console_enabled {
+            state.pending_summaries.clear();
+            return;
+        }
         if !state.pending_summaries.is_empty() {
             println!("\n🎖️ === CYCLE SUMMARY ===");
             
@@ -208,7 +248,8 @@ macro_rules! o_error {
     ($($arg:tt)*) => {{
         let broker = $crate::output_broker::get_output_broker();
         let message = format!($($arg)*);
-        tokio::spawn(broker.output($crate::output_broker::OutputLevel::Error, message));
+        $crate::events::publish($crate::events::GameEvent::Error { message: message.clone() });
+        tokio::spawn(broker.output_from($crate::output_broker::OutputLevel::Error, module_path!(), message));
     }};
 }
 
@@ -217,7 +258,7 @@ macro_rules! o_summary {
     ($($arg:tt)*) => {{
         let broker = $crate::output_broker::get_output_broker();
         let message = format!($($arg)*);
-        tokio::spawn(broker.output($crate::output_broker::OutputLevel::Summary, message));
+        tokio::spawn(broker.output_from($crate::output_broker::OutputLevel::Summary, module_path!(), message));
     }};
 }
 
@@ -226,7 +267,7 @@ macro_rules! o_info {
     ($($arg:tt)*) => {{
         let broker = $crate::output_broker::get_output_broker();
         let message = format!($($arg)*);
-        tokio::spawn(broker.output($crate::output_broker::OutputLevel::Info, message));
+        tokio::spawn(broker.output_from($crate::output_broker::OutputLevel::Info, module_path!(), message));
     }};
 }
 
@@ -235,7 +276,7 @@ macro_rules! o_debug {
     ($($arg:tt)*) => {{
         let broker = $crate::output_broker::get_output_broker();
         let message = format!($($arg)*);
-        tokio::spawn(broker.output($crate::output_broker::OutputLevel::Debug, message));
+        tokio::spawn(broker.output_from($crate::output_broker::OutputLevel::Debug, module_path!(), message));
     }};
 }
 
@@ -244,6 +285,6 @@ macro_rules! o_trace {
     ($($arg:tt)*) => {{
         let broker = $crate::output_broker::get_output_broker();
         let message = format!($($arg)*);
-        tokio::spawn(broker.output($crate::output_broker::OutputLevel::Trace, message));
+        tokio::spawn(broker.output_from($crate::output_broker::OutputLevel::Trace, module_path!(), message));
     }};
 }
\ No newline at end of file
@@ -0,0 +1,157 @@
+// Condition history - frame, reactor and engine wear sampled over time for maintenance forecasts
+use std::sync::{Arc, Mutex, OnceLock};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use crate::models::Ship;
+use crate::o_debug;
+use super::persistence::StorageFormat;
+
+pub const CONDITION_HISTORY_PATH: &str = "storage/condition_history.json";
+/// Oldest samples are dropped beyond this so the history stays small
+const MAX_SAMPLES: usize = 5000;
+/// An unchanged reading is only stored again after this long
+const RESAMPLE_MINUTES: i64 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ShipComponent {
+    Frame,
+    Reactor,
+    Engine,
+}
+
+impl ShipComponent {
+    pub const ALL: [ShipComponent; 3] = [ShipComponent::Frame, ShipComponent::Reactor, ShipComponent::Engine];
+
+    /// Condition and integrity of this component on a ship, as percentages
+    pub fn reading(&self, ship: &Ship) -> Option<(f64, Option<f64>)> {
+        let (condition, integrity) = match self {
+            ShipComponent::Frame => (ship.frame.condition, ship.frame.integrity),
+            ShipComponent::Reactor => (ship.reactor.condition, ship.reactor.integrity),
+            ShipComponent::Engine => (ship.engine.condition, ship.engine.integrity),
+        };
+        condition.map(|condition| (as_percent(condition), integrity.map(as_percent)))
+    }
+}
+
+/// The API reports wear as a 0-1 fraction; older code and cached ships use 0-100
+pub fn as_percent(value: f64) -> f64 {
+    if value <= 1.0 { value * 100.0 } else { value }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConditionSample {
+    pub timestamp: DateTime<Utc>,
+    pub ship_symbol: String,
+    pub component: ShipComponent,
+    pub condition: f64,
+    pub integrity: Option<f64>,
+}
+
+pub const CONDITION_HISTORY_FORMAT: StorageFormat = StorageFormat { name: "condition history", first_version: 1, migrations: &[] };
+
+/// Shared handle to the condition history; clones see the same samples
+#[derive(Debug, Clone)]
+pub struct ConditionHistory {
+    storage_path: Option<String>,
+    samples: Arc<Mutex<Vec<ConditionSample>>>,
+}
+
+static GLOBAL_CONDITION_HISTORY: OnceLock<ConditionHistory> = OnceLock::new();
+
+/// The condition history shared by the whole fleet
+pub fn get_condition_history() -> &'static ConditionHistory {
+    GLOBAL_CONDITION_HISTORY.get_or_init(|| ConditionHistory::new(CONDITION_HISTORY_PATH))
+}
+
+impl ConditionHistory {
+    pub fn new(storage_path: &str) -> Self {
+        let history = Self {
+            storage_path: Some(storage_path.to_string()),
+            samples: Arc::new(Mutex::new(Vec::new())),
+        };
+
+        if let Err(e) = history.load_from_disk() {
+            o_debug!("⚠️ Failed to load condition history: {}", e);
+            o_debug!("💾 Starting with empty condition history");
+        }
+
+        history
+    }
+
+    /// A history that is never written to disk
+    pub fn in_memory() -> Self {
+        Self {
+            storage_path: None,
+            samples: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Store a sample unless it repeats the last reading for that component recently
+    pub fn record(&self, sample: ConditionSample) {
+        let mut samples = self.samples.lock().unwrap();
+        let last = samples.iter().rev()
+            .find(|existing| existing.ship_symbol == sample.ship_symbol && existing.component == sample.component);
+        if let Some(last) = last
+            && last.condition == sample.condition
+            && sample.timestamp - last.timestamp < Duration::minutes(RESAMPLE_MINUTES) {
+            return;
+        }
+
+        samples.push(sample);
+        if samples.len() > MAX_SAMPLES {
+            let excess = samples.len() - MAX_SAMPLES;
+            samples.drain(..excess);
+        }
+        self.save_to_disk(&samples);
+    }
+
+    /// Sample every component the ship reports a condition for
+    pub fn record_ship(&self, ship: &Ship) {
+        for component in ShipComponent::ALL {
+            if let Some((condition, integrity)) = component.reading(ship) {
+                self.record(ConditionSample {
+                    timestamp: Utc::now(),
+                    ship_symbol: ship.symbol.clone(),
+                    component,
+                    condition,
+                    integrity,
+                });
+            }
+        }
+    }
+
+    /// Samples for one component of one ship, oldest first
+    pub fn samples(&self, ship_symbol: &str, component: ShipComponent) -> Vec<ConditionSample> {
+        self.samples.lock().unwrap().iter()
+            .filter(|sample| sample.ship_symbol == ship_symbol && sample.component == component)
+            .cloned()
+            .collect()
+    }
+
+    pub fn clear(&self) {
+        let mut samples = self.samples.lock().unwrap();
+        samples.clear();
+        self.save_to_disk(&samples);
+    }
+
+    fn load_from_disk(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(storage_path) = &self.storage_path else {
+            return Ok(());
+        };
+        let Some(samples) = CONDITION_HISTORY_FORMAT.load::<Vec<ConditionSample>>(storage_path)? else {
+            return Ok(());
+        };
+        o_debug!("💾 Loaded {} condition samples from disk", samples.len());
+        *self.samples.lock().unwrap() = samples;
+        Ok(())
+    }
+
+    fn save_to_disk(&self, samples: &[ConditionSample]) {
+        let Some(storage_path) = &self.storage_path else {
+            return;
+        };
+        if let Err(e) = CONDITION_HISTORY_FORMAT.save(storage_path, &samples) {
+            o_debug!("⚠️ Failed to save condition history: {}", e);
+        }
+    }
+}
@@ -1,10 +1,9 @@
 // Persistent cooldown storage system
 use std::collections::HashMap;
-use std::fs;
 use crate::{o_debug};
-use std::path::Path;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use super::persistence::{from_unversioned, StorageFormat};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CooldownEntry {
@@ -13,6 +12,8 @@ pub struct CooldownEntry {
     pub last_updated: DateTime<Utc>,
 }
 
+pub const COOLDOWN_FORMAT: StorageFormat = StorageFormat { name: "cooldowns", first_version: 0, migrations: &[from_unversioned] };
+
 pub struct CooldownStore {
     storage_path: String,
     cooldowns: HashMap<String, CooldownEntry>,
@@ -115,12 +116,10 @@ impl CooldownStore {
     }
     
     fn load_from_disk(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        if !Path::new(&self.storage_path).exists() {
-            return Ok(()); // File doesn't exist yet, start fresh
-        }
-        
-        let content = fs::read_to_string(&self.storage_path)?;
-        let entries: Vec<CooldownEntry> = serde_json::from_str(&content)?;
+        // Missing files start fresh; files from older versions are migrated
+        let Some(entries) = COOLDOWN_FORMAT.load::<Vec<CooldownEntry>>(&self.storage_path)? else {
+            return Ok(());
+        };
         
         // Convert to HashMap
         self.cooldowns.clear();
@@ -136,18 +135,15 @@ impl CooldownStore {
         Ok(())
     }
     
+    /// Write the current contents to disk, e.g. before shutting down
+    pub fn flush(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self.save_to_disk()
+    }
+    
     fn save_to_disk(&self) -> Result<(), Box<dyn std::error::Error>> {
         // Convert HashMap to Vec for serialization
         let entries: Vec<CooldownEntry> = self.cooldowns.values().cloned().collect();
-        let content = serde_json::to_string_pretty(&entries)?;
-        
-        // Create parent directory if it doesn't exist
-        if let Some(parent) = Path::new(&self.storage_path).parent() {
-            fs::create_dir_all(parent)?;
-        }
-        
-        fs::write(&self.storage_path, content)?;
-        Ok(())
+        COOLDOWN_FORMAT.save(&self.storage_path, &entries)
     }
     
     pub fn print_status(&self) {
@@ -0,0 +1,235 @@
+// Extraction yield analytics - every extraction recorded and rolled up per field and mount loadout
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::models::{ExtractionData, Ship};
+use crate::events::GameEvent;
+use crate::o_debug;
+use super::persistence::StorageFormat;
+
+pub const EXTRACTION_LOG_PATH: &str = "storage/extraction_log.json";
+/// Oldest records are dropped beyond this so the log stays small
+const MAX_RECORDS: usize = 5000;
+/// Fields need this many extractions before their yield counts as proven
+pub const MIN_PROVEN_EXTRACTIONS: u32 = 3;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractionRecord {
+    pub timestamp: DateTime<Utc>,
+    pub waypoint_symbol: String,
+    pub ship_symbol: String,
+    pub mounts: Vec<String>,
+    pub survey_signature: Option<String>,
+    pub yield_symbol: String,
+    pub units: i32,
+    pub cooldown_seconds: f64,
+}
+
+impl ExtractionRecord {
+    pub fn new(ship: &Ship, waypoint_symbol: &str, survey_signature: Option<&str>, extraction: &ExtractionData) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            waypoint_symbol: waypoint_symbol.to_string(),
+            ship_symbol: ship.symbol.clone(),
+            mounts: ship.mounts.iter().map(|mount| mount.symbol.clone()).collect(),
+            survey_signature: survey_signature.map(str::to_string),
+            yield_symbol: extraction.extraction.extraction_yield.symbol.clone(),
+            units: extraction.extraction.extraction_yield.units,
+            cooldown_seconds: extraction.cooldown.total_seconds,
+        }
+    }
+
+    /// Mounts sorted and joined so ships with the same fit share a key
+    pub fn loadout(&self) -> String {
+        let mut mounts = self.mounts.clone();
+        mounts.sort();
+        if mounts.is_empty() { "NO_MOUNTS".to_string() } else { mounts.join("+") }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct YieldStats {
+    pub extractions: u32,
+    pub total_units: i64,
+    pub total_cooldown_seconds: f64,
+    pub surveyed_extractions: u32,
+}
+
+impl YieldStats {
+    fn add(&mut self, record: &ExtractionRecord) {
+        self.extractions += 1;
+        self.total_units += record.units as i64;
+        self.total_cooldown_seconds += record.cooldown_seconds;
+        if record.survey_signature.is_some() {
+            self.surveyed_extractions += 1;
+        }
+    }
+
+    pub fn units_per_cooldown_second(&self) -> f64 {
+        if self.total_cooldown_seconds <= 0.0 {
+            return 0.0;
+        }
+        self.total_units as f64 / self.total_cooldown_seconds
+    }
+
+    pub fn average_units(&self) -> f64 {
+        if self.extractions == 0 {
+            return 0.0;
+        }
+        self.total_units as f64 / self.extractions as f64
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct YieldReport {
+    pub by_waypoint: HashMap<String, YieldStats>,
+    pub by_loadout: HashMap<String, YieldStats>,
+}
+
+impl YieldReport {
+    /// Waypoints ordered from most to least productive
+    pub fn ranked_waypoints(&self) -> Vec<(&String, &YieldStats)> {
+        let mut ranked: Vec<_> = self.by_waypoint.iter().collect();
+        ranked.sort_by(|a, b| b.1.units_per_cooldown_second().partial_cmp(&a.1.units_per_cooldown_second())
+            .unwrap_or(std::cmp::Ordering::Equal));
+        ranked
+    }
+
+    pub fn ranked_loadouts(&self) -> Vec<(&String, &YieldStats)> {
+        let mut ranked: Vec<_> = self.by_loadout.iter().collect();
+        ranked.sort_by(|a, b| b.1.units_per_cooldown_second().partial_cmp(&a.1.units_per_cooldown_second())
+            .unwrap_or(std::cmp::Ordering::Equal));
+        ranked
+    }
+}
+
+pub const EXTRACTION_LOG_FORMAT: StorageFormat = StorageFormat { name: "extraction log", first_version: 1, migrations: &[] };
+
+/// Shared handle to the extraction log; clones see the same records.
+/// New records are only written to disk by `flush`, so mining never waits on a save.
+#[derive(Debug, Clone)]
+pub struct ExtractionLog {
+    storage_path: Option<String>,
+    records: Arc<Mutex<Vec<ExtractionRecord>>>,
+    dirty: Arc<AtomicBool>,
+}
+
+static GLOBAL_EXTRACTION_LOG: OnceLock<ExtractionLog> = OnceLock::new();
+
+/// The extraction log shared by the whole fleet
+pub fn get_extraction_log() -> &'static ExtractionLog {
+    GLOBAL_EXTRACTION_LOG.get_or_init(|| ExtractionLog::new(EXTRACTION_LOG_PATH))
+}
+
+impl ExtractionLog {
+    pub fn new(storage_path: &str) -> Self {
+        let log = Self {
+            storage_path: Some(storage_path.to_string()),
+            records: Arc::new(Mutex::new(Vec::new())),
+            dirty: Arc::new(AtomicBool::new(false)),
+        };
+
+        if let Err(e) = log.load_from_disk() {
+            o_debug!("⚠️ Failed to load extraction log: {}", e);
+            o_debug!("💾 Starting with empty extraction log");
+        }
+
+        log
+    }
+
+    /// A log that is never written to disk
+    pub fn in_memory() -> Self {
+        Self {
+            storage_path: None,
+            records: Arc::new(Mutex::new(Vec::new())),
+            dirty: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn record(&self, record: ExtractionRecord) {
+        o_debug!("📈 {} extracted {} x{} at {} ({:.0}s cooldown)",
+                record.ship_symbol, record.yield_symbol, record.units, record.waypoint_symbol, record.cooldown_seconds);
+        let mut records = self.records.lock().unwrap();
+        records.push(record);
+        if records.len() > MAX_RECORDS {
+            let excess = records.len() - MAX_RECORDS;
+            records.drain(..excess);
+        }
+        self.dirty.store(true, Ordering::Relaxed);
+    }
+
+    /// Record an extraction made by a ship at a waypoint
+    pub fn record_extraction(&self, ship: &Ship, waypoint_symbol: &str, survey_signature: Option<&str>, extraction: &ExtractionData) {
+        crate::events::publish(GameEvent::extraction(&ship.symbol, waypoint_symbol, &extraction.extraction.extraction_yield));
+        self.record(ExtractionRecord::new(ship, waypoint_symbol, survey_signature, extraction));
+    }
+
+    pub fn records(&self) -> Vec<ExtractionRecord> {
+        self.records.lock().unwrap().clone()
+    }
+
+    pub fn report(&self) -> YieldReport {
+        let records = self.records.lock().unwrap();
+        let mut report = YieldReport::default();
+        for record in records.iter() {
+            report.by_waypoint.entry(record.waypoint_symbol.clone()).or_default().add(record);
+            report.by_loadout.entry(record.loadout()).or_default().add(record);
+        }
+        report
+    }
+
+    /// Yield per cooldown-second at a waypoint, once enough extractions have been seen there
+    pub fn proven_yield_rate(&self, waypoint_symbol: &str) -> Option<f64> {
+        let records = self.records.lock().unwrap();
+        let mut stats = YieldStats::default();
+        for record in records.iter().filter(|record| record.waypoint_symbol == waypoint_symbol) {
+            stats.add(record);
+        }
+        (stats.extractions >= MIN_PROVEN_EXTRACTIONS).then(|| stats.units_per_cooldown_second())
+    }
+
+    /// Highest proven yield rate across all fields
+    pub fn best_proven_yield_rate(&self) -> Option<f64> {
+        self.report().by_waypoint.values()
+            .filter(|stats| stats.extractions >= MIN_PROVEN_EXTRACTIONS)
+            .map(|stats| stats.units_per_cooldown_second())
+            .reduce(f64::max)
+    }
+
+    pub fn clear(&self) {
+        self.records.lock().unwrap().clear();
+        self.dirty.store(true, Ordering::Relaxed);
+        if let Err(e) = self.flush() {
+            o_debug!("⚠️ Failed to save extraction log: {}", e);
+        }
+    }
+
+    /// Write records added since the last flush to disk; called periodically and before shutting down
+    pub fn flush(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(storage_path) = &self.storage_path else {
+            return Ok(());
+        };
+        if !self.dirty.swap(false, Ordering::Relaxed) {
+            return Ok(());
+        }
+        // Serialise a snapshot so extractions recorded meanwhile don't wait on the write
+        let records = self.records.lock().unwrap().clone();
+        EXTRACTION_LOG_FORMAT.save(storage_path, &records).inspect_err(|_| {
+            self.dirty.store(true, Ordering::Relaxed);
+        })
+    }
+
+    fn load_from_disk(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(storage_path) = &self.storage_path else {
+            return Ok(());
+        };
+        let Some(records) = EXTRACTION_LOG_FORMAT.load::<Vec<ExtractionRecord>>(storage_path)? else {
+            return Ok(());
+        };
+        o_debug!("💾 Loaded {} extraction records from disk", records.len());
+        *self.records.lock().unwrap() = records;
+        Ok(())
+    }
+}
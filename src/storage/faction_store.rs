@@ -0,0 +1,273 @@
+// Persistent per-faction contract history and reputation tracking
+use std::collections::HashMap;
+use crate::{o_debug};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::models::Contract;
+use super::persistence::StorageFormat;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractRecord {
+    pub contract_id: String,
+    pub contract_type: String,
+    pub total_payment: i64,
+    pub total_units: i32,
+    pub trade_symbols: Vec<String>,
+    pub negotiated_at: DateTime<Utc>,
+    pub accepted: bool,
+    pub fulfilled_at: Option<DateTime<Utc>>,
+}
+
+impl ContractRecord {
+    pub fn from_contract(contract: &Contract) -> Self {
+        Self {
+            contract_id: contract.id.clone(),
+            contract_type: contract.contract_type.clone(),
+            total_payment: contract.terms.payment.on_accepted + contract.terms.payment.on_fulfilled,
+            total_units: contract.terms.deliver.iter().map(|d| d.units_required).sum(),
+            trade_symbols: contract.terms.deliver.iter().map(|d| d.trade_symbol.clone()).collect(),
+            negotiated_at: Utc::now(),
+            accepted: contract.accepted,
+            fulfilled_at: None,
+        }
+    }
+
+    pub fn payment_per_unit(&self) -> f64 {
+        if self.total_units > 0 {
+            self.total_payment as f64 / self.total_units as f64
+        } else {
+            self.total_payment as f64
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReputationSample {
+    pub reputation: i32,
+    pub recorded_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct FactionRecord {
+    pub faction_symbol: String,
+    pub contracts: Vec<ContractRecord>,
+    pub reputation_history: Vec<ReputationSample>,
+}
+
+impl FactionRecord {
+    pub fn current_reputation(&self) -> Option<i32> {
+        self.reputation_history.last().map(|s| s.reputation)
+    }
+
+    pub fn fulfilled_count(&self) -> usize {
+        self.contracts.iter().filter(|c| c.fulfilled_at.is_some()).count()
+    }
+
+    /// Reputation change since the first recorded sample
+    pub fn reputation_change(&self) -> i32 {
+        match (self.reputation_history.first(), self.reputation_history.last()) {
+            (Some(first), Some(last)) => last.reputation - first.reputation,
+            _ => 0,
+        }
+    }
+
+    pub fn average_payment_per_unit(&self) -> Option<f64> {
+        if self.contracts.is_empty() {
+            return None;
+        }
+        let total: f64 = self.contracts.iter().map(|c| c.payment_per_unit()).sum();
+        Some(total / self.contracts.len() as f64)
+    }
+}
+
+pub const FACTION_FORMAT: StorageFormat = StorageFormat { name: "faction history", first_version: 1, migrations: &[] };
+
+pub struct FactionStore {
+    storage_path: String,
+    factions: HashMap<String, FactionRecord>,
+}
+
+impl FactionStore {
+    pub fn new(storage_path: &str) -> Self {
+        let mut store = Self {
+            storage_path: storage_path.to_string(),
+            factions: HashMap::new(),
+        };
+
+        if let Err(e) = store.load_from_disk() {
+            o_debug!("⚠️ Failed to load faction history: {}", e);
+            o_debug!("💾 Starting with empty faction history");
+        }
+
+        store
+    }
+
+    fn record_mut(&mut self, faction_symbol: &str) -> &mut FactionRecord {
+        self.factions.entry(faction_symbol.to_string()).or_insert_with(|| FactionRecord {
+            faction_symbol: faction_symbol.to_string(),
+            ..Default::default()
+        })
+    }
+
+    pub fn get_faction(&self, faction_symbol: &str) -> Option<&FactionRecord> {
+        self.factions.get(faction_symbol)
+    }
+
+    pub fn record_negotiated(&mut self, contract: &Contract) -> Result<(), Box<dyn std::error::Error>> {
+        let record = self.record_mut(&contract.faction_symbol);
+        if !record.contracts.iter().any(|c| c.contract_id == contract.id) {
+            record.contracts.push(ContractRecord::from_contract(contract));
+            o_debug!("💾 Recorded contract {} for faction {}", contract.id, contract.faction_symbol);
+        }
+        self.save_to_disk()
+    }
+
+    pub fn record_accepted(&mut self, contract: &Contract) -> Result<(), Box<dyn std::error::Error>> {
+        let record = self.record_mut(&contract.faction_symbol);
+        match record.contracts.iter_mut().find(|c| c.contract_id == contract.id) {
+            Some(existing) => existing.accepted = true,
+            None => {
+                let mut new_record = ContractRecord::from_contract(contract);
+                new_record.accepted = true;
+                record.contracts.push(new_record);
+            }
+        }
+        self.save_to_disk()
+    }
+
+    pub fn record_fulfilled(&mut self, contract: &Contract) -> Result<(), Box<dyn std::error::Error>> {
+        let record = self.record_mut(&contract.faction_symbol);
+        match record.contracts.iter_mut().find(|c| c.contract_id == contract.id) {
+            Some(existing) => existing.fulfilled_at = Some(Utc::now()),
+            None => {
+                let mut new_record = ContractRecord::from_contract(contract);
+                new_record.accepted = true;
+                new_record.fulfilled_at = Some(Utc::now());
+                record.contracts.push(new_record);
+            }
+        }
+        o_debug!("💾 Recorded fulfillment of {} for faction {}", contract.id, contract.faction_symbol);
+        self.save_to_disk()
+    }
+
+    /// Record the latest reputation for a faction, skipping unchanged values
+    pub fn record_reputation(&mut self, faction_symbol: &str, reputation: i32) -> Result<(), Box<dyn std::error::Error>> {
+        let record = self.record_mut(faction_symbol);
+        if record.current_reputation() == Some(reputation) {
+            return Ok(());
+        }
+        record.reputation_history.push(ReputationSample {
+            reputation,
+            recorded_at: Utc::now(),
+        });
+        self.save_to_disk()
+    }
+
+    /// Score a faction for contract negotiation. Higher is better.
+    ///
+    /// Factions with no history get a neutral score so they still get tried.
+    pub fn score_faction(&self, faction_symbol: &str) -> f64 {
+        let Some(record) = self.factions.get(faction_symbol) else {
+            return 0.0;
+        };
+
+        let pay_score = record.average_payment_per_unit().unwrap_or(0.0);
+        let reputation_bonus = record.current_reputation().unwrap_or(0) as f64 * 0.5;
+        let fulfillment_bonus = record.fulfilled_count() as f64 * 10.0;
+
+        pay_score + reputation_bonus + fulfillment_bonus
+    }
+
+    /// Rank candidate factions from best to worst
+    pub fn rank_factions(&self, candidates: &[String]) -> Vec<(String, f64)> {
+        let mut ranked: Vec<(String, f64)> = candidates.iter()
+            .map(|symbol| (symbol.clone(), self.score_faction(symbol)))
+            .collect();
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        ranked
+    }
+
+    fn load_from_disk(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(records) = FACTION_FORMAT.load::<Vec<FactionRecord>>(&self.storage_path)? else {
+            return Ok(());
+        };
+
+        self.factions.clear();
+        for record in records {
+            self.factions.insert(record.faction_symbol.clone(), record);
+        }
+
+        o_debug!("💾 Loaded history for {} factions from disk", self.factions.len());
+        Ok(())
+    }
+
+    fn save_to_disk(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let records: Vec<FactionRecord> = self.factions.values().cloned().collect();
+        FACTION_FORMAT.save(&self.storage_path, &records)
+    }
+
+    pub fn print_status(&self) {
+        if self.factions.is_empty() {
+            o_debug!("💾 Faction History: no contracts recorded yet");
+            return;
+        }
+
+        o_debug!("💾 Faction History: {} factions", self.factions.len());
+        for record in self.factions.values() {
+            o_debug!("   • {}: {} contracts ({} fulfilled), reputation {} ({:+}), {:.1} credits/unit",
+                    record.faction_symbol,
+                    record.contracts.len(),
+                    record.fulfilled_count(),
+                    record.current_reputation().unwrap_or(0),
+                    record.reputation_change(),
+                    record.average_payment_per_unit().unwrap_or(0.0));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract_record(id: &str, total_payment: i64, total_units: i32, fulfilled: bool) -> ContractRecord {
+        ContractRecord {
+            contract_id: id.to_string(),
+            contract_type: "PROCUREMENT".to_string(),
+            total_payment,
+            total_units,
+            trade_symbols: vec!["IRON_ORE".to_string()],
+            negotiated_at: Utc::now(),
+            accepted: true,
+            fulfilled_at: fulfilled.then(Utc::now),
+        }
+    }
+
+    fn faction(symbol: &str, contracts: Vec<ContractRecord>, reputation: i32) -> FactionRecord {
+        FactionRecord {
+            faction_symbol: symbol.to_string(),
+            contracts,
+            reputation_history: vec![ReputationSample { reputation, recorded_at: Utc::now() }],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_factions_rank_by_pay_reputation_and_fulfilment() {
+        let path = std::env::temp_dir().join(format!("faction_rank_{}.json", std::process::id()));
+        let mut store = FactionStore::new(path.to_str().unwrap());
+        // 75 credits/unit on average, +10 for reputation and +10 for the fulfilled contract
+        store.factions.insert("COSMIC".to_string(), faction("COSMIC", vec![
+            contract_record("C1", 10000, 100, true),
+            contract_record("C2", 5000, 100, false),
+        ], 20));
+        // Pays best per unit but the poor reputation costs 50
+        store.factions.insert("VOID".to_string(), faction("VOID", vec![contract_record("V1", 20000, 100, false)], -100));
+
+        assert_eq!(store.score_faction("COSMIC"), 95.0);
+        let ranked = store.rank_factions(&["COSMIC".to_string(), "UNKNOWN".to_string(), "VOID".to_string()]);
+        assert_eq!(ranked, vec![("VOID".to_string(), 150.0), ("COSMIC".to_string(), 95.0), ("UNKNOWN".to_string(), 0.0)]);
+
+        store.record_reputation("COSMIC", 20).expect("unchanged reputation");
+        assert_eq!(store.get_faction("COSMIC").unwrap().reputation_history.len(), 1);
+        assert!(!path.exists(), "an unchanged reputation is not saved");
+    }
+}
@@ -0,0 +1,140 @@
+// Persistent goal queue so goals and their progress survive restarts
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::goals::{GoalAction, GoalParameters, GoalPriority, GoalProgress};
+use crate::o_debug;
+use super::persistence::StorageFormat;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StoredGoalState {
+    Queued,
+    Running,
+    Completed,
+    Failed(String),
+}
+
+/// Everything needed to rebuild a goal: what it does, its parameters and how far it got
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredGoal {
+    pub id: String,
+    pub action: GoalAction,
+    pub description: String,
+    pub priority: GoalPriority,
+    pub params: GoalParameters,
+    pub state: StoredGoalState,
+    pub progress: GoalProgress,
+    pub queued_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl StoredGoal {
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, StoredGoalState::Completed | StoredGoalState::Failed(_))
+    }
+}
+
+pub const GOAL_STORE_FORMAT: StorageFormat = StorageFormat { name: "goals", first_version: 1, migrations: &[] };
+
+pub struct GoalStore {
+    storage_path: String,
+    goals: Vec<StoredGoal>, // in queue order
+}
+
+impl GoalStore {
+    pub fn new(storage_path: &str) -> Self {
+        let mut store = Self {
+            storage_path: storage_path.to_string(),
+            goals: Vec::new(),
+        };
+
+        if let Err(e) = store.load_from_disk() {
+            o_debug!("⚠️ Failed to load goal queue: {}", e);
+            o_debug!("💾 Starting with empty goal queue");
+        }
+
+        store
+    }
+
+    pub fn goals(&self) -> &[StoredGoal] {
+        &self.goals
+    }
+
+    pub fn get_goal(&self, goal_id: &str) -> Option<&StoredGoal> {
+        self.goals.iter().find(|goal| goal.id == goal_id)
+    }
+
+    /// Goals that were queued or running when the agent last stopped
+    pub fn unfinished_goals(&self) -> Vec<&StoredGoal> {
+        self.goals.iter().filter(|goal| !goal.is_finished()).collect()
+    }
+
+    pub fn has_unfinished_goals(&self) -> bool {
+        self.goals.iter().any(|goal| !goal.is_finished())
+    }
+
+    /// Insert a goal, replacing any earlier record with the same ID
+    pub fn upsert_goal(&mut self, goal: StoredGoal) -> Result<(), Box<dyn std::error::Error>> {
+        match self.goals.iter_mut().find(|existing| existing.id == goal.id) {
+            Some(existing) => *existing = goal,
+            None => self.goals.push(goal),
+        }
+        self.save_to_disk()
+    }
+
+    pub fn set_state(&mut self, goal_id: &str, state: StoredGoalState) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(goal) = self.goals.iter_mut().find(|goal| goal.id == goal_id) else {
+            return Ok(());
+        };
+        if matches!(state, StoredGoalState::Completed | StoredGoalState::Failed(_)) {
+            goal.finished_at = Some(Utc::now());
+        }
+        goal.state = state;
+        self.save_to_disk()
+    }
+
+    /// Copy the latest progress of running goals, saving only if something changed
+    pub fn update_progress(&mut self, updates: Vec<(String, GoalProgress)>) -> Result<(), Box<dyn std::error::Error>> {
+        let mut changed = false;
+        for (goal_id, progress) in updates {
+            if let Some(goal) = self.goals.iter_mut().find(|goal| goal.id == goal_id)
+                && goal.progress != progress {
+                goal.progress = progress;
+                changed = true;
+            }
+        }
+        if changed {
+            self.save_to_disk()?;
+        }
+        Ok(())
+    }
+
+    /// Drop completed and failed goals
+    pub fn clear_finished(&mut self) -> Result<usize, Box<dyn std::error::Error>> {
+        let before = self.goals.len();
+        self.goals.retain(|goal| !goal.is_finished());
+        self.save_to_disk()?;
+        Ok(before - self.goals.len())
+    }
+
+    /// Drop every goal, finished or not
+    pub fn clear_all(&mut self) -> Result<usize, Box<dyn std::error::Error>> {
+        let count = self.goals.len();
+        self.goals.clear();
+        self.save_to_disk()?;
+        Ok(count)
+    }
+
+    fn load_from_disk(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(goals) = GOAL_STORE_FORMAT.load::<Vec<StoredGoal>>(&self.storage_path)? else {
+            return Ok(());
+        };
+        self.goals = goals;
+
+        o_debug!("💾 Loaded {} goals ({} unfinished) from disk", self.goals.len(), self.unfinished_goals().len());
+        Ok(())
+    }
+
+    fn save_to_disk(&self) -> Result<(), Box<dyn std::error::Error>> {
+        GOAL_STORE_FORMAT.save(&self.storage_path, &self.goals)
+    }
+}
@@ -0,0 +1,141 @@
+// Income ledger - credits each ship earns from sales, for measuring what a ship type pays back
+use std::sync::{Arc, Mutex, OnceLock};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use crate::events::{EventSubscriber, GameEvent, PublishedEvent};
+use crate::o_debug;
+use super::persistence::StorageFormat;
+
+pub const INCOME_LEDGER_PATH: &str = "storage/income_ledger.json";
+/// Oldest records are dropped beyond this so the ledger stays small
+const MAX_RECORDS: usize = 5000;
+/// Income is averaged over at least this long so a single sale is not read as an hourly rate
+const MIN_MEASURED_HOURS: f64 = 0.25;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncomeRecord {
+    pub timestamp: DateTime<Utc>,
+    pub ship_symbol: String,
+    pub good: String,
+    pub units: i32,
+    pub credits: i64,
+}
+
+pub const INCOME_LEDGER_FORMAT: StorageFormat = StorageFormat { name: "income ledger", first_version: 1, migrations: &[] };
+
+/// Shared handle to the income ledger; clones see the same records
+#[derive(Debug, Clone)]
+pub struct IncomeLedger {
+    storage_path: Option<String>,
+    records: Arc<Mutex<Vec<IncomeRecord>>>,
+}
+
+static GLOBAL_INCOME_LEDGER: OnceLock<IncomeLedger> = OnceLock::new();
+
+/// The income ledger shared by the whole fleet
+pub fn get_income_ledger() -> &'static IncomeLedger {
+    GLOBAL_INCOME_LEDGER.get_or_init(|| IncomeLedger::new(INCOME_LEDGER_PATH))
+}
+
+impl IncomeLedger {
+    pub fn new(storage_path: &str) -> Self {
+        let ledger = Self {
+            storage_path: Some(storage_path.to_string()),
+            records: Arc::new(Mutex::new(Vec::new())),
+        };
+
+        if let Err(e) = ledger.load_from_disk() {
+            o_debug!("⚠️ Failed to load income ledger: {}", e);
+            o_debug!("💾 Starting with empty income ledger");
+        }
+
+        ledger
+    }
+
+    /// A ledger that is never written to disk
+    pub fn in_memory() -> Self {
+        Self {
+            storage_path: None,
+            records: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    pub fn record(&self, record: IncomeRecord) {
+        let mut records = self.records.lock().unwrap();
+        records.push(record);
+        if records.len() > MAX_RECORDS {
+            let excess = records.len() - MAX_RECORDS;
+            records.drain(..excess);
+        }
+        self.save_to_disk(&records);
+    }
+
+    pub fn record_sale(&self, ship_symbol: &str, good: &str, units: i32, credits: i64) {
+        o_debug!("💵 {} sold {} x{} for {}", ship_symbol, good, units, credits);
+        self.record(IncomeRecord {
+            timestamp: Utc::now(),
+            ship_symbol: ship_symbol.to_string(),
+            good: good.to_string(),
+            units,
+            credits,
+        });
+    }
+
+    pub fn records(&self) -> Vec<IncomeRecord> {
+        self.records.lock().unwrap().clone()
+    }
+
+    /// Average credits per hour a ship earned over the window, or None if it sold nothing
+    pub fn credits_per_hour(&self, ship_symbol: &str, window: Duration) -> Option<f64> {
+        let now = Utc::now();
+        let since = now - window;
+        let records = self.records.lock().unwrap();
+        let sales: Vec<&IncomeRecord> = records.iter()
+            .filter(|record| record.ship_symbol == ship_symbol && record.timestamp >= since)
+            .collect();
+        let first = sales.iter().map(|record| record.timestamp).min()?;
+        let credits: i64 = sales.iter().map(|record| record.credits).sum();
+        let hours = ((now - first).num_seconds() as f64 / 3600.0).max(MIN_MEASURED_HOURS);
+        Some(credits as f64 / hours)
+    }
+
+    pub fn clear(&self) {
+        let mut records = self.records.lock().unwrap();
+        records.clear();
+        self.save_to_disk(&records);
+    }
+
+    fn load_from_disk(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(storage_path) = &self.storage_path else {
+            return Ok(());
+        };
+        let Some(records) = INCOME_LEDGER_FORMAT.load::<Vec<IncomeRecord>>(storage_path)? else {
+            return Ok(());
+        };
+        o_debug!("💾 Loaded {} income records from disk", records.len());
+        *self.records.lock().unwrap() = records;
+        Ok(())
+    }
+
+    fn save_to_disk(&self, records: &[IncomeRecord]) {
+        let Some(storage_path) = &self.storage_path else {
+            return;
+        };
+        if let Err(e) = INCOME_LEDGER_FORMAT.save(storage_path, &records) {
+            o_debug!("⚠️ Failed to save income ledger: {}", e);
+        }
+    }
+}
+
+/// The ledger records sales as they are published on the event bus
+impl EventSubscriber for IncomeLedger {
+    fn name(&self) -> &str {
+        "income ledger"
+    }
+
+    fn handle(&mut self, event: &PublishedEvent) {
+        if let GameEvent::Sale { ship_symbol, good, units, total_price } = &event.event {
+            self.record_sale(ship_symbol, good, *units, *total_price);
+        }
+    }
+}
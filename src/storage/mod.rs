@@ -2,7 +2,22 @@
 pub mod cooldown_store;
 pub mod ship_state_store;
 pub mod survey_cache;
+pub mod faction_store;
+pub mod goal_store;
+pub mod survey_pool;
+pub mod extraction_log;
+pub mod income_ledger;
+pub mod condition_history;
+pub mod persistence;
 
 pub use cooldown_store::*;
 pub use ship_state_store::*;
-pub use survey_cache::*;
\ No newline at end of file
+pub use survey_cache::*;
+pub use faction_store::*;
+pub use goal_store::*;
+pub use survey_pool::*;
+pub use extraction_log::*;
+pub use income_ledger::*;
+pub use condition_history::*;
+pub use persistence::*;
+
@@ -0,0 +1,147 @@
+// Persistence - versioned JSON files written atomically, with migrations for files from older versions
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::Path;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::{o_error, o_info};
+
+/// Everything the bot keeps between runs lives here
+pub const STORAGE_DIR: &str = "storage";
+/// Remembers which server reset the stored data belongs to
+const RESET_MARKER_FILE: &str = "server_reset.json";
+
+/// Upgrades a file's data from one schema version to the next
+pub type Migration = fn(Value) -> Result<Value, String>;
+
+/// Cooldowns, ship states and the survey cache were written as bare data before versioning. Their formats
+/// start at version 0 and use this as their first migration, so those files load unchanged as version 1.
+/// Formats added since start at version 1 and have nothing to migrate from.
+pub fn from_unversioned(data: Value) -> Result<Value, String> {
+    Ok(data)
+}
+
+/// On-disk layout of every versioned file
+#[derive(Serialize, Deserialize)]
+struct Envelope<T> {
+    schema_version: u32,
+    data: T,
+}
+
+/// A stored file format: `migrations[n]` upgrades version `first_version + n` to the next, so the current
+/// version is `first_version` plus the number of migrations. Files from before versioning count as version 0.
+#[derive(Debug, Clone, Copy)]
+pub struct StorageFormat {
+    pub name: &'static str,
+    /// Oldest version this format can read
+    pub first_version: u32,
+    pub migrations: &'static [Migration],
+}
+
+impl StorageFormat {
+    pub const fn version(&self) -> u32 {
+        self.first_version + self.migrations.len() as u32
+    }
+
+    /// Bring a file's contents up to the current version, returning the data and the version it was stored at
+    pub fn migrate(&self, file: Value) -> Result<(Value, u32), String> {
+        let (stored_version, mut data) = match file {
+            Value::Object(mut object) if object.contains_key("schema_version") && object.contains_key("data") => {
+                let version = object.get("schema_version").and_then(Value::as_u64)
+                    .ok_or_else(|| format!("{} file has an invalid schema_version", self.name))? as u32;
+                (version, object.remove("data").unwrap_or(Value::Null))
+            }
+            bare => (0, bare),
+        };
+        if stored_version > self.version() {
+            return Err(format!("{} file is schema version {}, newer than this build understands ({})",
+                               self.name, stored_version, self.version()));
+        }
+        if stored_version < self.first_version {
+            return Err(format!("{} file is schema version {}, older than this build understands ({})",
+                               self.name, stored_version, self.first_version));
+        }
+        let pending = self.migrations.iter().enumerate().skip((stored_version - self.first_version) as usize);
+        for (index, migration) in pending {
+            let from = self.first_version as usize + index;
+            data = migration(data).map_err(|e| format!("{} migration {} -> {} failed: {}", self.name, from, from + 1, e))?;
+        }
+        Ok((data, stored_version))
+    }
+
+    pub fn save<T: Serialize>(&self, path: &str, data: &T) -> Result<(), Box<dyn std::error::Error>> {
+        let content = serde_json::to_string_pretty(&Envelope { schema_version: self.version(), data })?;
+        write_atomically(path, &content)?;
+        Ok(())
+    }
+
+    /// Read a file, migrating and rewriting it if it is from an older version. A file that cannot be read
+    /// is moved to `<path>.corrupt` so the next save does not destroy it.
+    pub fn load<T: DeserializeOwned + Serialize>(&self, path: &str) -> Result<Option<T>, Box<dyn std::error::Error>> {
+        if !Path::new(path).exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path)?;
+        let loaded = serde_json::from_str::<Value>(&content)
+            .map_err(|e| e.to_string())
+            .and_then(|file| self.migrate(file))
+            .and_then(|(data, stored_version)| {
+                serde_json::from_value::<T>(data).map(|data| (data, stored_version)).map_err(|e| e.to_string())
+            });
+        let (data, stored_version) = match loaded {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                let corrupt_path = format!("{}.corrupt", path);
+                if let Err(rename_error) = fs::rename(path, &corrupt_path) {
+                    o_error!("⚠️ Could not move unreadable {} aside: {}", path, rename_error);
+                }
+                return Err(format!("{} could not be loaded (kept as {}): {}", path, corrupt_path, e).into());
+            }
+        };
+        if stored_version < self.version() {
+            o_info!("💾 Migrated {} from schema version {} to {}", path, stored_version, self.version());
+            self.save(path, &data)?;
+        }
+        Ok(Some(data))
+    }
+}
+
+/// Write a file through a synced temporary sibling and a rename, so a crash or shutdown never leaves a truncated file
+pub fn write_atomically(path: &str, content: &str) -> std::io::Result<()> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let temp_path = format!("{}.tmp", path);
+    let mut file = File::create(&temp_path)?;
+    file.write_all(content.as_bytes())?;
+    file.sync_all()?;
+    fs::rename(&temp_path, path)
+}
+
+const RESET_MARKER_FORMAT: StorageFormat = StorageFormat { name: "server reset marker", first_version: 1, migrations: &[] };
+
+/// Empty `storage_dir` when the server has been reset since the data in it was written, so nothing
+/// about the old universe leaks into the new one. Returns whether anything was cleared.
+pub fn clear_on_server_reset(storage_dir: &str, reset_date: &str) -> Result<bool, Box<dyn std::error::Error>> {
+    let marker_path = Path::new(storage_dir).join(RESET_MARKER_FILE);
+    let marker_path = marker_path.to_str().ok_or("storage path is not valid UTF-8")?;
+    let known_reset = RESET_MARKER_FORMAT.load::<String>(marker_path).unwrap_or(None);
+
+    // Data from before the marker existed is kept; its reset date is unknown
+    let reset_happened = known_reset.as_deref().is_some_and(|known| known != reset_date);
+    if reset_happened {
+        for entry in fs::read_dir(storage_dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                fs::remove_dir_all(&path)?;
+            } else {
+                fs::remove_file(&path)?;
+            }
+        }
+    }
+    if known_reset.as_deref() != Some(reset_date) {
+        RESET_MARKER_FORMAT.save(marker_path, &reset_date)?;
+    }
+    Ok(reset_happened)
+}
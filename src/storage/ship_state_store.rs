@@ -1,11 +1,11 @@
 // Persistent ship state storage system
 use std::collections::HashMap;
-use std::fs;
 use crate::{o_debug};
-use std::path::Path;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use super::persistence::{from_unversioned, StorageFormat};
 use crate::models::Ship;
+use crate::operations::{ActionId, ShipMission};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CachedShipState {
@@ -14,6 +14,8 @@ pub struct CachedShipState {
     pub last_api_refresh: DateTime<Utc>,
     pub is_stale: bool,
     pub pending_actions: Vec<String>, // Track what we've told the ship to do
+    #[serde(default)]
+    pub missions: Vec<ShipMission>, // Running action first, then queued ones; survive restarts so the ship picks up where it left off
 }
 
 impl CachedShipState {
@@ -25,6 +27,7 @@ impl CachedShipState {
             last_api_refresh: now,
             is_stale: false,
             pending_actions: Vec::new(),
+            missions: Vec::new(),
         }
     }
     
@@ -52,6 +55,9 @@ impl CachedShipState {
     }
 }
 
+pub const SHIP_STATE_PATH: &str = "storage/ship_states.json";
+pub const SHIP_STATE_FORMAT: StorageFormat = StorageFormat { name: "ship states", first_version: 0, migrations: &[from_unversioned] };
+
 pub struct ShipStateStore {
     storage_path: String,
     ships: HashMap<String, CachedShipState>,
@@ -77,7 +83,9 @@ impl ShipStateStore {
     
     pub fn cache_ship(&mut self, ship: Ship) -> Result<(), Box<dyn std::error::Error>> {
         let ship_symbol = ship.symbol.clone();
-        let cached_state = CachedShipState::new(ship);
+        let mut cached_state = CachedShipState::new(ship);
+        // A fresh copy of the ship must not lose the missions it is part way through
+        cached_state.missions = self.ships.get(&ship_symbol).map(|cached| cached.missions.clone()).unwrap_or_default();
         
         self.ships.insert(ship_symbol.clone(), cached_state);
         
@@ -138,6 +146,74 @@ impl ShipStateStore {
         Ok(())
     }
     
+    /// The ship's missions in the order they run
+    pub fn missions(&self, ship_symbol: &str) -> &[ShipMission] {
+        self.ships.get(ship_symbol).map(|cached| cached.missions.as_slice()).unwrap_or_default()
+    }
+    
+    /// The mission the ship is carrying out now
+    pub fn mission(&self, ship_symbol: &str) -> Option<&ShipMission> {
+        self.missions(ship_symbol).first()
+    }
+    
+    /// Store a mission behind the ship's others, or with `urgent` ahead of them
+    pub fn add_mission(&mut self, ship_symbol: &str, mission: ShipMission, urgent: bool) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(cached) = self.ships.get_mut(ship_symbol) {
+            o_debug!("🎯 {} mission #{}: {} steps", ship_symbol, mission.action_id, mission.steps.len());
+            if urgent {
+                cached.missions.insert(0, mission);
+            } else {
+                cached.missions.push(mission);
+            }
+            self.save_to_disk()?;
+        }
+        Ok(())
+    }
+    
+    /// Record how many of a mission's plan steps are done; only written to disk when it changes
+    pub fn set_mission_progress(&mut self, ship_symbol: &str, action_id: ActionId, completed_steps: usize) -> Result<(), Box<dyn std::error::Error>> {
+        let mission = self.ships.get_mut(ship_symbol)
+            .and_then(|cached| cached.missions.iter_mut().find(|mission| mission.action_id == action_id));
+        let changed = match mission {
+            Some(mission) if mission.completed_steps != completed_steps => {
+                mission.completed_steps = completed_steps;
+                true
+            }
+            _ => false,
+        };
+        if changed {
+            self.save_to_disk()?;
+        }
+        Ok(())
+    }
+    
+    /// Clear the mission for one action, or with no action ID every mission the ship has
+    pub fn clear_mission(&mut self, ship_symbol: &str, action_id: Option<ActionId>) -> Result<(), Box<dyn std::error::Error>> {
+        let cleared = match self.ships.get_mut(ship_symbol) {
+            Some(cached) => {
+                let before = cached.missions.len();
+                cached.missions.retain(|mission| action_id.is_some_and(|id| id != mission.action_id));
+                cached.missions.len() < before
+            }
+            None => false,
+        };
+        if cleared {
+            o_debug!("🎯 {} mission cleared", ship_symbol);
+            self.save_to_disk()?;
+        }
+        Ok(())
+    }
+    
+    /// Goods any of the ship's missions is holding on to
+    pub fn reserved_goods(&self, ship_symbol: &str) -> Vec<String> {
+        let mut goods: Vec<String> = self.missions(ship_symbol).iter()
+            .flat_map(|mission| mission.reserved_goods.iter().cloned())
+            .collect();
+        goods.sort();
+        goods.dedup();
+        goods
+    }
+    
     pub fn get_stale_ships(&self) -> Vec<String> {
         let mut stale = Vec::new();
         
@@ -204,12 +280,10 @@ impl ShipStateStore {
     }
     
     fn load_from_disk(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        if !Path::new(&self.storage_path).exists() {
-            return Ok(()); // File doesn't exist yet, start fresh
-        }
-        
-        let content = fs::read_to_string(&self.storage_path)?;
-        let cached_states: Vec<CachedShipState> = serde_json::from_str(&content)?;
+        // Missing files start fresh; files from older versions are migrated
+        let Some(cached_states) = SHIP_STATE_FORMAT.load::<Vec<CachedShipState>>(&self.storage_path)? else {
+            return Ok(());
+        };
         
         // Convert to HashMap
         self.ships.clear();
@@ -225,18 +299,15 @@ impl ShipStateStore {
         Ok(())
     }
     
+    /// Write the current contents to disk, e.g. before shutting down
+    pub fn flush(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self.save_to_disk()
+    }
+    
     fn save_to_disk(&self) -> Result<(), Box<dyn std::error::Error>> {
         // Convert HashMap to Vec for serialization
         let cached_states: Vec<CachedShipState> = self.ships.values().cloned().collect();
-        let content = serde_json::to_string_pretty(&cached_states)?;
-        
-        // Create parent directory if it doesn't exist
-        if let Some(parent) = Path::new(&self.storage_path).parent() {
-            fs::create_dir_all(parent)?;
-        }
-        
-        fs::write(&self.storage_path, content)?;
-        Ok(())
+        SHIP_STATE_FORMAT.save(&self.storage_path, &cached_states)
     }
     
     // Helper method to get fresh ship data (either from cache or force API refresh)
@@ -1,11 +1,20 @@
-// Persistent scan and survey data storage system
+// Persistent waypoint scan storage. Surveys live in the fleet-wide SurveyPool.
 use std::collections::HashMap;
-use std::fs;
 use crate::{o_debug};
-use std::path::Path;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use crate::models::{Waypoint, SurveyData};
+use crate::models::Waypoint;
+use super::persistence::{from_unversioned, StorageFormat};
+
+pub const SURVEY_CACHE_PATH: &str = "storage/survey_cache.json";
+
+pub const SURVEY_CACHE_FORMAT: StorageFormat = StorageFormat { name: "survey cache", first_version: 0, migrations: &[from_unversioned] };
+
+/// Layout of the cache file; borrowed when saving, owned when loading
+#[derive(Serialize, Deserialize)]
+struct CacheData<M> {
+    waypoint_cache: M,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CachedWaypointData {
@@ -14,19 +23,9 @@ pub struct CachedWaypointData {
     pub system_symbol: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CachedSurveyData {
-    pub surveys: Vec<crate::models::Survey>,
-    pub last_surveyed: DateTime<Utc>,
-    pub waypoint_symbol: String,
-    pub expires_at: DateTime<Utc>, // Surveys have expiration times
-}
-
-
 pub struct SurveyCache {
     storage_path: String,
     waypoint_cache: HashMap<String, CachedWaypointData>, // system_symbol -> waypoints
-    survey_cache: HashMap<String, CachedSurveyData>,     // waypoint_symbol -> surveys
     cache_duration_hours: i64,
 }
 
@@ -35,7 +34,6 @@ impl SurveyCache {
         let mut cache = Self {
             storage_path: storage_path.to_string(),
             waypoint_cache: HashMap::new(),
-            survey_cache: HashMap::new(),
             cache_duration_hours,
         };
         
@@ -84,68 +82,21 @@ impl SurveyCache {
         !self.get_cached_waypoints(system_symbol).is_some()
     }
     
-    // Survey caching methods
-    pub fn cache_survey_data(&mut self, waypoint_symbol: &str, survey_data: &SurveyData) -> Result<(), Box<dyn std::error::Error>> {
-        // Surveys typically expire in 30 minutes
-        let expires_at = Utc::now() + chrono::Duration::minutes(30);
-        
-        let cached_data = CachedSurveyData {
-            surveys: survey_data.surveys.clone(),
-            last_surveyed: Utc::now(),
-            waypoint_symbol: waypoint_symbol.to_string(),
-            expires_at,
-        };
-        
-        self.survey_cache.insert(waypoint_symbol.to_string(), cached_data);
-        
-        o_debug!("💾 Cached {} surveys for waypoint {} (expires: {})", 
-                survey_data.surveys.len(), waypoint_symbol, expires_at.format("%H:%M:%S UTC"));
-        
-        self.save_to_disk()?;
-        Ok(())
-    }
-    
-    pub fn get_cached_surveys(&self, waypoint_symbol: &str) -> Option<&Vec<crate::models::Survey>> {
-        if let Some(cached) = self.survey_cache.get(waypoint_symbol) {
-            let now = Utc::now();
-            
-            if now < cached.expires_at {
-                let remaining_minutes = cached.expires_at.signed_duration_since(now).num_minutes();
-                o_debug!("📋 Using cached surveys for {} ({}min remaining)", waypoint_symbol, remaining_minutes);
-                return Some(&cached.surveys);
-            } else {
-                o_debug!("⏰ Surveys for {} have expired", waypoint_symbol);
-            }
-        }
-        None
-    }
-    
-    pub fn should_survey_waypoint(&self, waypoint_symbol: &str) -> bool {
-        !self.get_cached_surveys(waypoint_symbol).is_some()
-    }
-    
-    
     // Cache management methods
     pub fn cleanup_expired(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         let now = Utc::now();
-        let initial_surveys = self.survey_cache.len();
         let initial_waypoints = self.waypoint_cache.len();
         
-        // Remove expired surveys
-        self.survey_cache.retain(|_, cached| now < cached.expires_at);
-        
         // Remove stale waypoint data
         let waypoint_threshold = chrono::Duration::hours(self.cache_duration_hours);
         self.waypoint_cache.retain(|_, cached| {
             now.signed_duration_since(cached.last_scanned) <= waypoint_threshold
         });
         
-        let removed_surveys = initial_surveys - self.survey_cache.len();
         let removed_waypoints = initial_waypoints - self.waypoint_cache.len();
         
-        if removed_surveys > 0 || removed_waypoints > 0 {
-            o_debug!("🧹 Cleaned up cache: {} surveys, {} waypoints expired", 
-                    removed_surveys, removed_waypoints);
+        if removed_waypoints > 0 {
+            o_debug!("🧹 Cleaned up cache: {} waypoints expired", removed_waypoints);
             self.save_to_disk()?;
         }
         
@@ -155,7 +106,8 @@ impl SurveyCache {
     pub fn print_cache_status(&self) {
         o_debug!("💾 Survey Cache Status:");
         o_debug!("   📊 Cached systems: {}", self.waypoint_cache.len());
-        o_debug!("   🔍 Active surveys: {}", self.survey_cache.len());
+        let pool_stats = crate::storage::get_survey_pool().stats();
+        o_debug!("   🔍 Pooled surveys: {} across {} waypoints", pool_stats.total_surveys, pool_stats.waypoints);
         o_debug!("   ⏱️  Cache duration: {}h", self.cache_duration_hours);
         
         // Show system details
@@ -165,15 +117,6 @@ impl SurveyCache {
             o_debug!("     • System {}: {} waypoints {} ({}h ago)", 
                     system, cached.waypoints.len(), status, age_hours);
         }
-        
-        // Show survey details
-        for (waypoint, cached) in &self.survey_cache {
-            let remaining_minutes = cached.expires_at.signed_duration_since(Utc::now()).num_minutes();
-            if remaining_minutes > 0 {
-                o_debug!("     • Surveys {}: {} deposits ({}min left)", 
-                        waypoint, cached.surveys.len(), remaining_minutes);
-            }
-        }
     }
     
     // Find cached waypoints by type or trait
@@ -214,25 +157,14 @@ impl SurveyCache {
     }
     
     fn load_from_disk(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        if !Path::new(&self.storage_path).exists() {
-            return Ok(()); // File doesn't exist yet, start fresh
-        }
-        
-        let content = fs::read_to_string(&self.storage_path)?;
-        
-        #[derive(Deserialize)]
-        struct CacheData {
-            waypoint_cache: HashMap<String, CachedWaypointData>,
-            survey_cache: HashMap<String, CachedSurveyData>,
-        }
-        
-        let cache_data: CacheData = serde_json::from_str(&content)?;
+        // Missing files start fresh; files from older versions are migrated
+        let Some(cache_data) = SURVEY_CACHE_FORMAT.load::<CacheData<HashMap<String, CachedWaypointData>>>(&self.storage_path)? else {
+            return Ok(());
+        };
         
         self.waypoint_cache = cache_data.waypoint_cache;
-        self.survey_cache = cache_data.survey_cache;
         
-        o_debug!("💾 Loaded cache: {} systems, {} surveys", 
-                self.waypoint_cache.len(), self.survey_cache.len());
+        o_debug!("💾 Loaded cache: {} systems", self.waypoint_cache.len());
         
         // Clean up expired entries immediately after loading
         self.cleanup_expired()?;
@@ -240,26 +172,16 @@ impl SurveyCache {
         Ok(())
     }
     
+    /// Write the current contents to disk, e.g. before shutting down
+    pub fn flush(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self.save_to_disk()
+    }
+    
     fn save_to_disk(&self) -> Result<(), Box<dyn std::error::Error>> {
-        #[derive(Serialize)]
-        struct CacheData<'a> {
-            waypoint_cache: &'a HashMap<String, CachedWaypointData>,
-            survey_cache: &'a HashMap<String, CachedSurveyData>,
-        }
-        
         let cache_data = CacheData {
             waypoint_cache: &self.waypoint_cache,
-            survey_cache: &self.survey_cache,
         };
         
-        let content = serde_json::to_string_pretty(&cache_data)?;
-        
-        // Create parent directory if it doesn't exist
-        if let Some(parent) = Path::new(&self.storage_path).parent() {
-            fs::create_dir_all(parent)?;
-        }
-        
-        fs::write(&self.storage_path, content)?;
-        Ok(())
+        SURVEY_CACHE_FORMAT.save(&self.storage_path, &cache_data)
     }
 }
\ No newline at end of file
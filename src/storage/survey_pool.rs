@@ -0,0 +1,385 @@
+// Fleet-wide survey pool - every miner draws from and every surveyor refills the same surveys
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::client::SpaceTradersClient;
+use crate::models::{Contract, Market, Ship, Survey};
+use crate::{o_debug, o_info};
+use super::persistence::StorageFormat;
+
+pub const SURVEY_POOL_PATH: &str = "storage/survey_pool.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PooledSurvey {
+    pub survey: Survey,
+    pub surveyed_by: String,
+    pub added_at: DateTime<Utc>,
+    pub extractions: u32,
+}
+
+impl PooledSurvey {
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&self.survey.expiration)
+            .ok()
+            .map(|expiration| expiration.with_timezone(&Utc))
+    }
+
+    /// Surveys with an unreadable expiration are treated as expired
+    pub fn is_expired(&self) -> bool {
+        self.expires_at().is_none_or(|expires_at| expires_at <= Utc::now())
+    }
+}
+
+/// What each good is worth per unit: its best known sell price, or the contract
+/// payment per unit when an open contract pays more
+#[derive(Debug, Clone, Default)]
+pub struct GoodValues {
+    market_prices: HashMap<String, HashMap<String, f64>>, // good -> market waypoint -> sell price
+    contract_values: HashMap<String, f64>,                // good -> payment per unit
+}
+
+impl GoodValues {
+    /// Replace the prices last seen at this market
+    pub fn record_market(&mut self, market: &Market) {
+        let Some(trade_goods) = &market.trade_goods else {
+            return;
+        };
+        for prices in self.market_prices.values_mut() {
+            prices.remove(&market.symbol);
+        }
+        for good in trade_goods {
+            self.market_prices.entry(good.symbol.clone()).or_default()
+                .insert(market.symbol.clone(), good.sell_price as f64);
+        }
+    }
+
+    /// Replace contract values with those of the open contracts
+    pub fn record_contracts(&mut self, contracts: &[Contract]) {
+        self.contract_values.clear();
+        for contract in contracts.iter().filter(|contract| !contract.fulfilled) {
+            let total_units: i32 = contract.terms.deliver.iter().map(|d| d.units_required).sum();
+            if total_units <= 0 {
+                continue;
+            }
+            // The acceptance payment is already banked once a contract is accepted
+            let payment = if contract.accepted {
+                contract.terms.payment.on_fulfilled
+            } else {
+                contract.terms.payment.on_accepted + contract.terms.payment.on_fulfilled
+            };
+            let per_unit = payment as f64 / total_units as f64;
+            for delivery in contract.terms.deliver.iter().filter(|d| d.units_fulfilled < d.units_required) {
+                let value = self.contract_values.entry(delivery.trade_symbol.clone()).or_insert(0.0);
+                *value = value.max(per_unit);
+            }
+        }
+    }
+
+    /// Markets whose prices have been seen
+    pub fn known_markets(&self) -> usize {
+        let mut markets: Vec<&String> = self.market_prices.values().flat_map(|prices| prices.keys()).collect();
+        markets.sort();
+        markets.dedup();
+        markets.len()
+    }
+
+    pub fn value_of(&self, good: &str) -> Option<f64> {
+        let market = self.market_prices.get(good)
+            .and_then(|prices| prices.values().copied().reduce(f64::max));
+        let contract = self.contract_values.get(good).copied();
+        match (market, contract) {
+            (Some(market), Some(contract)) => Some(market.max(contract)),
+            (market, contract) => market.or(contract),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SurveyPoolStats {
+    pub total_surveys: usize,
+    pub waypoints: usize,
+}
+
+pub const SURVEY_POOL_FORMAT: StorageFormat = StorageFormat { name: "survey pool", first_version: 1, migrations: &[] };
+
+/// Shared handle to the survey pool; clones see the same surveys
+#[derive(Debug, Clone)]
+pub struct SurveyPool {
+    storage_path: Option<String>,
+    surveys: Arc<Mutex<HashMap<String, Vec<PooledSurvey>>>>, // waypoint_symbol -> surveys
+    values: Arc<Mutex<GoodValues>>,
+}
+
+static GLOBAL_SURVEY_POOL: OnceLock<SurveyPool> = OnceLock::new();
+
+/// The survey pool shared by the whole fleet
+pub fn get_survey_pool() -> &'static SurveyPool {
+    GLOBAL_SURVEY_POOL.get_or_init(|| SurveyPool::new(SURVEY_POOL_PATH))
+}
+
+/// Whether an extraction error means the survey can never be used again: the API rejected its
+/// signature (4220), it expired (4221) or it was exhausted (4224)
+pub fn is_dead_survey_error(error: &str) -> bool {
+    matches!(crate::client::api_error_code(error), Some(4220 | 4221 | 4224))
+}
+
+/// Whether a ship carries a surveyor mount and can refill the pool
+pub fn is_surveyor(ship: &Ship) -> bool {
+    ship.mounts.iter().any(|mount| mount.symbol.contains("SURVEYOR"))
+}
+
+impl SurveyPool {
+    pub fn new(storage_path: &str) -> Self {
+        let pool = Self {
+            storage_path: Some(storage_path.to_string()),
+            surveys: Arc::new(Mutex::new(HashMap::new())),
+            values: Arc::new(Mutex::new(GoodValues::default())),
+        };
+
+        if let Err(e) = pool.load_from_disk() {
+            o_debug!("⚠️ Failed to load survey pool: {}", e);
+            o_debug!("💾 Starting with empty survey pool");
+        }
+
+        pool
+    }
+
+    /// A pool that is never written to disk
+    pub fn in_memory() -> Self {
+        Self {
+            storage_path: None,
+            surveys: Arc::new(Mutex::new(HashMap::new())),
+            values: Arc::new(Mutex::new(GoodValues::default())),
+        }
+    }
+
+    pub fn add_surveys(&self, surveyed_by: &str, surveys: &[Survey]) {
+        if surveys.is_empty() {
+            return;
+        }
+        let mut pool = self.surveys.lock().unwrap();
+        for survey in surveys {
+            let waypoint_surveys = pool.entry(survey.symbol.clone()).or_default();
+            if !waypoint_surveys.iter().any(|pooled| pooled.survey.signature == survey.signature) {
+                waypoint_surveys.push(PooledSurvey {
+                    survey: survey.clone(),
+                    surveyed_by: surveyed_by.to_string(),
+                    added_at: Utc::now(),
+                    extractions: 0,
+                });
+            }
+        }
+        o_debug!("💾 {} added {} surveys to the pool", surveyed_by, surveys.len());
+        self.save_to_disk(&pool);
+    }
+
+    /// Feed live sell prices into survey scoring
+    pub fn record_market_prices(&self, market: &Market) {
+        self.values.lock().unwrap().record_market(market);
+    }
+
+    /// Feed open contract payments into survey scoring
+    pub fn record_contract_values(&self, contracts: &[Contract]) {
+        self.values.lock().unwrap().record_contracts(contracts);
+    }
+
+    /// Best known per-unit value of a good from market prices and open contracts
+    pub fn good_value(&self, good: &str) -> Option<f64> {
+        self.values.lock().unwrap().value_of(good)
+    }
+
+    /// Number of markets with live prices, a measure of market coverage
+    pub fn known_markets(&self) -> usize {
+        self.values.lock().unwrap().known_markets()
+    }
+
+    /// Live survey at a waypoint with the highest expected value for the wanted goods
+    pub fn best_survey(&self, waypoint_symbol: &str, wanted: &[String]) -> Option<Survey> {
+        self.best_survey_with_value(waypoint_symbol, wanted).map(|(survey, _)| survey)
+    }
+
+    fn best_survey_with_value(&self, waypoint_symbol: &str, wanted: &[String]) -> Option<(Survey, f64)> {
+        self.prune_expired();
+        let pool = self.surveys.lock().unwrap();
+        let values = self.values.lock().unwrap();
+        pool.get(waypoint_symbol)?
+            .iter()
+            .map(|pooled| (pooled, Self::expected_value(&pooled.survey, wanted, &values)))
+            .filter(|(_, expected)| *expected > 0.0)
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(pooled, expected)| (pooled.survey.clone(), expected))
+    }
+
+    /// The survey a miner should extract with, or None when an unsurveyed extraction
+    /// is expected to be worth at least as much as the best survey
+    pub fn choose_survey(&self, waypoint_symbol: &str, wanted: &[String]) -> Option<Survey> {
+        let (survey, survey_value) = self.best_survey_with_value(waypoint_symbol, wanted)?;
+        let unsurveyed_value = self.unsurveyed_value(waypoint_symbol, wanted);
+        if survey_value <= unsurveyed_value {
+            o_debug!("🎲 Unsurveyed extraction at {} ({:.1}) beats survey {} ({:.1})",
+                    waypoint_symbol, unsurveyed_value, survey.signature, survey_value);
+            return None;
+        }
+        o_debug!("📋 Survey {} expected {:.1} per extraction vs {:.1} unsurveyed",
+                survey.signature, survey_value, unsurveyed_value);
+        Some(survey)
+    }
+
+    /// Expected value of an unsurveyed extraction, estimated from the deposit mix
+    /// of every survey seen at the waypoint
+    pub fn unsurveyed_value(&self, waypoint_symbol: &str, wanted: &[String]) -> f64 {
+        let pool = self.surveys.lock().unwrap();
+        let values = self.values.lock().unwrap();
+        let deposits: Vec<&str> = pool.get(waypoint_symbol)
+            .map(|surveys| surveys.iter()
+                .flat_map(|pooled| pooled.survey.deposits.iter().map(|deposit| deposit.symbol.as_str()))
+                .collect())
+            .unwrap_or_default();
+        if deposits.is_empty() {
+            return 0.0;
+        }
+        deposits.iter().map(|symbol| Self::deposit_value(symbol, wanted, &values)).sum::<f64>() / deposits.len() as f64
+    }
+
+    pub fn has_survey_for(&self, waypoint_symbol: &str, wanted: &[String]) -> bool {
+        self.best_survey(waypoint_symbol, wanted).is_some()
+    }
+
+    /// Live surveys at a waypoint
+    pub fn surveys_at(&self, waypoint_symbol: &str) -> Vec<Survey> {
+        self.prune_expired();
+        self.surveys.lock().unwrap()
+            .get(waypoint_symbol)
+            .map(|surveys| surveys.iter().map(|pooled| pooled.survey.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Expected value per extraction: each deposit's share of the survey times what
+    /// that good is worth, scaled by the survey size
+    pub fn expected_value(survey: &Survey, wanted: &[String], values: &GoodValues) -> f64 {
+        if survey.deposits.is_empty() {
+            return 0.0;
+        }
+        let total: f64 = survey.deposits.iter()
+            .map(|deposit| Self::deposit_value(&deposit.symbol, wanted, values))
+            .sum();
+        let size_multiplier = match survey.size.as_str() {
+            "SMALL" => 1.0,
+            "MODERATE" => 2.0,
+            "LARGE" => 3.0,
+            _ => 1.0,
+        };
+        total / survey.deposits.len() as f64 * size_multiplier
+    }
+
+    /// Unwanted goods are worth nothing; an empty wanted list wants everything.
+    /// Wanted goods with no known price still count for 1 so they can be ranked.
+    fn deposit_value(symbol: &str, wanted: &[String], values: &GoodValues) -> f64 {
+        if !wanted.is_empty() && !wanted.iter().any(|good| good == symbol) {
+            return 0.0;
+        }
+        values.value_of(symbol).unwrap_or(1.0)
+    }
+
+    pub fn record_extraction(&self, signature: &str) {
+        let mut pool = self.surveys.lock().unwrap();
+        if let Some(pooled) = pool.values_mut().flatten().find(|pooled| pooled.survey.signature == signature) {
+            pooled.extractions += 1;
+        }
+    }
+
+    pub fn remove_survey(&self, signature: &str, reason: &str) -> bool {
+        let mut pool = self.surveys.lock().unwrap();
+        let before: usize = pool.values().map(|surveys| surveys.len()).sum();
+        for surveys in pool.values_mut() {
+            surveys.retain(|pooled| pooled.survey.signature != signature);
+        }
+        pool.retain(|_, surveys| !surveys.is_empty());
+        let removed = pool.values().map(|surveys| surveys.len()).sum::<usize>() < before;
+
+        if removed {
+            o_info!("🗑️ Dropped survey {} from pool: {}", signature, reason);
+            self.save_to_disk(&pool);
+        }
+        removed
+    }
+
+    /// Drop the survey if an extraction error shows it is exhausted or expired.
+    /// Returns true when the survey was dead, so the caller can retry without it.
+    pub fn handle_extraction_error(&self, signature: &str, error: &str) -> bool {
+        if !is_dead_survey_error(error) {
+            return false;
+        }
+        let reason = if error.to_lowercase().contains("exhausted") || error.contains("4224") { "exhausted" } else { "expired" };
+        self.remove_survey(signature, reason);
+        true
+    }
+
+    pub fn prune_expired(&self) -> usize {
+        let mut pool = self.surveys.lock().unwrap();
+        let before: usize = pool.values().map(|surveys| surveys.len()).sum();
+        for surveys in pool.values_mut() {
+            surveys.retain(|pooled| !pooled.is_expired());
+        }
+        pool.retain(|_, surveys| !surveys.is_empty());
+        let removed = before - pool.values().map(|surveys| surveys.len()).sum::<usize>();
+
+        if removed > 0 {
+            o_debug!("🧹 Survey pool cleanup: removed {} expired surveys", removed);
+            self.save_to_disk(&pool);
+        }
+        removed
+    }
+
+    /// Survey the ship's current waypoint and add the results to the pool
+    pub async fn replenish(&self, client: &SpaceTradersClient, surveyor: &str) -> Result<usize, Box<dyn std::error::Error>> {
+        let survey_data = client.create_survey(surveyor).await?;
+        let count = survey_data.surveys.len();
+        if let Some(waypoint) = survey_data.surveys.first().map(|survey| survey.symbol.clone()) {
+            o_info!("🔍 {} surveyed {} - {} surveys added to the fleet pool", surveyor, waypoint, count);
+        }
+        self.add_surveys(surveyor, &survey_data.surveys);
+        Ok(count)
+    }
+
+    pub fn stats(&self) -> SurveyPoolStats {
+        let pool = self.surveys.lock().unwrap();
+        SurveyPoolStats {
+            total_surveys: pool.values().map(|surveys| surveys.len()).sum(),
+            waypoints: pool.len(),
+        }
+    }
+
+    /// Write the current surveys to disk, e.g. before shutting down
+    pub fn flush(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(storage_path) = &self.storage_path else {
+            return Ok(());
+        };
+        SURVEY_POOL_FORMAT.save(storage_path, &*self.surveys.lock().unwrap())
+    }
+
+    fn load_from_disk(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(storage_path) = &self.storage_path else {
+            return Ok(());
+        };
+        let Some(surveys) = SURVEY_POOL_FORMAT.load::<HashMap<String, Vec<PooledSurvey>>>(storage_path)? else {
+            return Ok(());
+        };
+        let count: usize = surveys.values().map(|surveys| surveys.len()).sum();
+        *self.surveys.lock().unwrap() = surveys;
+
+        o_debug!("💾 Loaded {} pooled surveys from disk", count);
+        self.prune_expired();
+        Ok(())
+    }
+
+    fn save_to_disk(&self, surveys: &HashMap<String, Vec<PooledSurvey>>) {
+        let Some(storage_path) = &self.storage_path else {
+            return;
+        };
+        if let Err(e) = SURVEY_POOL_FORMAT.save(storage_path, surveys) {
+            o_debug!("⚠️ Failed to save survey pool: {}", e);
+        }
+    }
+}
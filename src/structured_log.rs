@@ -0,0 +1,203 @@
+// Structured logging - JSON lines written next to the console output, with size-based rotation
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::config::LoggingConfig;
+use crate::output_broker::OutputLevel;
+
+/// One line of the JSON log
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LogRecord {
+    pub timestamp: DateTime<Utc>,
+    pub level: &'static str,
+    pub subsystem: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship_symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract_id: Option<String>,
+    pub message: String,
+}
+
+impl LogRecord {
+    /// Build a record from a console message, picking the ship and contract out of its text
+    pub fn from_message(level: &OutputLevel, module_path: &str, message: &str) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            level: level_name(level),
+            subsystem: subsystem_of(module_path).to_string(),
+            ship_symbol: find_ship_symbol(message),
+            contract_id: find_contract_id(message),
+            message: message.to_string(),
+        }
+    }
+}
+
+pub fn level_name(level: &OutputLevel) -> &'static str {
+    match level {
+        OutputLevel::Error => "error",
+        OutputLevel::Summary => "summary",
+        OutputLevel::Info => "info",
+        OutputLevel::Debug => "debug",
+        OutputLevel::Trace => "trace",
+    }
+}
+
+pub fn parse_level(name: &str) -> Option<OutputLevel> {
+    match name.to_ascii_lowercase().as_str() {
+        "error" => Some(OutputLevel::Error),
+        "summary" => Some(OutputLevel::Summary),
+        "info" => Some(OutputLevel::Info),
+        "debug" => Some(OutputLevel::Debug),
+        "trace" => Some(OutputLevel::Trace),
+        _ => None,
+    }
+}
+
+/// `spacetraders_cc::operations::ship_actor` logs as `ship_actor`
+pub fn subsystem_of(module_path: &str) -> &str {
+    module_path.rsplit("::").next().unwrap_or(module_path)
+}
+
+fn words(message: &str) -> impl Iterator<Item = &str> {
+    message.split(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_')).filter(|word| !word.is_empty())
+}
+
+/// Ship symbols are `AGENT-1F`: one dash and a hex suffix. Waypoints have two dashes and are skipped.
+pub fn find_ship_symbol(message: &str) -> Option<String> {
+    words(message)
+        .find(|word| {
+            let mut parts = word.split('-');
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(agent), Some(number), None) => !agent.is_empty()
+                    && agent.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+                    && agent.chars().any(|c| c.is_ascii_uppercase())
+                    && !number.is_empty()
+                    && number.chars().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_lowercase()),
+                _ => false,
+            }
+        })
+        .map(str::to_string)
+}
+
+/// Contract IDs are 25 character lowercase cuids starting with `c`
+pub fn find_contract_id(message: &str) -> Option<String> {
+    words(message)
+        .find(|word| word.len() == 25 && word.starts_with('c')
+            && word.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            && word.chars().any(|c| c.is_ascii_digit()))
+        .map(str::to_string)
+}
+
+/// Appends JSON lines to a file and rotates it to `.1`, `.2`, ... once it grows past the size limit
+#[derive(Debug)]
+pub struct JsonLogger {
+    config: LoggingConfig,
+    file: Option<File>,
+    written: u64,
+}
+
+static GLOBAL_JSON_LOGGER: OnceLock<Mutex<JsonLogger>> = OnceLock::new();
+
+/// The JSON log shared by the console broker and the API client
+pub fn get_json_logger() -> &'static Mutex<JsonLogger> {
+    GLOBAL_JSON_LOGGER.get_or_init(|| Mutex::new(JsonLogger::new(LoggingConfig::default())))
+}
+
+/// Write a record through the global logger if its subsystem's level lets it through
+pub fn log_record(record: &LogRecord) {
+    if let Ok(mut logger) = get_json_logger().lock() {
+        logger.log(record);
+    }
+}
+
+impl JsonLogger {
+    pub fn new(config: LoggingConfig) -> Self {
+        Self { config, file: None, written: 0 }
+    }
+
+    /// Apply new settings; a changed path is picked up on the next write
+    pub fn configure(&mut self, config: LoggingConfig) {
+        if config.path != self.config.path {
+            self.file = None;
+        }
+        self.config = config;
+    }
+
+    /// Whether a level is logged for a subsystem, falling back to the default level
+    pub fn enabled(&self, level: &OutputLevel, subsystem: &str) -> bool {
+        if !self.config.enabled {
+            return false;
+        }
+        let threshold = self.config.levels.get(subsystem)
+            .unwrap_or(&self.config.default_level);
+        parse_level(threshold).is_some_and(|threshold| *level <= threshold)
+    }
+
+    pub fn log(&mut self, record: &LogRecord) {
+        let Some(level) = parse_level(record.level) else {
+            return;
+        };
+        if !self.enabled(&level, &record.subsystem) {
+            return;
+        }
+        let Ok(mut line) = serde_json::to_string(record) else {
+            return;
+        };
+        line.push('\n');
+
+        // Logging must never take the bot down, so write failures are dropped
+        let _ = self.write_line(&line);
+    }
+
+    fn write_line(&mut self, line: &str) -> std::io::Result<()> {
+        if self.file.is_some() && self.written + line.len() as u64 > self.config.max_file_bytes {
+            self.file = None;
+            self.rotate()?;
+        }
+        if self.file.is_none() {
+            self.open()?;
+        }
+        if let Some(file) = self.file.as_mut() {
+            file.write_all(line.as_bytes())?;
+            self.written += line.len() as u64;
+        }
+        Ok(())
+    }
+
+    fn open(&mut self) -> std::io::Result<()> {
+        if let Some(parent) = Path::new(&self.config.path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&self.config.path)?;
+        self.written = file.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+        self.file = Some(file);
+        if self.written > 0 && self.written >= self.config.max_file_bytes {
+            self.file = None;
+            self.rotate()?;
+            return self.open();
+        }
+        Ok(())
+    }
+
+    /// Shift `log.N-1` to `log.N` down to `log` to `log.1`; the oldest file beyond `max_files` is removed
+    fn rotate(&mut self) -> std::io::Result<()> {
+        let path = &self.config.path;
+        let kept = self.config.max_files.max(1);
+        let _ = fs::remove_file(format!("{}.{}", path, kept));
+        for index in (1..kept).rev() {
+            let from = format!("{}.{}", path, index);
+            if Path::new(&from).exists() {
+                fs::rename(&from, format!("{}.{}", path, index + 1))?;
+            }
+        }
+        if Path::new(path).exists() {
+            fs::rename(path, format!("{}.1", path))?;
+        }
+        self.written = 0;
+        Ok(())
+    }
+}
+
@@ -0,0 +1,35 @@
+// Ship actor queue tests - ordering and control messages, no API access needed
+use spacetraders_cc::operations::{ActionQueue, ControlEffect, ShipAction, ShipCommand};
+
+fn navigate(destination: &str) -> ShipAction {
+    ShipAction::Navigate { destination: destination.to_string() }
+}
+
+#[test]
+fn test_actions_run_in_order_and_preempt_jumps_the_queue() {
+    let mut queue = ActionQueue::new();
+    assert_eq!(queue.apply(ShipCommand::Enqueue { id: 1, action: navigate("X1-AB12-A1") }), ControlEffect::None);
+    assert_eq!(queue.apply(ShipCommand::Enqueue { id: 2, action: ShipAction::Dock }), ControlEffect::None);
+    assert_eq!(queue.ids(), vec![1, 2]);
+
+    assert_eq!(queue.apply(ShipCommand::Preempt { id: 3, action: ShipAction::Repair { shipyard: "X1-AB12-S1".to_string() } }),
+               ControlEffect::Preempt { by: 3 });
+    assert_eq!(queue.ids(), vec![3, 1, 2]);
+    assert_eq!(queue.pop_next().map(|(id, _)| id), Some(3));
+
+    // Cancelling only affects the running action
+    assert_eq!(queue.apply(ShipCommand::CancelCurrent), ControlEffect::CancelCurrent);
+    assert_eq!(queue.len(), 2);
+}
+
+#[test]
+fn test_clear_returns_dropped_actions_for_reporting() {
+    let mut queue = ActionQueue::new();
+    queue.apply(ShipCommand::Enqueue { id: 7, action: navigate("X1-AB12-A1") });
+    queue.apply(ShipCommand::Enqueue { id: 8, action: ShipAction::Orbit });
+
+    assert_eq!(queue.apply(ShipCommand::ClearQueue),
+               ControlEffect::Cleared(vec![(7, navigate("X1-AB12-A1")), (8, ShipAction::Orbit)]));
+    assert!(queue.is_empty());
+    assert_eq!(queue.pop_next(), None);
+}
@@ -0,0 +1,116 @@
+// Fixtures shared by the integration tests; each test file uses only some of them
+#![allow(dead_code)]
+use spacetraders_cc::models::{CargoItem, Market, Ship, ShipMount, ShipRequirements, Trait, Waypoint};
+
+pub const SYSTEM: &str = "X1-AB12";
+
+fn no_requirements() -> serde_json::Value {
+    serde_json::json!({"power": null, "crew": null, "slots": null})
+}
+
+/// An empty hauler in orbit at `waypoint` with a full tank and a 40 unit hold
+pub fn ship(symbol: &str, waypoint: &str) -> Ship {
+    let route_waypoint = serde_json::json!({"symbol": waypoint, "type": "ASTEROID", "systemSymbol": SYSTEM, "x": 0, "y": 0});
+    let module = serde_json::json!({"symbol": "ENGINE_ION_DRIVE_I", "capacity": null, "range": null,
+        "name": "Engine", "description": "", "requirements": no_requirements()});
+    serde_json::from_value(serde_json::json!({
+        "symbol": symbol,
+        "registration": {"name": symbol, "factionSymbol": "COSMIC", "role": "HAULER"},
+        "nav": {"systemSymbol": SYSTEM, "waypointSymbol": waypoint, "status": "IN_ORBIT", "flightMode": "CRUISE",
+            "route": {"destination": route_waypoint, "origin": route_waypoint, "departureTime": "", "arrival": ""}},
+        "crew": {"current": 0, "required": 0, "capacity": 0, "rotation": "STRICT", "morale": 100, "wages": 0},
+        "frame": {"symbol": "FRAME_LIGHT_FREIGHTER", "name": "Frame", "description": "", "condition": null, "integrity": null,
+            "moduleSlots": 0, "mountingPoints": 0, "fuelCapacity": 100, "requirements": no_requirements()},
+        "reactor": module,
+        "engine": module,
+        "cooldown": {"shipSymbol": symbol, "totalSeconds": 0.0, "remainingSeconds": 0.0, "expiration": null},
+        "modules": [],
+        "mounts": [],
+        "cargo": {"capacity": 40, "units": 0, "inventory": []},
+        "fuel": {"current": 100, "capacity": 100, "consumed": {"amount": 0, "timestamp": ""}},
+    })).expect("valid ship json")
+}
+
+/// An empty excavator with one mining laser
+pub fn miner(symbol: &str, waypoint: &str) -> Ship {
+    let mut miner = ship(symbol, waypoint);
+    miner.registration.role = "EXCAVATOR".to_string();
+    miner.frame.symbol = "FRAME_MINER".to_string();
+    miner.mounts = vec![mount("MOUNT_MINING_LASER_I")];
+    miner
+}
+
+/// A satellite probe: no fuel tank and no hold, like the real ones
+pub fn probe(symbol: &str, waypoint: &str) -> Ship {
+    let mut probe = ship(symbol, waypoint);
+    probe.registration.role = "SATELLITE".to_string();
+    probe.frame.symbol = "FRAME_PROBE".to_string();
+    probe.cargo.capacity = 0;
+    probe.fuel.current = 0;
+    probe.fuel.capacity = 0;
+    probe
+}
+
+pub fn requirements(power: i32, crew: i32, slots: i32) -> ShipRequirements {
+    ShipRequirements { power: Some(power), crew: Some(crew), slots: Some(slots) }
+}
+
+pub fn mount(symbol: &str) -> ShipMount {
+    ShipMount {
+        symbol: symbol.to_string(),
+        name: symbol.to_string(),
+        description: None,
+        strength: None,
+        deposits: None,
+        requirements: ShipRequirements { power: None, crew: None, slots: None },
+    }
+}
+
+/// Set a ship's hold size and fill it with `held` goods
+pub fn with_cargo(mut ship: Ship, capacity: i32, held: &[(&str, i32)]) -> Ship {
+    ship.cargo.capacity = capacity;
+    ship.cargo.inventory = held.iter().map(|(good, units)| CargoItem {
+        symbol: good.to_string(),
+        name: good.to_string(),
+        description: String::new(),
+        units: *units,
+    }).collect();
+    ship.cargo.units = held.iter().map(|(_, units)| units).sum();
+    ship
+}
+
+pub fn waypoint(symbol: &str, waypoint_type: &str, x: i32, y: i32) -> Waypoint {
+    Waypoint {
+        symbol: symbol.to_string(),
+        waypoint_type: waypoint_type.to_string(),
+        system_symbol: SYSTEM.to_string(),
+        x,
+        y,
+        orbitals: Vec::new(),
+        traits: Vec::new(),
+        chart: None,
+        faction: None,
+    }
+}
+
+pub fn with_traits(mut waypoint: Waypoint, traits: &[&str]) -> Waypoint {
+    waypoint.traits = traits.iter().map(|symbol| Trait {
+        symbol: symbol.to_string(),
+        name: symbol.to_string(),
+        description: String::new(),
+    }).collect();
+    waypoint
+}
+
+/// A market listing `(good, trade volume, purchase price, sell price)` for each good
+pub fn market(symbol: &str, goods: &[(&str, i32, i32, i32)]) -> Market {
+    serde_json::from_value(serde_json::json!({
+        "symbol": symbol,
+        "exports": [],
+        "imports": [],
+        "exchange": [],
+        "transactions": null,
+        "tradeGoods": goods.iter().map(|(good, volume, buy, sell)| serde_json::json!({"symbol": good, "tradeVolume": volume,
+            "supply": "MODERATE", "activity": null, "purchasePrice": buy, "sellPrice": sell})).collect::<Vec<_>>(),
+    })).expect("valid market json")
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::{miner, ship, with_cargo};
+use spacetraders_cc::models::Ship;
+use spacetraders_cc::operations::ContractOperations;
+use spacetraders_cc::{SpaceTradersClient, SpaceTradersConfig};
+
+#[tokio::test]
+async fn test_negotiation_courier_prefers_probes_then_empty_small_haulers() {
+    let client = SpaceTradersClient::new("token".to_string());
+    let config = SpaceTradersConfig::default();
+    let contracts = ContractOperations::new(&client, &config);
+
+    let mut probe = ship("PROBE-1", "X1-AB12-A1");
+    probe.registration.role = "SATELLITE".to_string();
+    probe.cargo.capacity = 0;
+    let mut small_hauler = ship("HAULER-1", "X1-AB12-A1");
+    small_hauler.cargo.capacity = 20;
+    let mut fleet = vec![
+        miner("MINER-1", "X1-AB12-A1"),
+        with_cargo(ship("HAULER-2", "X1-AB12-A1"), 20, &[("IRON_ORE", 5)]),
+        ship("HAULER-3", "X1-AB12-A1"),
+        small_hauler,
+        probe,
+    ];
+    let headquarters = "X1-AB12-H1";
+    let pick = |fleet: &[Ship]| contracts.select_negotiation_ship(fleet, headquarters, &[]).map(|ship| ship.symbol.clone());
+
+    assert_eq!(pick(&fleet).as_deref(), Some("PROBE-1"));
+    // Ships on a mission or working a station are not idle
+    let busy = ["PROBE-1".to_string(), "HAULER-1".to_string()];
+    assert_eq!(contracts.select_negotiation_ship(&fleet, headquarters, &busy).map(|ship| ship.symbol.as_str()), Some("HAULER-3"));
+    fleet[4].nav.status = "IN_TRANSIT".to_string();
+    assert_eq!(pick(&fleet).as_deref(), Some("HAULER-1"));
+    fleet[3].nav.system_symbol = "X1-CD34".to_string();
+    assert_eq!(pick(&fleet).as_deref(), Some("HAULER-3"));
+    assert_eq!(pick(&fleet[..2]).as_deref(), Some("HAULER-2"));
+    assert_eq!(pick(&fleet[..1]).as_deref(), Some("MINER-1"));
+    assert!(contracts.select_negotiation_ship(&fleet, "BAD", &[]).is_none());
+}
@@ -0,0 +1,54 @@
+// Control API tests - command handling and the HTTP round trip, no API access needed
+use spacetraders_cc::control::{send_command, Control, ControlCommand, ForcedRole};
+
+#[tokio::test]
+async fn test_pause_resume_and_roles() {
+    let control = Control::default();
+    assert!(control.apply(ControlCommand::Pause { ship: Some("MINER-1".to_string()) }).ok);
+    assert!(control.is_paused("MINER-1"));
+    assert!(!control.is_paused("MINER-2"));
+
+    control.apply(ControlCommand::Pause { ship: None });
+    assert!(control.is_paused("MINER-2"));
+    let response = control.apply(ControlCommand::Resume { ship: Some("MINER-1".to_string()) });
+    assert!(response.ok && response.message.contains("still paused"));
+    control.apply(ControlCommand::Resume { ship: None });
+    assert!(!control.is_paused("MINER-1") && !control.is_paused("MINER-2"));
+    assert!(!control.apply(ControlCommand::Resume { ship: Some("MINER-1".to_string()) }).ok);
+
+    assert_eq!(ForcedRole::parse("hauler"), Some(ForcedRole::Hauler));
+    assert_eq!(ForcedRole::parse("pilot"), None);
+    control.apply(ControlCommand::Role { ship: "MINER-1".to_string(), role: Some(ForcedRole::Hauler) });
+    assert_eq!(control.forced_role("MINER-1"), Some(ForcedRole::Hauler));
+    control.apply(ControlCommand::Role { ship: "MINER-1".to_string(), role: None });
+    assert_eq!(control.forced_role("MINER-1"), None);
+}
+
+#[tokio::test]
+async fn test_goals_are_checked_and_queued_in_order() {
+    let control = Control::default();
+    assert!(!control.apply(ControlCommand::Goal { goal: "make me rich".to_string() }).ok);
+    assert!(control.apply(ControlCommand::Goal { goal: "mine iron ore".to_string() }).ok);
+    assert!(control.apply(ControlCommand::Goal { goal: "explore".to_string() }).ok);
+    assert_eq!(control.take_goals(), vec!["mine iron ore".to_string(), "explore".to_string()]);
+    assert!(control.take_goals().is_empty());
+}
+
+#[tokio::test]
+async fn test_commands_round_trip_over_http() {
+    let control = Control::default();
+    let addr = control.serve("127.0.0.1:0".parse().unwrap()).await.expect("bind").to_string();
+
+    let response = send_command(&addr, &ControlCommand::Pause { ship: Some("MINER-1".to_string()) }).await.expect("pause");
+    assert!(response.ok);
+    assert!(control.is_paused("MINER-1"));
+
+    let response = send_command(&addr, &ControlCommand::Status).await.expect("status");
+    let status = response.status.expect("status body");
+    assert_eq!(status["fleet_paused"], false);
+    assert_eq!(status["shutdown_requested"], false);
+
+    assert!(!control.shutdown_requested());
+    send_command(&addr, &ControlCommand::Shutdown).await.expect("shutdown");
+    tokio::time::timeout(std::time::Duration::from_secs(1), control.wait_for_shutdown()).await.expect("shutdown signalled");
+}
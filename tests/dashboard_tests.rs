@@ -0,0 +1,50 @@
+// Dashboard tests - bars, the credit graph and frame layout, no API access needed
+use spacetraders_cc::dashboard::{action_summary, describe_event, progress_bar, sparkline, Dashboard};
+use spacetraders_cc::events::GameEvent;
+use spacetraders_cc::metrics::MetricsSnapshot;
+use spacetraders_cc::operations::ShipAction;
+
+#[test]
+fn test_progress_bar_and_sparkline() {
+    assert_eq!(progress_bar(50.0, 4), "[██░░]");
+    assert_eq!(progress_bar(150.0, 4), "[████]");
+    assert_eq!(progress_bar(0.0, 2), "[░░]");
+
+    assert_eq!(sparkline(&[], 10), "");
+    assert_eq!(sparkline(&[100, 200, 300], 10), "▁▅█");
+    // Only the most recent values fit
+    assert_eq!(sparkline(&[0, 100, 200, 300], 2), "▁█");
+    assert_eq!(sparkline(&[5, 5], 10), "▁▁");
+}
+
+#[test]
+fn test_action_and_event_lines() {
+    assert_eq!(action_summary(&ShipAction::Navigate { destination: "X1-AB12-B2".to_string() }), "fly to X1-AB12-B2");
+    assert_eq!(action_summary(&ShipAction::Dock), "dock");
+    assert_eq!(describe_event(&GameEvent::Sale { ship_symbol: "MINER-1".to_string(), good: "IRON_ORE".to_string(),
+        units: 7, total_price: 280 }), "💰 MINER-1 sold 7 IRON_ORE for 280");
+}
+
+#[test]
+fn test_render_shows_contracts_api_and_scrolling_events() {
+    let dashboard = Dashboard::default();
+    for credits in [1000, 1000, 1500] {
+        dashboard.sample_credits(credits);
+    }
+    for index in 0..20 {
+        dashboard.push_event(format!("event {}", index));
+    }
+    let frame = dashboard.render(&MetricsSnapshot {
+        credits: Some(1500),
+        api_queue_depth: 3,
+        api_requests: 42,
+        rate_limited: 1,
+        contract_progress: vec![("CONTRACT-1".to_string(), "IRON_ORE".to_string(), 30, 60)],
+    });
+
+    assert!(frame.contains("💰 Credits: 1500  ▁█"));
+    assert!(frame.contains("🌐 API: queue 3 | requests 42 | rate limited 1"));
+    assert!(frame.contains(&format!("{} 30/60", progress_bar(50.0, 20))));
+    assert!(frame.contains("event 19"));
+    assert!(!frame.contains("event 7\n"));
+}
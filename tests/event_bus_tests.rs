@@ -0,0 +1,63 @@
+// Event bus tests - publishing, subscribing and the income ledger as a subscriber, no API access needed
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use spacetraders_cc::events::{EventBus, EventSubscriber, GameEvent, PublishedEvent};
+use spacetraders_cc::storage::IncomeLedger;
+
+struct Collector {
+    seen: Arc<Mutex<Vec<GameEvent>>>,
+}
+
+impl EventSubscriber for Collector {
+    fn name(&self) -> &str {
+        "collector"
+    }
+
+    fn handle(&mut self, event: &PublishedEvent) {
+        self.seen.lock().unwrap().push(event.event.clone());
+    }
+}
+
+fn sale(units: i32, total_price: i64) -> GameEvent {
+    GameEvent::Sale { ship_symbol: "HAULER-1".to_string(), good: "IRON_ORE".to_string(), units, total_price }
+}
+
+#[tokio::test]
+async fn test_every_subscriber_sees_each_event() {
+    let bus = EventBus::new(16);
+    assert_eq!(bus.publish(sale(1, 10)), 0);
+
+    let mut receiver = bus.subscribe();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    bus.register(Collector { seen: seen.clone() });
+    assert_eq!(bus.subscriber_count(), 2);
+
+    let arrived = GameEvent::ShipArrived { ship_symbol: "HAULER-1".to_string(), waypoint_symbol: "X1-AB12-B2".to_string() };
+    assert_eq!(bus.publish(arrived.clone()), 2);
+    assert_eq!(receiver.recv().await.expect("event").event, arrived);
+    assert_eq!(arrived.ship_symbol(), Some("HAULER-1"));
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(*seen.lock().unwrap(), vec![arrived]);
+}
+
+#[tokio::test]
+async fn test_ledger_records_published_sales() {
+    let bus = EventBus::new(16);
+    let ledger = IncomeLedger::in_memory();
+    bus.register(ledger.clone());
+
+    bus.publish(sale(20, 400));
+    bus.publish(GameEvent::ContractAccepted { contract_id: "CONTRACT-1".to_string() });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let records = ledger.records();
+    assert_eq!(records.len(), 1);
+    assert_eq!((records[0].units, records[0].credits), (20, 400));
+}
+
+#[test]
+fn test_events_serialize_with_type_tag() {
+    let json = serde_json::to_value(GameEvent::CooldownEnded { ship_symbol: "MINER-1".to_string() }).expect("json");
+    assert_eq!(json, serde_json::json!({"type": "CooldownEnded", "ship_symbol": "MINER-1"}));
+}
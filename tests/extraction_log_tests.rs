@@ -0,0 +1,60 @@
+// Extraction yield analytics tests - no API access needed
+use spacetraders_cc::storage::{ExtractionLog, ExtractionRecord};
+
+fn record(waypoint: &str, mounts: &[&str], units: i32, cooldown_seconds: f64) -> ExtractionRecord {
+    ExtractionRecord {
+        timestamp: chrono::Utc::now(),
+        waypoint_symbol: waypoint.to_string(),
+        ship_symbol: "AGENT-1".to_string(),
+        mounts: mounts.iter().map(|mount| mount.to_string()).collect(),
+        survey_signature: None,
+        yield_symbol: "IRON_ORE".to_string(),
+        units,
+        cooldown_seconds,
+    }
+}
+
+#[tokio::test]
+async fn test_report_rolls_up_yield_per_cooldown_second() {
+    let log = ExtractionLog::in_memory();
+    log.record(record("X1-AB12-A1", &["MOUNT_MINING_LASER_I", "MOUNT_SURVEYOR_I"], 10, 70.0));
+    log.record(record("X1-AB12-A1", &["MOUNT_SURVEYOR_I", "MOUNT_MINING_LASER_I"], 4, 70.0));
+    log.record(record("X1-AB12-A2", &["MOUNT_MINING_LASER_II"], 20, 80.0));
+
+    let report = log.report();
+    assert_eq!(report.by_waypoint["X1-AB12-A1"].extractions, 2);
+    assert!((report.by_waypoint["X1-AB12-A1"].units_per_cooldown_second() - 0.1).abs() < 1e-9);
+    // Mount order does not split a loadout
+    assert_eq!(report.by_loadout["MOUNT_MINING_LASER_I+MOUNT_SURVEYOR_I"].total_units, 14);
+    assert_eq!(report.ranked_waypoints()[0].0, "X1-AB12-A2");
+}
+
+#[tokio::test]
+async fn test_fields_need_several_extractions_to_be_proven() {
+    let log = ExtractionLog::in_memory();
+    log.record(record("X1-AB12-A1", &[], 10, 50.0));
+    log.record(record("X1-AB12-A2", &[], 30, 50.0));
+    for _ in 0..2 {
+        log.record(record("X1-AB12-A1", &[], 10, 50.0));
+    }
+
+    assert_eq!(log.proven_yield_rate("X1-AB12-A1"), Some(0.2));
+    assert_eq!(log.proven_yield_rate("X1-AB12-A2"), None);
+    assert_eq!(log.best_proven_yield_rate(), Some(0.2));
+}
+
+#[tokio::test]
+async fn test_records_reach_disk_on_flush() {
+    let path = std::env::temp_dir().join(format!("extraction_log_{}.json", std::process::id()));
+    let path = path.to_str().unwrap();
+    let _ = std::fs::remove_file(path);
+
+    let log = ExtractionLog::new(path);
+    log.record(record("X1-AB12-A1", &[], 10, 50.0));
+    assert!(!std::path::Path::new(path).exists());
+
+    log.flush().unwrap();
+    assert_eq!(ExtractionLog::new(path).records().len(), 1);
+
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use chrono::Utc;
+use common::{miner, mount, ship};
+use spacetraders_cc::models::{Ship, Shipyard};
+use spacetraders_cc::operations::{detect_bottleneck, purchase_budget, Bottleneck, FleetPlanner, ShipClass};
+use spacetraders_cc::storage::{ExtractionLog, ExtractionRecord, IncomeLedger};
+
+fn loaded_miner(symbol: &str, cargo_units: i32) -> Ship {
+    let mut miner = miner(symbol, "X1-AB12-A1");
+    miner.cargo.capacity = 30;
+    miner.cargo.units = cargo_units;
+    miner
+}
+
+fn hauler(symbol: &str) -> Ship {
+    let mut hauler = ship(symbol, "X1-AB12-A1");
+    hauler.cargo.capacity = 80;
+    hauler
+}
+
+fn shipyard(symbol: &str, listings: &[(&str, &str, &[&str], i32)]) -> Shipyard {
+    let requirements = serde_json::json!({"power": null, "crew": null, "slots": null});
+    let module = serde_json::json!({"symbol": "ENGINE_ION_DRIVE_I", "capacity": null, "range": null,
+        "name": "Engine", "description": "", "requirements": requirements});
+    let ships: Vec<serde_json::Value> = listings.iter().map(|(ship_type, frame, mounts, price)| serde_json::json!({
+        "type": ship_type, "name": ship_type, "description": "", "purchasePrice": price,
+        "frame": {"symbol": frame, "name": "Frame", "description": "", "condition": null, "integrity": null,
+            "moduleSlots": 0, "mountingPoints": 0, "fuelCapacity": 100, "requirements": requirements},
+        "reactor": module, "engine": module, "modules": [],
+        "mounts": mounts.iter().map(|symbol| mount(symbol)).collect::<Vec<_>>(),
+        "crew": {"required": 0, "capacity": 0},
+    })).collect();
+    serde_json::from_value(serde_json::json!({
+        "symbol": symbol,
+        "shipTypes": listings.iter().map(|(ship_type, ..)| serde_json::json!({"type": ship_type})).collect::<Vec<_>>(),
+        "transactions": null,
+        "ships": ships,
+        "modificationsFee": 0,
+    })).expect("valid shipyard json")
+}
+
+#[test]
+fn test_bottleneck_follows_market_coverage_then_full_holds() {
+    let fleet = vec![
+        loaded_miner("MINER-1", 29),
+        loaded_miner("MINER-2", 5),
+        hauler("HAULER-1"),
+    ];
+    assert_eq!(ShipClass::of_ship(&fleet[0]), ShipClass::Miner);
+    assert_eq!(ShipClass::of_ship(&fleet[2]), ShipClass::Hauler);
+
+    assert_eq!(detect_bottleneck(&fleet, 1, 6), Bottleneck::MarketCoverage);
+    assert_eq!(detect_bottleneck(&fleet, 4, 6), Bottleneck::Hauling);
+    assert_eq!(detect_bottleneck(&fleet[1..], 4, 6), Bottleneck::Extraction);
+}
+
+#[tokio::test]
+async fn test_recommends_quickest_payback_within_budget() {
+    let fleet = vec![
+        loaded_miner("MINER-1", 5),
+        hauler("HAULER-1"),
+    ];
+    let ledger = IncomeLedger::in_memory();
+    // A single sale is spread over the minimum 15 minute window: 2,000 credits/hour
+    ledger.record_sale("HAULER-1", "IRON_ORE", 50, 500);
+    let log = ExtractionLog::in_memory();
+    log.record(ExtractionRecord {
+        timestamp: Utc::now(),
+        waypoint_symbol: "X1-AB12-A1".to_string(),
+        ship_symbol: "MINER-1".to_string(),
+        mounts: vec!["MOUNT_MINING_LASER_I".to_string()],
+        survey_signature: None,
+        yield_symbol: "IRON_ORE".to_string(),
+        units: 10,
+        cooldown_seconds: 60.0,
+    });
+
+    // 10 ore a minute at 5 credits: 3,000 credits/hour, boosted while extraction is the bottleneck
+    let planner = FleetPlanner::measure(&fleet, &ledger, &log, |_| Some(5.0), Bottleneck::Extraction, 5);
+    assert_eq!(planner.expected_income(ShipClass::Miner), Some(4500.0));
+    assert_eq!(planner.expected_income(ShipClass::Hauler), Some(2000.0));
+    assert_eq!(planner.expected_income(ShipClass::Probe), None);
+
+    let yards = vec![
+        shipyard("X1-AB12-S1", &[("SHIP_MINING_DRONE", "FRAME_DRONE", &["MOUNT_MINING_LASER_I"], 45000),
+            ("SHIP_PROBE", "FRAME_PROBE", &[], 20000)]),
+        shipyard("X1-AB12-S2", &[("SHIP_LIGHT_HAULER", "FRAME_LIGHT_FREIGHTER", &[], 30000)]),
+    ];
+    let best = planner.recommend(&yards, purchase_budget(80000, 20000)).expect("a ship to buy");
+    assert_eq!((best.shipyard.as_str(), best.ship_type.as_str(), best.class), ("X1-AB12-S1", "SHIP_MINING_DRONE", ShipClass::Miner));
+    assert_eq!(best.payback_hours, 10.0);
+
+    // The reserve keeps the drone out of reach, so the hauler is the best affordable ship
+    let best = planner.recommend(&yards, purchase_budget(60000, 20000)).expect("a ship to buy");
+    assert_eq!(best.ship_type, "SHIP_LIGHT_HAULER");
+    assert!(planner.recommend(&yards, purchase_budget(40000, 20000)).is_none());
+
+    // At the extractor cap only non-extracting ships are considered
+    let capped = FleetPlanner::measure(&fleet, &ledger, &log, |_| Some(5.0), Bottleneck::Extraction, 1);
+    assert_eq!(capped.recommend(&yards, 100000).map(|option| option.class), Some(ShipClass::Hauler));
+}
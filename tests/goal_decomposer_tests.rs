@@ -0,0 +1,130 @@
+mod common;
+
+use common::{probe, ship, with_cargo};
+use spacetraders_cc::goals::{ContextEngine, ExplorationGoal, FleetStatus, Goal, GoalContext, GoalDecomposer, GoalParameters,
+                             GoalPriority, GoalStatus, ResourceAllocator, ResourcePurchaseGoal, ShipSelector};
+use spacetraders_cc::models::{Agent, Ship};
+
+fn context(ships: Vec<Ship>) -> GoalContext {
+    GoalContext {
+        fleet_status: FleetStatus {
+            available_ships: ships.iter().map(|ship| ship.symbol.clone()).collect(),
+            busy_ships: Default::default(),
+            mining_ships: Vec::new(),
+            hauler_ships: ships.iter().map(|ship| ship.symbol.clone()).collect(),
+            probe_ships: Vec::new(),
+        },
+        ships,
+        agent: Agent {
+            account_id: "account".to_string(),
+            symbol: "AGENT".to_string(),
+            headquarters: "X1-AB12-A1".to_string(),
+            credits: 100_000,
+            starting_faction: "COSMIC".to_string(),
+            ship_count: 2,
+        },
+        contracts: Vec::new(),
+        known_waypoints: Default::default(),
+        known_markets: Default::default(),
+        available_credits: 100_000,
+        credit_reserve: 0,
+        fuel_safety_margin: 0,
+        progress: Default::default(),
+    }
+}
+
+fn purchase(quantity: i32) -> Box<dyn Goal> {
+    Box::new(ResourcePurchaseGoal {
+        id: "buy_iron".to_string(),
+        resource_type: "IRON".to_string(),
+        target_quantity: quantity,
+        priority: GoalPriority::Override,
+        status: GoalStatus::Pending,
+        params: GoalParameters {
+            quantity: Some(quantity),
+            resource: Some("IRON".to_string()),
+            ships: ShipSelector::Count(2),
+            ..Default::default()
+        },
+    })
+}
+
+fn haulers() -> Vec<Ship> {
+    vec![ship("HAULER-1", "X1-AB12-A1"), with_cargo(ship("HAULER-2", "X1-AB12-A1"), 40, &[("FUEL", 10)])]
+}
+
+#[tokio::test]
+async fn test_purchase_splits_by_free_space() {
+    let sub_goals = GoalDecomposer::new().decompose(purchase(70), &context(haulers())).await;
+
+    let parts: Vec<_> = sub_goals.iter().map(|goal| (goal.id(), goal.parameters().quantity, goal.parameters().ships)).collect();
+    assert_eq!(parts, vec![
+        ("buy_iron_part1".to_string(), Some(40), ShipSelector::Symbols(vec!["HAULER-1".to_string()])),
+        ("buy_iron_part2".to_string(), Some(30), ShipSelector::Symbols(vec!["HAULER-2".to_string()])),
+    ]);
+}
+
+#[tokio::test]
+async fn test_purchase_queues_remainder_after_parts() {
+    let sub_goals = GoalDecomposer::new().decompose(purchase(100), &context(haulers())).await;
+
+    assert_eq!(sub_goals.len(), 3);
+    let remainder = &sub_goals[2];
+    assert_eq!(remainder.id(), "buy_iron_remainder");
+    assert_eq!(remainder.parameters().quantity, Some(30));
+    assert_eq!(remainder.parameters().ships, ShipSelector::Count(2));
+    assert_eq!(remainder.dependencies(), vec!["buy_iron_part1", "buy_iron_part2"]);
+}
+
+#[tokio::test]
+async fn test_purchase_with_one_buyer_is_not_split() {
+    let sub_goals = GoalDecomposer::new().decompose(purchase(100), &context(vec![ship("HAULER-1", "X1-AB12-A1")])).await;
+
+    assert_eq!(sub_goals.len(), 1);
+    assert_eq!(sub_goals[0].id(), "buy_iron");
+}
+
+/// A context whose fleet status is classified the way the goal system does it
+fn analyzed_context(ships: Vec<Ship>) -> GoalContext {
+    GoalContext { fleet_status: ContextEngine::new().analyze_fleet_status(&ships), ..context(ships) }
+}
+
+#[tokio::test]
+async fn test_probes_scout_before_purchases_and_take_exploration() {
+    let context = analyzed_context(vec![ship("HAULER-1", "X1-AB12-A1"), probe("PROBE-1", "X1-AB12-B2")]);
+    assert_eq!(context.fleet_status.probe_ships, vec!["PROBE-1"]);
+    assert!(!context.fleet_status.busy_ships.contains_key("PROBE-1"), "an empty tank is not low fuel for a probe");
+
+    let sub_goals = GoalDecomposer::new().decompose(purchase(20), &context).await;
+    assert_eq!(sub_goals[0].id(), "buy_iron_scout_markets");
+    assert_eq!(sub_goals[0].parameters().ships, ShipSelector::Symbols(vec!["PROBE-1".to_string()]));
+    assert_eq!(sub_goals[1].dependencies(), vec!["buy_iron_scout_markets"]);
+
+    let explore = ExplorationGoal {
+        id: "explore".to_string(),
+        target_type: "SHIPYARDS".to_string(),
+        priority: GoalPriority::Override,
+        status: GoalStatus::Pending,
+        params: GoalParameters::default(),
+    };
+    assert_eq!(ResourceAllocator::new().allocate_ships(&explore, &context), Ok(vec!["PROBE-1".to_string()]));
+}
+
+#[tokio::test]
+async fn test_paused_ships_are_not_allocated_to_goals() {
+    use spacetraders_cc::control::{get_control, ControlCommand};
+
+    let context = analyzed_context(vec![probe("PAUSED-PROBE-1", "X1-AB12-B2"), probe("PROBE-2", "X1-AB12-B2")]);
+    get_control().apply(ControlCommand::Pause { ship: Some("PAUSED-PROBE-1".to_string()) });
+
+    let explore = |ships| ExplorationGoal {
+        id: "explore".to_string(),
+        target_type: "SHIPYARDS".to_string(),
+        priority: GoalPriority::Override,
+        status: GoalStatus::Pending,
+        params: GoalParameters { ships, ..Default::default() },
+    };
+    assert_eq!(ResourceAllocator::new().allocate_ships(&explore(ShipSelector::Any), &context), Ok(vec!["PROBE-2".to_string()]));
+    let named = ShipSelector::Symbols(vec!["PAUSED-PROBE-1".to_string()]);
+    assert!(ResourceAllocator::new().allocate_ships(&explore(named), &context).is_err());
+}
@@ -0,0 +1,77 @@
+// Goal DSL parser tests - no API access needed
+use spacetraders_cc::goals::goal_dsl::{parse_goal_command, GoalAction};
+use spacetraders_cc::goals::{ClauseLink, GoalLocation, MarketTarget, ShipSelector};
+
+#[test]
+fn test_full_mining_command() {
+    let command = parse_goal_command("mine 200 iron ore at X1-AB12-C3 with 3 ships by 2030-01-01T18:00:00Z then sell at best market")
+        .expect("command should parse");
+
+    assert_eq!(command.clauses.len(), 2);
+
+    let mine = &command.clauses[0];
+    assert_eq!(mine.action, GoalAction::Mine);
+    assert_eq!(mine.params.quantity, Some(200));
+    assert_eq!(mine.params.resource.as_deref(), Some("IRON_ORE"));
+    assert_eq!(mine.params.location, Some(GoalLocation::Waypoint("X1-AB12-C3".to_string())));
+    assert_eq!(mine.params.ships, ShipSelector::Count(3));
+    assert!(mine.params.deadline.is_some());
+
+    let sell = &command.clauses[1];
+    assert_eq!(sell.action, GoalAction::Sell);
+    assert_eq!(sell.link, ClauseLink::Then);
+    assert_eq!(sell.params.market, Some(MarketTarget::Best));
+    // Sell inherits what the previous clause produced
+    assert_eq!(sell.params.resource.as_deref(), Some("IRON_ORE"));
+    assert_eq!(sell.params.quantity, Some(200));
+}
+
+#[test]
+fn test_and_chaining_and_ship_symbols() {
+    let command = parse_goal_command("mine copper with AGENT-1, AGENT-2 and explore shipyards")
+        .expect("command should parse");
+
+    assert_eq!(command.clauses.len(), 2);
+    assert_eq!(command.clauses[0].params.resource.as_deref(), Some("COPPER_ORE"));
+    assert_eq!(command.clauses[0].params.ships,
+               ShipSelector::Symbols(vec!["AGENT-1".to_string(), "AGENT-2".to_string()]));
+    assert_eq!(command.clauses[1].action, GoalAction::Explore);
+    assert_eq!(command.clauses[1].link, ClauseLink::And);
+    assert_eq!(command.clauses[1].params.target.as_deref(), Some("SHIPYARDS"));
+}
+
+#[test]
+fn test_other_actions() {
+    let command = parse_goal_command("refine 50 iron then transfer iron to AGENT-3 then designate AGENT-4 as refinery")
+        .expect("command should parse");
+
+    assert_eq!(command.clauses[0].params.resource.as_deref(), Some("IRON"));
+    assert_eq!(command.clauses[1].params.target_ship.as_deref(), Some("AGENT-3"));
+    assert_eq!(command.clauses[2].params.target_ship.as_deref(), Some("AGENT-4"));
+    assert_eq!(command.clauses[2].params.role.as_deref(), Some("refiner"));
+
+    let buy = parse_goal_command("buy a mining ship in X1-AB12").expect("command should parse");
+    assert_eq!(buy.clauses[0].params.ship_type.as_deref(), Some("MINING"));
+    assert_eq!(buy.clauses[0].params.location, Some(GoalLocation::System("X1-AB12".to_string())));
+}
+
+#[test]
+fn test_errors_point_at_offending_token() {
+    let error = parse_goal_command("mine 200 iron at nowhere").unwrap_err();
+    assert_eq!(error.token.as_deref(), Some("nowhere"));
+    assert_eq!(error.column, 17);
+    assert!(error.to_string().contains("^^^^^^^"));
+
+    let error = parse_goal_command("fly to the moon").unwrap_err();
+    assert_eq!(error.token.as_deref(), Some("fly"));
+    assert!(error.message.contains("Unknown action"));
+
+    let error = parse_goal_command("mine iron with 0 ships").unwrap_err();
+    assert_eq!(error.token.as_deref(), Some("0"));
+
+    let error = parse_goal_command("mine iron then").unwrap_err();
+    assert!(error.token.is_none());
+
+    let error = parse_goal_command("mine iron by tomorrow").unwrap_err();
+    assert_eq!(error.token.as_deref(), Some("tomorrow"));
+}
@@ -0,0 +1,112 @@
+// Goal manager dependency tests - no API access needed
+use spacetraders_cc::client::PriorityApiClient;
+use spacetraders_cc::goals::{DebugGoal, FleetStatus, Goal, GoalContext, GoalInterpreter, GoalManager, GoalParameters,
+                             GoalPriority, GoalStatus};
+use spacetraders_cc::models::Agent;
+use spacetraders_cc::SpaceTradersClient;
+
+fn debug_goal(id: &str, depends_on: &[&str]) -> Box<dyn Goal> {
+    Box::new(DebugGoal {
+        id: id.to_string(),
+        target: "system".to_string(),
+        priority: GoalPriority::Override,
+        status: GoalStatus::Pending,
+        params: GoalParameters {
+            depends_on: depends_on.iter().map(|id| id.to_string()).collect(),
+            ..Default::default()
+        },
+    })
+}
+
+fn empty_context() -> GoalContext {
+    GoalContext {
+        ships: Vec::new(),
+        agent: Agent {
+            account_id: "account".to_string(),
+            symbol: "AGENT".to_string(),
+            headquarters: "X1-TEST-A1".to_string(),
+            credits: 0,
+            starting_faction: "COSMIC".to_string(),
+            ship_count: 0,
+        },
+        contracts: Vec::new(),
+        known_waypoints: Default::default(),
+        known_markets: Default::default(),
+        available_credits: 0,
+        credit_reserve: 0,
+        fuel_safety_margin: 0,
+        fleet_status: FleetStatus {
+            available_ships: Vec::new(),
+            busy_ships: Default::default(),
+            mining_ships: Vec::new(),
+            hauler_ships: Vec::new(),
+            probe_ships: Vec::new(),
+        },
+        progress: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn test_dependency_chain_runs_in_order() {
+    let client = PriorityApiClient::new(SpaceTradersClient::new("token".to_string()));
+    let context = empty_context();
+    let mut manager = GoalManager::new();
+    manager.add_goal(debug_goal("mine", &[]));
+    manager.add_goal(debug_goal("refine", &["mine"]));
+    manager.add_goal(debug_goal("sell", &["refine"]));
+
+    let mut finished = 0;
+    while manager.has_pending_goals() && finished < 3 {
+        finished += manager.execute_goals(&client, &context).await.expect("goals should run").len();
+    }
+
+    assert!(manager.get_failed_goals().is_empty(), "{:?}", manager.get_failed_goals());
+    assert_eq!(finished, 3);
+    assert!(!manager.has_pending_goals());
+}
+
+#[tokio::test]
+async fn test_failure_propagates_to_dependents() {
+    let mut manager = GoalManager::new();
+    manager.add_goal(debug_goal("mine", &[]));
+    manager.add_goal(debug_goal("refine", &["mine"]));
+    manager.add_goal(debug_goal("sell", &["refine"]));
+    manager.add_goal(debug_goal("explore", &[]));
+
+    manager.cancel_goal("mine").await.expect("queued goal should cancel");
+
+    let failed = manager.get_failed_goals();
+    assert_eq!(failed.len(), 3);
+    assert!(failed.iter().any(|(id, reason)| id == "refine" && reason.contains("Dependency mine failed")));
+    assert!(failed.iter().any(|(id, reason)| id == "sell" && reason.contains("Dependency refine failed")));
+
+    // Independent goals stay queued
+    assert_eq!(manager.get_status().queued_count, 1);
+}
+
+#[test]
+fn test_add_dependency_is_idempotent() {
+    let mut goal = debug_goal("sell", &["mine"]);
+    goal.add_dependency("mine");
+    goal.add_dependency("refine");
+    assert_eq!(goal.dependencies(), vec!["mine".to_string(), "refine".to_string()]);
+}
+
+#[tokio::test]
+async fn test_unfinished_goals_are_restored_from_disk() {
+    let path = std::env::temp_dir().join(format!("goal_queue_test_{}.json", std::process::id()));
+    let path = path.to_str().unwrap();
+
+    let mut manager = GoalManager::with_store(path);
+    manager.add_goal(debug_goal("mine", &[]));
+    manager.add_goal(debug_goal("sell", &["mine"]));
+    assert!(GoalManager::has_stored_goals(path));
+
+    let mut restarted = GoalManager::with_store(path);
+    assert_eq!(restarted.restore_goals(&GoalInterpreter::new()), 2);
+    assert_eq!(restarted.get_status().queued_count, 2);
+
+    restarted.clear_stored_goals();
+    assert!(!GoalManager::has_stored_goals(path));
+    let _ = std::fs::remove_file(path);
+}
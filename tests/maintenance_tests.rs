@@ -0,0 +1,78 @@
+mod common;
+
+use common::{miner, waypoint};
+use spacetraders_cc::models::{Ship, Waypoint};
+use spacetraders_cc::operations::{MaintenanceManager, ScrapAdvice};
+use spacetraders_cc::storage::{ConditionHistory, ShipComponent};
+
+/// A miner flying from A1 to B2 with its engine and reactor at 95%
+fn ship(frame_condition: f64, origin: (i32, i32), destination: (i32, i32)) -> Ship {
+    let mut ship = miner("MINER-1", "X1-AB12-B2");
+    ship.mounts.clear();
+    ship.frame.condition = Some(frame_condition);
+    ship.frame.integrity = Some(1.0);
+    for module in [&mut ship.engine, &mut ship.reactor] {
+        module.condition = Some(0.95);
+        module.integrity = Some(1.0);
+    }
+    ship.nav.route.origin.symbol = "X1-AB12-A1".to_string();
+    (ship.nav.route.origin.x, ship.nav.route.origin.y) = origin;
+    (ship.nav.route.destination.x, ship.nav.route.destination.y) = destination;
+    ship
+}
+
+fn shipyard(symbol: &str, x: i32, y: i32) -> Waypoint {
+    waypoint(symbol, "ORBITAL_STATION", x, y)
+}
+
+#[tokio::test]
+async fn test_repair_visit_on_route() {
+    let history = ConditionHistory::in_memory();
+    let healthy = ship(0.95, (0, 0), (100, 0));
+    history.record_ship(&healthy);
+    let samples = history.samples("MINER-1", ShipComponent::Engine);
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].condition, 95.0);
+
+    let mut maintenance = MaintenanceManager::new(70.0);
+    assert!(!maintenance.repair_due(&healthy, &history));
+    assert_eq!(maintenance.plan_visit(&healthy), None);
+
+    let worn = ship(0.5, (0, 0), (100, 0));
+    history.record_ship(&worn);
+    assert!(maintenance.repair_due(&worn, &history));
+
+    maintenance.record_shipyards("X1-AB12", &[shipyard("X1-AB12-S1", 50, 40), shipyard("X1-AB12-S2", 100, 10)]);
+    let visit = maintenance.plan_visit(&worn).expect("a shipyard");
+    assert_eq!((visit.shipyard.as_str(), visit.detour), ("X1-AB12-S2", 10.0));
+}
+
+#[tokio::test]
+async fn test_declined_repair_is_not_retried() {
+    let history = ConditionHistory::in_memory();
+    let mut maintenance = MaintenanceManager::new(70.0);
+    let worn = ship(0.5, (0, 0), (100, 0));
+    history.record_ship(&worn);
+    assert!(maintenance.repair_due(&worn, &history));
+
+    let advice = ScrapAdvice { ship_symbol: "MINER-1".to_string(), repair_cost: 12000, scrap_value: 8000 };
+    maintenance.decline_repair(advice.clone(), &history);
+    assert_eq!(maintenance.declined_repair("MINER-1"), Some(&advice));
+    assert!(!maintenance.repair_due(&worn, &history));
+
+    // Further wear does not send it back to be priced again
+    let worse = ship(0.4, (0, 0), (100, 0));
+    history.record_ship(&worse);
+    assert!(!maintenance.repair_due(&worse, &history));
+
+    // A changed threshold prices it again
+    maintenance.set_repair_threshold(60.0);
+    assert!(maintenance.repair_due(&worse, &history));
+
+    // So does a condition above where it was priced
+    maintenance.decline_repair(advice, &history);
+    assert!(!maintenance.repair_due(&worse, &history));
+    let patched = ship(0.55, (0, 0), (100, 0));
+    history.record_ship(&patched);
+    assert!(maintenance.repair_due(&patched, &history));
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use common::miner;
+use spacetraders_cc::events::{EventBus, GameEvent};
+use spacetraders_cc::metrics::Metrics;
+use spacetraders_cc::models::{Contract, Ship};
+
+fn ship() -> Ship {
+    let mut ship = miner("MINER-1", "X1-AB12-A1");
+    ship.cargo.capacity = 30;
+    ship.cargo.units = 12;
+    ship.fuel.current = 80;
+    ship
+}
+
+fn contract(accepted: bool) -> Contract {
+    serde_json::from_value(serde_json::json!({
+        "id": "CONTRACT-1", "factionSymbol": "COSMIC", "type": "PROCUREMENT", "accepted": accepted, "fulfilled": false,
+        "expiration": "", "deadlineToAccept": "",
+        "terms": {"deadline": "", "payment": {"onAccepted": 1000, "onFulfilled": 5000},
+            "deliver": [{"tradeSymbol": "IRON_ORE", "destinationSymbol": "X1-AB12-B2", "unitsRequired": 60, "unitsFulfilled": 20}]},
+    })).expect("valid contract json")
+}
+
+#[tokio::test]
+async fn test_render_includes_fleet_api_and_event_totals() {
+    let metrics = Metrics::default();
+    metrics.record_api_request("https://api.spacetraders.io/v2/my/agent", Some(200));
+    metrics.record_api_request("https://api.spacetraders.io/v2/my/agent", Some(429));
+    metrics.record_credits(175000);
+    metrics.record_ship(&ship(), "on_cooldown");
+    metrics.record_contracts(&[contract(true)]);
+
+    let bus = EventBus::new(16);
+    bus.register(metrics.clone());
+    bus.publish(GameEvent::Extraction { ship_symbol: "MINER-1".to_string(), waypoint_symbol: "X1-AB12-A1".to_string(),
+        good: "IRON_ORE".to_string(), units: 7 });
+    bus.publish(GameEvent::Sale { ship_symbol: "MINER-1".to_string(), good: "IRON_ORE".to_string(), units: 7, total_price: 280 });
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    let text = metrics.render();
+    for line in [
+        "spacetraders_api_requests_total{endpoint=\"/my/agent\",status=\"200\"} 1",
+        "spacetraders_api_rate_limited_total 1",
+        "spacetraders_credits 175000",
+        "spacetraders_ship_state{ship=\"MINER-1\",state=\"on_cooldown\"} 1",
+        "spacetraders_ship_cargo_units{ship=\"MINER-1\"} 12",
+        "spacetraders_ship_fuel{ship=\"MINER-1\"} 80",
+        "spacetraders_extracted_units_total{good=\"IRON_ORE\"} 7",
+        "spacetraders_sales_revenue_total{good=\"IRON_ORE\"} 280",
+        "spacetraders_contract_units_fulfilled{contract=\"CONTRACT-1\",good=\"IRON_ORE\"} 20",
+        "spacetraders_contract_units_required{contract=\"CONTRACT-1\",good=\"IRON_ORE\"} 60",
+    ] {
+        assert!(text.lines().any(|rendered| rendered == line), "missing {}", line);
+    }
+
+    // Contracts that are not accepted yet are not progress
+    metrics.record_contracts(&[contract(false)]);
+    assert!(!metrics.render().contains("CONTRACT-1"));
+}
+
+#[tokio::test]
+async fn test_endpoint_serves_metrics() {
+    let metrics = Metrics::default();
+    metrics.record_credits(42);
+    let addr = metrics.serve("127.0.0.1:0".parse().unwrap()).await.expect("bind");
+
+    let mut stream = tokio::net::TcpStream::connect(addr).await.expect("connect");
+    stream.write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n").await.expect("request");
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.expect("response");
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.contains("spacetraders_credits 42"));
+
+    let mut stream = tokio::net::TcpStream::connect(addr).await.expect("connect");
+    stream.write_all(b"GET / HTTP/1.1\r\n\r\n").await.expect("request");
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.expect("response");
+    assert!(response.starts_with("HTTP/1.1 404"));
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use common::{miner, ship};
+use spacetraders_cc::models::Ship;
+use spacetraders_cc::operations::{MiningStation, StationTask};
+
+fn station_miner(symbol: &str, units: i32) -> Ship {
+    let mut miner = miner(symbol, "X1-AB12-A1");
+    miner.cargo.capacity = 15;
+    miner.cargo.units = units;
+    miner
+}
+
+fn hauler(symbol: &str, waypoint: &str, units: i32) -> Ship {
+    let mut hauler = ship(symbol, waypoint);
+    hauler.cargo.units = units;
+    hauler
+}
+
+#[tokio::test]
+async fn test_miners_offload_to_the_loading_hauler() {
+    let ships = vec![
+        station_miner("MINER-1", 12),
+        station_miner("MINER-2", 3),
+        hauler("HAULER-1", "X1-AB12-A1", 10),
+        hauler("HAULER-2", "X1-AB12-A1", 0),
+    ];
+    assert!(MiningStation::fleet_supports_station(&ships));
+
+    let mut station = MiningStation::new("X1-AB12-A1", 2);
+    station.refresh(&ships, &[]);
+    // The emptiest parked hauler loads first
+    assert_eq!(station.loading_hauler(), Some("HAULER-2"));
+    assert_eq!(station.task_for(&ships[0]), Some(StationTask::Offload { hauler: "HAULER-2".to_string() }));
+    assert_eq!(station.task_for(&ships[1]), Some(StationTask::Mine));
+    assert_eq!(station.task_for(&ships[2]), Some(StationTask::Load));
+}
+
+#[tokio::test]
+async fn test_full_hauler_departs_and_next_takes_its_place() {
+    let mut ships = vec![
+        station_miner("MINER-1", 15),
+        hauler("HAULER-1", "X1-AB12-A1", 0),
+        hauler("HAULER-2", "X1-AB12-A1", 20),
+    ];
+    let mut station = MiningStation::new("X1-AB12-A1", 2);
+    station.refresh(&ships, &[]);
+    assert_eq!(station.loading_hauler(), Some("HAULER-1"));
+
+    ships[1].cargo.units = 38;
+    station.refresh(&ships, &[]);
+    assert_eq!(station.task_for(&ships[1]), Some(StationTask::Depart));
+    assert_eq!(station.loading_hauler(), Some("HAULER-2"));
+
+    // Once both haulers are away, a full miner manages its own cargo; empty haulers come back
+    ships[1] = hauler("HAULER-1", "X1-AB12-M1", 0);
+    ships[2].cargo.units = 40;
+    station.refresh(&ships, &[]);
+    assert_eq!(station.loading_hauler(), None);
+    assert_eq!(station.task_for(&ships[0]), Some(StationTask::SelfHaul));
+    assert_eq!(station.task_for(&ships[1]), Some(StationTask::Return));
+}
+
+#[tokio::test]
+async fn test_station_recruits_only_its_hauler_quota_and_skips_busy_ships() {
+    let mut ships = vec![
+        station_miner("MINER-1", 0),
+        hauler("HAULER-1", "X1-AB12-B2", 0),
+        hauler("HAULER-2", "X1-AB12-A1", 5),
+        hauler("HAULER-3", "X1-AB12-A1", 0),
+    ];
+    let mut station = MiningStation::new("X1-AB12-A1", 1);
+    // HAULER-3 is out delivering contract cargo, so the other hauler on station is taken
+    station.refresh(&ships, &["HAULER-3".to_string()]);
+    assert_eq!(station.haulers, vec!["HAULER-2"]);
+    assert_eq!(station.task_for(&ships[1]), None);
+    assert_eq!(station.task_for(&ships[3]), None);
+
+    // Members keep their place even once they pick up other work
+    ships[2].cargo.units = 40;
+    station.refresh(&ships, &["HAULER-2".to_string()]);
+    assert_eq!(station.haulers, vec!["HAULER-2"]);
+    assert_eq!(station.task_for(&ships[2]), Some(StationTask::Depart));
+
+    station.max_haulers = 2;
+    station.refresh(&ships, &[]);
+    assert_eq!(station.haulers, vec!["HAULER-2", "HAULER-3"]);
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use common::with_cargo;
+use spacetraders_cc::models::Ship;
+use spacetraders_cc::operations::{MissionResume, ShipAction, ShipMission, TaskStep, TaskStepType, TradeOrder};
+use spacetraders_cc::storage::ShipStateStore;
+
+fn ship(waypoint: &str, status: &str, held: &[(&str, i32)]) -> Ship {
+    let mut ship = with_cargo(common::ship("HAULER-1", waypoint), 40, held);
+    ship.nav.status = status.to_string();
+    ship
+}
+
+fn step(step_type: TaskStepType, location: &str) -> TaskStep {
+    TaskStep { step_type, location: location.to_string(), fuel_cost: 0, description: format!("{} step", location) }
+}
+
+fn delivery_mission() -> ShipMission {
+    let action = ShipAction::DeliverCargo {
+        contract_id: "CONTRACT-1".to_string(),
+        destination: "X1-AB12-B2".to_string(),
+        trade_symbol: "ELECTRONICS".to_string(),
+        units: 20,
+    };
+    let steps = vec![
+        step(TaskStepType::Navigate, "X1-AB12-B2"),
+        step(TaskStepType::Dock, "X1-AB12-B2"),
+        step(TaskStepType::DeliverCargo, "X1-AB12-B2"),
+    ];
+    ShipMission::new(7, action, steps)
+}
+
+#[test]
+fn test_reconcile_resumes_at_first_unfinished_step() {
+    let mission = delivery_mission();
+    let active = vec!["CONTRACT-1".to_string()];
+    assert_eq!((mission.contract_id.as_deref(), mission.reserved_goods.clone()), (Some("CONTRACT-1"), vec!["ELECTRONICS".to_string()]));
+
+    let in_transit = ship("X1-AB12-B2", "IN_TRANSIT", &[("ELECTRONICS", 20)]);
+    assert_eq!(mission.reconcile(&in_transit, &active), MissionResume::Resume { from_step: 0 });
+    let docked = ship("X1-AB12-B2", "DOCKED", &[("ELECTRONICS", 20)]);
+    assert_eq!(mission.reconcile(&docked, &active), MissionResume::Resume { from_step: 2 });
+    assert_eq!(mission.step_description(2), "X1-AB12-B2 step");
+
+    let delivered = ship("X1-AB12-B2", "DOCKED", &[]);
+    assert_eq!(mission.reconcile(&delivered, &active), MissionResume::Completed);
+    assert!(matches!(mission.reconcile(&docked, &[]), MissionResume::Abandoned(_)));
+}
+
+#[test]
+fn test_trade_mission_reserves_bought_goods() {
+    let action = ShipAction::Trade {
+        orders: vec![TradeOrder::buy("ELECTRONICS", 20, Some(300)), TradeOrder::sell("IRON_ORE", 10, None)],
+        marketplace: "X1-AB12-M1".to_string(),
+    };
+    let mission = ShipMission::new(3, action, vec![step(TaskStepType::Navigate, "X1-AB12-M1"), step(TaskStepType::Trade, "X1-AB12-M1")]);
+    assert_eq!(mission.reserved_goods, vec!["ELECTRONICS".to_string()]);
+
+    // Already bought and sold: the trade step is done even though the ship has moved on
+    assert_eq!(mission.progress(&ship("X1-AB12-B2", "IN_ORBIT", &[("ELECTRONICS", 20)])), 2);
+    assert_eq!(mission.progress(&ship("X1-AB12-M1", "DOCKED", &[("IRON_ORE", 10)])), 1);
+}
+
+#[tokio::test]
+async fn test_missions_survive_a_restart() {
+    let path = std::env::temp_dir().join(format!("mission_resume_{}.json", std::process::id()));
+    let path = path.to_str().expect("utf-8 temp path");
+
+    let mut store = ShipStateStore::new(path, 5);
+    store.cache_ship(ship("X1-AB12-A1", "IN_ORBIT", &[("ELECTRONICS", 20)])).expect("cache");
+    store.add_mission("HAULER-1", delivery_mission(), false).expect("add mission");
+    store.set_mission_progress("HAULER-1", 7, 1).expect("progress");
+    // Re-caching fresh ship data keeps the mission
+    store.cache_ship(ship("X1-AB12-A1", "IN_ORBIT", &[("ELECTRONICS", 20)])).expect("cache");
+
+    let mut reloaded = ShipStateStore::new(path, 5);
+    let mission = reloaded.mission("HAULER-1").expect("mission on disk");
+    assert_eq!((mission.action_id, mission.completed_steps), (7, 1));
+    assert_eq!(reloaded.reserved_goods("HAULER-1"), vec!["ELECTRONICS".to_string()]);
+
+    // Work queued behind the delivery is stored beside it, not over it
+    let trade = ShipAction::Trade { orders: vec![TradeOrder::buy("FUEL", 10, None)], marketplace: "X1-AB12-M1".to_string() };
+    reloaded.add_mission("HAULER-1", ShipMission::new(8, trade, Vec::new()), false).expect("queue mission");
+    assert_eq!(reloaded.missions("HAULER-1").iter().map(|mission| mission.action_id).collect::<Vec<_>>(), vec![7, 8]);
+    assert_eq!(reloaded.reserved_goods("HAULER-1"), vec!["ELECTRONICS".to_string(), "FUEL".to_string()]);
+    assert_eq!(ShipStateStore::new(path, 5).missions("HAULER-1").len(), 2);
+
+    // Each action's report clears only its own mission
+    reloaded.clear_mission("HAULER-1", Some(8)).expect("clear");
+    assert_eq!(reloaded.mission("HAULER-1").map(|mission| mission.action_id), Some(7));
+    reloaded.clear_mission("HAULER-1", Some(9)).expect("clear");
+    assert!(reloaded.mission("HAULER-1").is_some());
+    reloaded.clear_mission("HAULER-1", Some(7)).expect("clear");
+    assert!(reloaded.mission("HAULER-1").is_none());
+
+    let _ = std::fs::remove_file(path);
+}
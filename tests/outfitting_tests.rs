@@ -0,0 +1,58 @@
+mod common;
+
+use common::{market, miner, mount, requirements};
+use spacetraders_cc::models::{Market, Ship, ShipMount};
+use spacetraders_cc::operations::{OutfittingManager, PartPurchase, ShipRole};
+
+/// A docked miner whose frame, engine and each mount draw one unit of power
+fn outfitted_miner(mounts: &[&str], power_output: i32, mounting_points: i32) -> Ship {
+    let mut ship = miner("MINER-1", "X1-AB12-A1");
+    ship.nav.status = "DOCKED".to_string();
+    ship.frame.module_slots = 2;
+    ship.frame.mounting_points = mounting_points;
+    ship.frame.requirements = requirements(1, 0, 0);
+    ship.reactor.power_output = Some(power_output);
+    ship.reactor.requirements = requirements(0, 0, 0);
+    ship.engine.requirements = requirements(1, 0, 0);
+    ship.mounts = mounts.iter().map(|symbol| ShipMount { requirements: requirements(1, 0, 0), ..mount(symbol) }).collect();
+    ship
+}
+
+fn parts_market(symbol: &str, parts: &[(&str, i32)]) -> Market {
+    let goods: Vec<(&str, i32, i32, i32)> = parts.iter().map(|(part, price)| (*part, 10, *price, price / 2)).collect();
+    market(symbol, &goods)
+}
+
+#[test]
+fn test_miner_plan_swaps_mounts_and_prices_parts() {
+    let mut outfitting = OutfittingManager::new();
+    outfitting.record_requirements("MOUNT_MINING_LASER_II", &requirements(2, 0, 0));
+    outfitting.record_requirements("MOUNT_SURVEYOR_I", &requirements(1, 0, 0));
+    outfitting.record_market(&parts_market("X1-AB12-M1", &[("MOUNT_MINING_LASER_II", 900)]));
+    outfitting.record_market(&parts_market("X1-AB12-M2", &[("MOUNT_MINING_LASER_II", 800), ("MOUNT_SURVEYOR_I", 300)]));
+
+    let miner = outfitted_miner(&["MOUNT_MINING_LASER_I", "MOUNT_SURVEYOR_I"], 10, 3);
+    let plan = outfitting.plan(&miner, &ShipRole::Miner).expect("miners have a loadout");
+    assert_eq!(plan.remove_mounts, vec!["MOUNT_MINING_LASER_I"]);
+    assert_eq!(plan.install_mounts, vec!["MOUNT_MINING_LASER_II", "MOUNT_MINING_LASER_II"]);
+    assert_eq!(plan.purchases[0], PartPurchase {
+        part: "MOUNT_MINING_LASER_II".to_string(),
+        waypoint: Some("X1-AB12-M2".to_string()),
+        price: Some(800),
+    });
+    assert!(plan.is_feasible());
+    assert_eq!(outfitting.estimate_cost(&plan, "X1-AB12-S1").total(), 1600);
+    assert!(outfitting.plan(&miner, &ShipRole::Scout).is_none());
+}
+
+#[test]
+fn test_plan_reports_power_and_mounting_point_limits() {
+    let mut outfitting = OutfittingManager::new();
+    outfitting.record_requirements("MOUNT_MINING_LASER_II", &requirements(2, 0, 0));
+
+    // Frame 1 + engine 1 + 2x laser 2 + surveyor 1 = 7 power against 4, and 3 mounts on 2 points
+    let miner = outfitted_miner(&["MOUNT_SURVEYOR_I"], 4, 2);
+    let plan = outfitting.plan(&miner, &ShipRole::Miner).expect("miners have a loadout");
+    assert_eq!(plan.violations.len(), 2);
+    assert!(!plan.is_feasible());
+}
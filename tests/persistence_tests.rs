@@ -0,0 +1,131 @@
+// Persistence tests - versioned envelopes, migrations, corrupt files and reset clearing, no API access needed
+use std::fs;
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use spacetraders_cc::goals::{GoalAction, GoalPriority};
+use spacetraders_cc::storage::{clear_on_server_reset, from_unversioned, CooldownStore, GoalStore, StorageFormat, StoredGoal,
+                               StoredGoalState, SurveyPool};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("persistence_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("temp dir");
+    dir
+}
+
+fn path_in(dir: &std::path::Path, file: &str) -> String {
+    dir.join(file).to_str().expect("utf-8 path").to_string()
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    ship_symbol: String,
+    units: i32,
+}
+
+/// Version 2 renamed `ship` to `ship_symbol`
+fn rename_ship_field(mut data: Value) -> Result<Value, String> {
+    for record in data.as_array_mut().ok_or("expected a list")? {
+        let object = record.as_object_mut().ok_or("expected an object")?;
+        let ship = object.remove("ship").ok_or("missing ship")?;
+        object.insert("ship_symbol".to_string(), ship);
+    }
+    Ok(data)
+}
+
+const RECORDS: StorageFormat = StorageFormat { name: "records", first_version: 0, migrations: &[from_unversioned, rename_ship_field] };
+
+#[tokio::test]
+async fn test_old_files_migrate_and_are_rewritten() {
+    let dir = temp_dir("migrate");
+    let path = path_in(&dir, "records.json");
+    fs::write(&path, json!([{"ship": "MINER-1", "units": 7}]).to_string()).expect("legacy file");
+
+    let records: Vec<Record> = RECORDS.load(&path).expect("load").expect("data");
+    assert_eq!(records, vec![Record { ship_symbol: "MINER-1".to_string(), units: 7 }]);
+    let rewritten: Value = serde_json::from_str(&fs::read_to_string(&path).expect("read")).expect("json");
+    assert_eq!(rewritten["schema_version"], 2);
+    assert_eq!(rewritten["data"][0]["ship_symbol"], "MINER-1");
+
+    // Version 1 files only run the second migration
+    let (data, stored) = RECORDS.migrate(json!({"schema_version": 1, "data": [{"ship": "HAULER-2", "units": 3}]})).expect("migrate");
+    assert_eq!(stored, 1);
+    assert_eq!(data[0]["ship_symbol"], "HAULER-2");
+    assert!(RECORDS.load::<Vec<Record>>(&path_in(&dir, "missing.json")).expect("load").is_none());
+
+    // Cooldown files from before versioning still load
+    let cooldowns = path_in(&dir, "cooldowns.json");
+    let until = (chrono::Utc::now() + chrono::Duration::minutes(5)).to_rfc3339();
+    fs::write(&cooldowns, json!([{"ship_symbol": "MINER-1", "cooldown_until": until, "last_updated": until}]).to_string()).expect("legacy cooldowns");
+    assert!(CooldownStore::new(&cooldowns).is_on_cooldown("MINER-1"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn test_goal_and_survey_files_start_at_version_1() {
+    let dir = temp_dir("stores");
+    let goals = path_in(&dir, "goals.json");
+    let mut store = GoalStore::new(&goals);
+    store.upsert_goal(StoredGoal {
+        id: "mine_iron".to_string(),
+        action: GoalAction::Mine,
+        description: "Mine 20 IRON_ORE".to_string(),
+        priority: GoalPriority::Override,
+        params: Default::default(),
+        state: StoredGoalState::Queued,
+        progress: Default::default(),
+        queued_at: chrono::Utc::now(),
+        finished_at: None,
+    }).expect("save goal");
+    let saved: Value = serde_json::from_str(&fs::read_to_string(&goals).expect("read")).expect("json");
+    assert_eq!(saved["schema_version"], 1);
+    assert_eq!(GoalStore::new(&goals).unfinished_goals().len(), 1);
+
+    // These files were always versioned, so bare data is not a file this build wrote
+    let surveys = path_in(&dir, "survey_pool.json");
+    fs::write(&surveys, "{}").expect("bare survey pool");
+    assert_eq!(SurveyPool::new(&surveys).stats().total_surveys, 0);
+    assert!(std::path::Path::new(&format!("{}.corrupt", surveys)).exists());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn test_unreadable_files_are_kept_aside() {
+    let dir = temp_dir("corrupt");
+    let path = path_in(&dir, "records.json");
+
+    fs::write(&path, json!({"schema_version": 9, "data": []}).to_string()).expect("future file");
+    assert!(RECORDS.load::<Vec<Record>>(&path).is_err());
+    assert!(!std::path::Path::new(&path).exists());
+    assert!(std::path::Path::new(&format!("{}.corrupt", path)).exists());
+
+    fs::write(&path, "{\"schema_version\": 2, \"da").expect("truncated file");
+    assert!(RECORDS.load::<Vec<Record>>(&path).is_err());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_storage_is_cleared_when_the_reset_date_changes() {
+    let dir = temp_dir("reset");
+    let storage_dir = dir.to_str().expect("utf-8 path");
+    fs::write(dir.join("ship_states.json"), "[]").expect("store");
+    fs::create_dir_all(dir.join("nested")).expect("nested dir");
+    fs::write(dir.join("nested").join("file.json"), "{}").expect("nested store");
+
+    // Without a marker the age of the data is unknown, so it is kept
+    assert!(!clear_on_server_reset(storage_dir, "2025-01-05").expect("first check"));
+    assert!(dir.join("ship_states.json").exists());
+    assert!(!clear_on_server_reset(storage_dir, "2025-01-05").expect("same reset"));
+    assert!(dir.join("ship_states.json").exists());
+
+    assert!(clear_on_server_reset(storage_dir, "2025-01-19").expect("new reset"));
+    assert!(!dir.join("ship_states.json").exists());
+    assert!(!dir.join("nested").exists());
+    assert!(!clear_on_server_reset(storage_dir, "2025-01-19").expect("marker rewritten"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use common::{ship, with_cargo};
+use spacetraders_cc::models::{RefineData, Ship};
+use spacetraders_cc::operations::{InputTransfer, RefineryPlanner, RefineryStep};
+
+fn ore_carrier(symbol: &str, waypoint: &str, ore: i32, capacity: i32) -> Ship {
+    with_cargo(ship(symbol, waypoint), capacity, &[("IRON_ORE", ore)])
+}
+
+fn refine_data(consumed: i32, produced: i32, cooldown: f64) -> RefineData {
+    serde_json::from_value(serde_json::json!({
+        "cargo": {"capacity": 100, "units": 0, "inventory": []},
+        "cooldown": {"shipSymbol": "REFINER-1", "totalSeconds": cooldown, "remainingSeconds": cooldown, "expiration": null},
+        "produced": [{"tradeSymbol": "IRON", "units": produced}],
+        "consumed": [{"tradeSymbol": "IRON_ORE", "units": consumed}],
+    })).expect("valid refine json")
+}
+
+#[tokio::test]
+async fn test_planner_learns_real_ratios_and_waits_on_cooldown() {
+    let mut planner = RefineryPlanner::new();
+    let refiner = ore_carrier("REFINER-1", "X1-AB12-A1", 100, 120);
+    assert_eq!(planner.next_step("IRON", &refiner.cargo), Some(RefineryStep::Refine));
+
+    planner.observe_refine(&refine_data(100, 10, 60.0));
+    let learned = planner.recipe("IRON").expect("iron recipe");
+    assert_eq!((learned.input_units, learned.output_units), (100, 10));
+    assert!(matches!(planner.next_step("IRON", &refiner.cargo), Some(RefineryStep::WaitForCooldown { .. })));
+
+    let empty = ore_carrier("REFINER-1", "X1-AB12-A1", 40, 120);
+    assert!(matches!(planner.next_step("IRON", &empty.cargo), Some(RefineryStep::NeedInput { .. })));
+    assert_eq!(planner.next_step("UNOBTAINIUM", &empty.cargo), None);
+}
+
+#[tokio::test]
+async fn test_top_up_from_co_located_ships() {
+    let planner = RefineryPlanner::new();
+    let refiner = ore_carrier("REFINER-1", "X1-AB12-A1", 10, 100);
+    let fleet = vec![
+        refiner.clone(),
+        ore_carrier("MINER-1", "X1-AB12-A1", 30, 40),
+        ore_carrier("MINER-2", "X1-AB12-B2", 40, 40),
+        ore_carrier("MINER-3", "X1-AB12-A1", 25, 40),
+    ];
+    // Two refines in stock means 60 ore, so 50 more are wanted from ships at the same waypoint
+    assert_eq!(planner.plan_top_up("IRON", &refiner, &fleet), vec![
+        InputTransfer { from_ship: "MINER-1".to_string(), good: "IRON_ORE".to_string(), units: 30 },
+        InputTransfer { from_ship: "MINER-3".to_string(), good: "IRON_ORE".to_string(), units: 20 },
+    ]);
+}
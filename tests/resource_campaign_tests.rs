@@ -0,0 +1,20 @@
+mod common;
+
+use common::{waypoint, with_traits};
+use spacetraders_cc::operations::ResourceCampaign;
+use spacetraders_cc::storage::ExtractionLog;
+
+#[test]
+fn test_candidates_prefer_matching_deposits() {
+    let waypoints = vec![
+        with_traits(waypoint("X1-AB12-A1", "ASTEROID", 0, 0), &["PRECIOUS_METAL_DEPOSITS"]),
+        with_traits(waypoint("X1-AB12-A2", "ENGINEERED_ASTEROID", 0, 0), &["COMMON_METAL_DEPOSITS"]),
+        with_traits(waypoint("X1-AB12-P1", "PLANET", 0, 0), &["COMMON_METAL_DEPOSITS"]),
+        waypoint("X1-AB12-G1", "GAS_GIANT", 0, 0),
+    ];
+    let log = ExtractionLog::in_memory();
+
+    assert_eq!(ResourceCampaign::rank_candidates("IRON_ORE", &waypoints, &log), vec!["X1-AB12-A2", "X1-AB12-A1"]);
+    assert_eq!(ResourceCampaign::rank_candidates("GOLD_ORE", &waypoints, &log), vec!["X1-AB12-A1", "X1-AB12-A2"]);
+    assert_eq!(ResourceCampaign::rank_candidates("HYDROCARBON", &waypoints, &log), vec!["X1-AB12-G1"]);
+}
@@ -0,0 +1,59 @@
+// Shutdown tests - atomic store writes and actors stopping on the shutdown signal, no API access needed
+use std::fs;
+use tokio::sync::mpsc;
+use tokio::time::{timeout, Duration};
+use spacetraders_cc::client::{ApiRequestBroker, SpaceTradersClient};
+use spacetraders_cc::config::SpaceTradersConfig;
+use spacetraders_cc::control::get_control;
+use spacetraders_cc::operations::ShipActor;
+use spacetraders_cc::storage::{write_atomically, CooldownStore, SurveyPool};
+
+#[tokio::test]
+async fn test_atomic_writes_replace_whole_files() {
+    let dir = std::env::temp_dir().join(format!("shutdown_store_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let path = dir.join("nested").join("store.json").to_str().expect("utf-8 path").to_string();
+
+    write_atomically(&path, "first version, quite long").expect("write");
+    write_atomically(&path, "second").expect("rewrite");
+    assert_eq!(fs::read_to_string(&path).expect("read"), "second");
+    assert!(!std::path::Path::new(&format!("{}.tmp", path)).exists());
+
+    // Stores written through it load back
+    let cooldowns = dir.join("cooldowns.json").to_str().expect("utf-8 path").to_string();
+    let mut store = CooldownStore::new(&cooldowns);
+    store.set_cooldown("MINER-1", 120.0).expect("set cooldown");
+    store.flush().expect("flush");
+    assert!(CooldownStore::new(&cooldowns).is_on_cooldown("MINER-1"));
+
+    let surveys = dir.join("survey_pool.json").to_str().expect("utf-8 path").to_string();
+    SurveyPool::new(&surveys).flush().expect("flush survey pool");
+    let saved: serde_json::Value = serde_json::from_str(&fs::read_to_string(&surveys).expect("read")).expect("json");
+    assert_eq!(saved["schema_version"], 1);
+    assert!(!std::path::Path::new(&format!("{}.tmp", surveys)).exists());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn test_idle_actor_stops_on_shutdown_and_broker_drains() {
+    let ship_symbol = format!("SHUTDOWN_TEST-{}", std::process::id());
+    let (_command_sender, command_receiver) = mpsc::unbounded_channel();
+    let (status_sender, _status_receiver) = mpsc::unbounded_channel();
+    let (report_sender, _report_receiver) = mpsc::unbounded_channel();
+    let mut actor = ShipActor::new(ship_symbol.clone(), command_receiver, status_sender, report_sender,
+        SpaceTradersClient::new("token".to_string()), SpaceTradersConfig::default());
+    let handle = tokio::spawn(async move { actor.run().await });
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(!handle.is_finished());
+
+    // The command channel is still open, so only the shutdown signal can stop it
+    get_control().request_shutdown();
+    timeout(Duration::from_secs(2), handle).await.expect("actor stopped").expect("actor did not panic");
+    timeout(Duration::from_secs(2), ApiRequestBroker::global().drain()).await.expect("broker drained");
+
+    let _ = fs::remove_file(format!("storage/cooldowns_{}.json", ship_symbol));
+    // Only removed when the test created it
+    let _ = fs::remove_dir("storage");
+}
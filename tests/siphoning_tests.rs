@@ -0,0 +1,16 @@
+mod common;
+
+use common::waypoint;
+use spacetraders_cc::operations::SiphonOperations;
+
+#[test]
+fn test_nearest_gas_giant_ignores_other_waypoints() {
+    let waypoints = vec![
+        waypoint("X1-AB12-A1", "ASTEROID", 1, 1),
+        waypoint("X1-AB12-G1", "GAS_GIANT", 50, 50),
+        waypoint("X1-AB12-G2", "GAS_GIANT", -10, 5),
+    ];
+    let nearest = SiphonOperations::nearest_gas_giant(&waypoints, 0, 0).expect("a gas giant exists");
+    assert_eq!(nearest.symbol, "X1-AB12-G2");
+    assert!(SiphonOperations::nearest_gas_giant(&waypoints[..1], 0, 0).is_none());
+}
@@ -0,0 +1,69 @@
+// Structured log tests - field extraction, per-subsystem levels and file rotation, no API access needed
+use std::collections::HashMap;
+use std::fs;
+use spacetraders_cc::config::{LoggingConfig, SpaceTradersConfig};
+use spacetraders_cc::output_broker::OutputLevel;
+use spacetraders_cc::structured_log::{find_contract_id, find_ship_symbol, subsystem_of, JsonLogger, LogRecord};
+
+#[test]
+fn test_record_fields_come_from_the_message() {
+    let record = LogRecord::from_message(&OutputLevel::Info, "spacetraders_cc::operations::ship_actor",
+        "📦 STARHOPPER-1A delivering to X1-AB12-B2 for contract clm0n4f8q001as60ct2rx8s1b");
+    assert_eq!(record.subsystem, "ship_actor");
+    assert_eq!(record.ship_symbol.as_deref(), Some("STARHOPPER-1A"));
+    assert_eq!(record.contract_id.as_deref(), Some("clm0n4f8q001as60ct2rx8s1b"));
+
+    assert_eq!(find_ship_symbol("⛏️ mining at X1-AB12-A1"), None);
+    assert_eq!(find_ship_symbol("POST https://api.spacetraders.io/v2/my/ships/AGENT_X-3/navigate -> 200"), Some("AGENT_X-3".to_string()));
+    assert_eq!(find_contract_id("no contract here"), None);
+    assert_eq!(subsystem_of("api"), "api");
+
+    let json = serde_json::to_value(&LogRecord { ship_symbol: None, contract_id: None, ..record }).expect("json");
+    assert!(json.get("ship_symbol").is_none());
+    assert_eq!(json["level"], "info");
+}
+
+#[test]
+fn test_levels_filter_per_subsystem_and_files_rotate() {
+    let dir = std::env::temp_dir().join(format!("structured_log_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let path = dir.join("bot.jsonl").to_str().expect("utf-8 path").to_string();
+
+    let mut logger = JsonLogger::new(LoggingConfig {
+        enabled: true,
+        path: path.clone(),
+        max_file_bytes: 400,
+        max_files: 2,
+        default_level: "info".to_string(),
+        levels: HashMap::from([("api".to_string(), "error".to_string())]),
+    });
+    assert!(logger.enabled(&OutputLevel::Summary, "fleet_coordinator"));
+    assert!(!logger.enabled(&OutputLevel::Debug, "fleet_coordinator"));
+    assert!(!logger.enabled(&OutputLevel::Info, "api"));
+
+    logger.log(&LogRecord::from_message(&OutputLevel::Debug, "api", "GET /my/ships -> 200"));
+    assert!(!std::path::Path::new(&path).exists());
+
+    for index in 0..20 {
+        logger.log(&LogRecord::from_message(&OutputLevel::Info, "ship_actor", &format!("MINER-1 extraction {}", index)));
+    }
+    let current = fs::read_to_string(&path).expect("current log");
+    assert!(current.len() <= 400);
+    let last: serde_json::Value = serde_json::from_str(current.lines().last().expect("a line")).expect("json line");
+    assert_eq!(last["message"], "MINER-1 extraction 19");
+    assert!(dir.join("bot.jsonl.1").exists() && dir.join("bot.jsonl.2").exists());
+    assert!(!dir.join("bot.jsonl.3").exists());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn test_shipped_config_has_valid_logging_section() {
+    let config: SpaceTradersConfig = toml::from_str(include_str!("../config.example.toml")).expect("example config");
+    config.validate().expect("valid config");
+    assert_eq!(config.logging.levels.get("api").map(String::as_str), Some("error"));
+
+    let mut broken = config.clone();
+    broken.logging.default_level = "loud".to_string();
+    assert!(broken.validate().is_err());
+}
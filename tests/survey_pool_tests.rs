@@ -0,0 +1,105 @@
+// Survey pool tests - no API access needed
+use spacetraders_cc::models::transaction::{Survey, SurveyDeposit};
+use spacetraders_cc::models::{Market, MarketTradeGood};
+use spacetraders_cc::storage::{is_dead_survey_error, SurveyPool};
+
+fn survey(signature: &str, deposits: &[&str], size: &str, expires_in_minutes: i64) -> Survey {
+    Survey {
+        signature: signature.to_string(),
+        symbol: "X1-AB12-C3".to_string(),
+        deposits: deposits.iter().map(|symbol| SurveyDeposit { symbol: symbol.to_string() }).collect(),
+        expiration: (chrono::Utc::now() + chrono::Duration::minutes(expires_in_minutes)).to_rfc3339(),
+        size: size.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_pool_ranks_by_expected_yield_of_wanted_goods() {
+    let pool = SurveyPool::in_memory();
+    pool.add_surveys("AGENT-1", &[
+        survey("MOSTLY-QUARTZ", &["QUARTZ_SAND", "QUARTZ_SAND", "IRON_ORE"], "LARGE", 30),
+        survey("MOSTLY-IRON", &["IRON_ORE", "IRON_ORE", "QUARTZ_SAND"], "MODERATE", 30),
+        survey("NO-IRON", &["COPPER_ORE"], "LARGE", 30),
+    ]);
+
+    let wanted = vec!["IRON_ORE".to_string()];
+    let best = pool.best_survey("X1-AB12-C3", &wanted).expect("a survey contains iron");
+    assert_eq!(best.signature, "MOSTLY-IRON");
+    assert!(pool.best_survey("X1-AB12-C3", &["GOLD_ORE".to_string()]).is_none());
+}
+
+#[tokio::test]
+async fn test_pool_drops_exhausted_and_expired_surveys() {
+    let pool = SurveyPool::in_memory();
+    pool.add_surveys("AGENT-1", &[
+        survey("LIVE", &["IRON_ORE"], "SMALL", 30),
+        survey("STALE", &["IRON_ORE"], "LARGE", -1),
+    ]);
+    assert_eq!(pool.surveys_at("X1-AB12-C3").len(), 1);
+
+    let exhausted = r#"{"error":{"message":"Ship extract failed. Survey X1-AB12-C3-LIVE has been exhausted.","code":4224}}"#;
+    assert!(is_dead_survey_error(exhausted));
+    assert!(pool.handle_extraction_error("LIVE", exhausted));
+    assert_eq!(pool.stats().total_surveys, 0);
+
+    assert!(!is_dead_survey_error("Ship action is still on cooldown for 12 second(s)"));
+    let expired = r#"Targeted extraction failed with status 400 Bad Request: {"error":{"message":"Survey expired.","code":4221}}"#;
+    assert!(is_dead_survey_error(expired));
+    assert!(is_dead_survey_error(r#"{"error":{"message":"Survey signature invalid.","code":4220}}"#));
+    // Wrong waypoint type is the ship's mistake, not the survey's, and numbers in the message are not codes
+    assert!(!is_dead_survey_error(r#"{"error":{"message":"Waypoint X1-AB12-4221 is not an asteroid.","code":4222}}"#));
+}
+
+fn market(symbol: &str, prices: &[(&str, i32)]) -> Market {
+    Market {
+        symbol: symbol.to_string(),
+        exports: Vec::new(),
+        imports: Vec::new(),
+        exchange: Vec::new(),
+        transactions: None,
+        trade_goods: Some(prices.iter().map(|(good, price)| MarketTradeGood {
+            symbol: good.to_string(),
+            trade_volume: 10,
+            supply: "MODERATE".to_string(),
+            activity: None,
+            purchase_price: price + 5,
+            sell_price: *price,
+        }).collect()),
+    }
+}
+
+#[tokio::test]
+async fn test_pool_ranks_by_expected_value_from_market_prices() {
+    let pool = SurveyPool::in_memory();
+    pool.add_surveys("AGENT-1", &[
+        survey("MOSTLY-IRON", &["IRON_ORE", "IRON_ORE", "QUARTZ_SAND"], "SMALL", 30),
+        survey("SOME-GOLD", &["GOLD_ORE", "QUARTZ_SAND", "QUARTZ_SAND"], "SMALL", 30),
+    ]);
+    pool.record_market_prices(&market("X1-AB12-M1", &[("IRON_ORE", 20), ("QUARTZ_SAND", 10), ("GOLD_ORE", 200)]));
+
+    // Any good will do: one third gold beats two thirds iron
+    let best = pool.choose_survey("X1-AB12-C3", &[]).expect("gold survey beats unsurveyed");
+    assert_eq!(best.signature, "SOME-GOLD");
+
+    // Only iron wanted: the iron survey beats the average unsurveyed deposit
+    let wanted = vec!["IRON_ORE".to_string()];
+    let best = pool.choose_survey("X1-AB12-C3", &wanted).expect("iron survey beats unsurveyed");
+    assert_eq!(best.signature, "MOSTLY-IRON");
+}
+
+#[tokio::test]
+async fn test_unsurveyed_extraction_preferred_when_survey_adds_no_value() {
+    let pool = SurveyPool::in_memory();
+    pool.add_surveys("AGENT-1", &[
+        survey("TYPICAL", &["QUARTZ_SAND", "IRON_ORE"], "SMALL", 30),
+    ]);
+    pool.record_market_prices(&market("X1-AB12-M1", &[("IRON_ORE", 100), ("QUARTZ_SAND", 10)]));
+
+    // The only survey matches the deposit mix seen here, so using it gains nothing
+    assert!(pool.best_survey("X1-AB12-C3", &[]).is_some());
+    assert!(pool.choose_survey("X1-AB12-C3", &[]).is_none());
+
+    // A larger survey of the same mix yields more per extraction
+    pool.add_surveys("AGENT-1", &[survey("TYPICAL-LARGE", &["QUARTZ_SAND", "IRON_ORE"], "LARGE", 30)]);
+    assert_eq!(pool.choose_survey("X1-AB12-C3", &[]).expect("large survey").signature, "TYPICAL-LARGE");
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use common::market;
+use spacetraders_cc::models::ShipCargo;
+use spacetraders_cc::operations::{plan_trade_lots, quoted_price, TradeLot, TradeOrder, TradeSide};
+
+fn cargo(capacity: i32, held: &[(&str, i32)]) -> ShipCargo {
+    serde_json::from_value(serde_json::json!({
+        "capacity": capacity,
+        "units": held.iter().map(|(_, units)| units).sum::<i32>(),
+        "inventory": held.iter().map(|(good, units)| serde_json::json!({"symbol": good, "name": good, "description": "",
+            "units": units})).collect::<Vec<_>>(),
+    })).expect("valid cargo json")
+}
+
+fn lot(order: usize, side: TradeSide, good: &str, units: i32) -> TradeLot {
+    TradeLot { order, side, good: good.to_string(), units }
+}
+
+#[test]
+fn test_sells_free_space_and_lots_follow_trade_volume() {
+    let market = market("X1-AB12-M1", &[("IRON_ORE", 20, 40, 35), ("FUEL", 10, 70, 60)]);
+    let hold = cargo(40, &[("IRON_ORE", 30)]);
+    let orders = vec![
+        TradeOrder::buy("FUEL", 25, Some(80)),
+        TradeOrder::sell("IRON_ORE", 100, Some(30)),
+    ];
+
+    // Selling all 30 ore leaves 40 free, so the full 25 fuel fits
+    let (lots, skipped) = plan_trade_lots(&orders, &market, &hold);
+    assert!(skipped.is_empty());
+    assert_eq!(lots, vec![
+        lot(1, TradeSide::Sell, "IRON_ORE", 20),
+        lot(1, TradeSide::Sell, "IRON_ORE", 10),
+        lot(0, TradeSide::Buy, "FUEL", 10),
+        lot(0, TradeSide::Buy, "FUEL", 10),
+        lot(0, TradeSide::Buy, "FUEL", 5),
+    ]);
+}
+
+#[test]
+fn test_price_limits_cargo_space_and_unlisted_goods() {
+    let market = market("X1-AB12-M1", &[("IRON_ORE", 20, 40, 35), ("FUEL", 10, 70, 60)]);
+    let hold = cargo(40, &[("IRON_ORE", 30)]);
+    let orders = vec![
+        TradeOrder::sell("IRON_ORE", 10, Some(50)),
+        TradeOrder::buy("FUEL", 25, None),
+        TradeOrder::buy("GOLD", 5, None),
+        TradeOrder::buy("IRON_ORE", 5, Some(30)),
+    ];
+
+    let (lots, skipped) = plan_trade_lots(&orders, &market, &hold);
+    assert_eq!(lots, vec![lot(1, TradeSide::Buy, "FUEL", 10)]);
+    assert_eq!(skipped.len(), 3);
+    assert!(TradeOrder::buy("FUEL", 1, Some(70)).accepts(70));
+    assert!(!TradeOrder::sell("FUEL", 1, Some(70)).accepts(60));
+}
+
+#[test]
+fn test_requote_checks_the_side_being_traded() {
+    let market = market("X1-AB12-M1", &[("FUEL", 10, 85, 60)]);
+    let buy = TradeOrder::buy("FUEL", 25, Some(80));
+    let sell = TradeOrder::sell("FUEL", 25, Some(60));
+
+    // A buy that has pushed the price to 85 stops before its next lot; the sell side is unaffected
+    assert_eq!(quoted_price(&market, TradeSide::Buy, "FUEL"), Some(85));
+    assert!(!buy.accepts(quoted_price(&market, TradeSide::Buy, "FUEL").unwrap()));
+    assert!(sell.accepts(quoted_price(&market, TradeSide::Sell, "FUEL").unwrap()));
+    assert_eq!(quoted_price(&market, TradeSide::Buy, "GOLD"), None);
+}